@@ -18,6 +18,22 @@ pub const ACTION_ROTATE_AGENT_SIGNER: u8 = 5;
 pub const ACTION_PLACE_JOB_BID: u8 = 6;
 pub const ACTION_WITHDRAW_JOB_BID: u8 = 7;
 pub const ACTION_SUBMIT_JOB: u8 = 8;
+pub const ACTION_CHANGE_VOTE: u8 = 9;
+pub const ACTION_RETRACT_VOTE: u8 = 10;
+pub const ACTION_RETRACT_ENTRY: u8 = 11;
+pub const ACTION_REVISE_ENTRY: u8 = 12;
+pub const ACTION_ARCHIVE_ENTRY: u8 = 13;
+pub const ACTION_ANCHOR_COMPRESSED_POST: u8 = 14;
+pub const ACTION_ANCHOR_COMPRESSED_COMMENT: u8 = 15;
+pub const ACTION_CAST_COMPRESSED_VOTE: u8 = 16;
+pub const ACTION_ANCHOR_REPOST: u8 = 17;
+pub const ACTION_BAN_AGENT: u8 = 18;
+pub const ACTION_UNBAN_AGENT: u8 = 19;
+pub const ACTION_SET_ENTRY_HIDDEN: u8 = 20;
+pub const ACTION_JOIN_ENCLAVE: u8 = 21;
+pub const ACTION_CANCEL_ENCLAVE_MEMBERSHIP: u8 = 22;
+pub const ACTION_CHANGE_COMPRESSED_VOTE: u8 = 23;
+pub const ACTION_RETRACT_COMPRESSED_VOTE: u8 = 24;
 
 // Ed25519 instruction layout constants (mirrors Solana's ed25519 precompile format).
 const ED25519_OFFSETS_START: usize = 2;
@@ -185,4 +185,238 @@ pub enum WunderlandError {
 
     #[msg("Arithmetic overflow")]
     ArithmeticOverflow,
+
+    // Vote lifecycle errors
+    #[msg("New vote value matches the existing vote")]
+    VoteUnchanged,
+
+    #[msg("Invalid vote account")]
+    InvalidVote,
+
+    #[msg("Invalid vote weight curve")]
+    InvalidVoteWeightCurve,
+
+    // Voting prerequisite (sybil gate) errors
+    #[msg("Voter agent is too new to vote")]
+    VoterTooNew,
+
+    #[msg("Voter agent has not authored enough entries to vote")]
+    VoterInsufficientEntries,
+
+    #[msg("Voter agent vault stake is below the voting minimum")]
+    VoterInsufficientStake,
+
+    #[msg("Withdrawal would dip into the locked voting stake")]
+    VaultStakeLocked,
+
+    #[msg("Invalid voter requirements")]
+    InvalidVoterRequirements,
+
+    #[msg("Invalid reputation half-life")]
+    InvalidReputationHalfLife,
+
+    // Entry retraction errors
+    #[msg("Entry has been retracted")]
+    EntryRetracted,
+
+    #[msg("Entry retraction timelock has not elapsed yet")]
+    RetractionNotReady,
+
+    #[msg("Entry does not belong to this agent")]
+    EntryAgentMismatch,
+
+    // Entry archive errors
+    #[msg("Rent recipient does not match the entry payer")]
+    InvalidRentRecipient,
+
+    // Compressed entry errors
+    #[msg("Post tree is full")]
+    PostTreeFull,
+
+    #[msg("Leaf does not match the provided entry")]
+    CompressedLeafMismatch,
+
+    // Repost errors
+    #[msg("Invalid repost kind (must be Repost or Quote)")]
+    InvalidRepostKind,
+
+    #[msg("Invalid repost target")]
+    InvalidRepostTarget,
+
+    // Threading errors
+    #[msg("Comment exceeds the enclave's maximum thread depth")]
+    CommentDepthExceeded,
+
+    #[msg("Invalid or missing thread root account")]
+    InvalidThreadRoot,
+
+    // Moderation errors
+    #[msg("Agent is banned from this enclave")]
+    AgentBanned,
+
+    #[msg("Entry does not belong to this enclave")]
+    EntryEnclaveMismatch,
+
+    #[msg("Entry hidden flag is already set to this value")]
+    HiddenUnchanged,
+
+    // Membership errors
+    #[msg("Enclave membership required")]
+    MembershipRequired,
+
+    #[msg("Enclave membership is not active")]
+    MembershipNotActive,
+
+    #[msg("Agent citizen level is below the enclave minimum")]
+    CitizenLevelTooLow,
+
+    #[msg("Agent reputation is below the enclave minimum")]
+    ReputationTooLow,
+
+    #[msg("Membership is already active")]
+    MembershipAlreadyActive,
+
+    // Enclave lifecycle errors
+    #[msg("Enclave is suspended by the program authority")]
+    EnclaveSuspended,
+
+    #[msg("Signer is not the pending enclave owner")]
+    InvalidPendingOwner,
+
+    // Enclave fee errors
+    #[msg("Enclave fee exceeds the global maximum")]
+    EnclaveFeeTooHigh,
+
+    // Sub-enclave errors
+    #[msg("Sub-enclave has not been approved by its parent")]
+    SubEnclaveNotApproved,
+
+    #[msg("Enclave is not a sub-enclave of this parent")]
+    NotASubEnclave,
+
+    #[msg("Revenue share must be at most 10000 basis points")]
+    InvalidRevenueShare,
+
+    // Council errors
+    #[msg("Enclave rewards are governed by its council")]
+    CouncilGoverned,
+
+    #[msg("Invalid council configuration")]
+    InvalidCouncil,
+
+    #[msg("Signer is not a council member")]
+    NotCouncilMember,
+
+    #[msg("Council member already approved this proposal")]
+    ProposalAlreadyApproved,
+
+    #[msg("Proposal approval window has closed")]
+    ProposalExpired,
+
+    #[msg("Proposal has not reached the approval threshold")]
+    ProposalThresholdNotMet,
+
+    #[msg("Proposal has already been executed")]
+    ProposalAlreadyExecuted,
+
+    // Authority transfer errors
+    #[msg("Authority transfer timelock has not elapsed yet")]
+    AuthorityTransferNotReady,
+
+    // Admin role errors
+    #[msg("Signer does not hold the required admin role")]
+    MissingRole,
+
+    #[msg("Invalid admin role bitmask")]
+    InvalidRole,
+
+    #[msg("Admin roles table is full")]
+    AdminRolesFull,
+
+    // Pause errors
+    #[msg("This subsystem is paused")]
+    SubsystemPaused,
+
+    #[msg("Invalid pause bitmask")]
+    InvalidPauseMask,
+
+    // Economics change errors
+    #[msg("Economics change is not effective yet")]
+    EconomicsChangeNotReady,
+
+    // Treasury errors
+    #[msg("Treasury withdrawal exceeds the spending cap for the rolling window")]
+    TreasuryWithdrawalCapExceeded,
+
+    // Migration errors
+    #[msg("Account data is smaller than the current layout")]
+    AccountTooSmall,
+
+    #[msg("Account layout version is newer than this program supports")]
+    UnsupportedAccountVersion,
+
+    // Job deadline errors
+    #[msg("Invalid job deadline")]
+    InvalidJobDeadline,
+
+    #[msg("Job bidding deadline has passed")]
+    JobBidDeadlinePassed,
+
+    #[msg("Job delivery deadline has passed")]
+    JobDeliveryDeadlinePassed,
+
+    #[msg("Job delivery deadline has not passed")]
+    JobNotExpired,
+
+    // Job review errors
+    #[msg("Invalid job review window")]
+    InvalidJobReviewWindow,
+
+    #[msg("Job review window has not elapsed")]
+    JobReviewWindowOpen,
+
+    // Archive errors
+    #[msg("Entry has replies and cannot be archived")]
+    EntryHasReplies,
+
+    // Enclave fee errors
+    #[msg("Economics config, payer and system program are required to pay the enclave fee")]
+    EnclaveFeeAccountsMissing,
+
+    // Economics change delay errors
+    #[msg("Economics change delay is below the minimum")]
+    EconomicsChangeDelayTooShort,
+
+    // Council lifecycle errors
+    #[msg("Proposal was superseded by a council update")]
+    ProposalSuperseded,
+
+    #[msg("Proposal is still open")]
+    ProposalStillOpen,
+
+    #[msg("Council still has open proposals")]
+    CouncilHasOpenProposals,
+
+    #[msg("Council change requires signatures from a threshold of current members")]
+    CouncilApprovalMissing,
+
+    // Sub-enclave approval errors
+    #[msg("Sub-enclave is already approved by its parent")]
+    SubEnclaveAlreadyApproved,
+
+    // Enclave join errors
+    #[msg("Voting config is required to check the enclave's minimum reputation")]
+    VotingConfigRequired,
+
+    // Treasury window errors
+    #[msg("Too many treasury withdrawals within the rolling window")]
+    TreasuryWithdrawalLimitReached,
+
+    // Authority handover errors
+    #[msg("Authority transfer timelock is below the minimum")]
+    AuthorityTimelockTooShort,
+
+    // Repost moderation errors
+    #[msg("Entry is hidden by an enclave moderator")]
+    EntryHidden,
 }
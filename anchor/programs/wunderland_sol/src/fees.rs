@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::errors::WunderlandError;
+use crate::state::{EconomicsConfig, EnclaveTreasury};
+
+/// Charge an enclave entry fee (`Enclave.post_fee_lamports` / `comment_fee_lamports`) from
+/// `payer` into the enclave treasury.
+///
+/// The fee is capped by the current `EconomicsConfig.max_enclave_fee_lamports`, so lowering the
+/// global cap also limits fees that were set earlier. The optional accounts are only required
+/// when a fee applies. Returns the amount charged.
+pub fn charge_enclave_fee<'info>(
+    fee: u64,
+    economics: Option<&Account<'info, EconomicsConfig>>,
+    treasury: Option<&Account<'info, EnclaveTreasury>>,
+    payer: Option<AccountInfo<'info>>,
+    system_program: Option<AccountInfo<'info>>,
+) -> Result<u64> {
+    if fee == 0 {
+        return Ok(0);
+    }
+    let economics = economics.ok_or(WunderlandError::EnclaveFeeAccountsMissing)?;
+    let fee = fee.min(economics.max_enclave_fee_lamports);
+    if fee == 0 {
+        return Ok(0);
+    }
+
+    let treasury = treasury.ok_or(WunderlandError::InvalidEnclaveTreasury)?;
+    let payer = payer.ok_or(WunderlandError::EnclaveFeeAccountsMissing)?;
+    let system_program = system_program.ok_or(WunderlandError::EnclaveFeeAccountsMissing)?;
+    system_program::transfer(
+        CpiContext::new(
+            system_program,
+            system_program::Transfer {
+                from: payer,
+                to: treasury.to_account_info(),
+            },
+        ),
+        fee,
+    )?;
+    Ok(fee)
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::state::{AuthorityTransfer, EconomicsConfig, GlobalTreasury, ProgramConfig};
+
+/// Accept a proposed admin authority handover (step 2 of 2, new authority only).
+///
+/// Atomically sets `ProgramConfig.authority`, `GlobalTreasury.authority` and
+/// `EconomicsConfig.authority`, then closes the proposal (rent to the previous authority).
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, GlobalTreasury>,
+
+    #[account(
+        mut,
+        seeds = [b"econ"],
+        bump = economics.bump,
+    )]
+    pub economics: Account<'info, EconomicsConfig>,
+
+    #[account(
+        mut,
+        close = previous_authority,
+        seeds = [b"authority_transfer"],
+        bump = authority_transfer.bump,
+        constraint = authority_transfer.current_authority == config.authority @ WunderlandError::UnauthorizedAuthority,
+        constraint = authority_transfer.new_authority == new_authority.key() @ WunderlandError::UnauthorizedAuthority
+    )]
+    pub authority_transfer: Account<'info, AuthorityTransfer>,
+
+    /// Proposed authority.
+    pub new_authority: Signer<'info>,
+
+    /// Previous authority (receives the proposal rent).
+    /// CHECK: Validated to match `authority_transfer.current_authority`.
+    #[account(
+        mut,
+        constraint = previous_authority.key() == authority_transfer.current_authority @ WunderlandError::UnauthorizedAuthority
+    )]
+    pub previous_authority: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    require!(
+        Clock::get()?.unix_timestamp >= ctx.accounts.authority_transfer.ready_at,
+        WunderlandError::AuthorityTransferNotReady
+    );
+
+    let new_authority = ctx.accounts.new_authority.key();
+    ctx.accounts.config.authority = new_authority;
+    ctx.accounts.treasury.authority = new_authority;
+    ctx.accounts.economics.authority = new_authority;
+
+    msg!(
+        "Authority transferred: from={} to={}",
+        ctx.accounts.previous_authority.key(),
+        new_authority
+    );
+    Ok(())
+}
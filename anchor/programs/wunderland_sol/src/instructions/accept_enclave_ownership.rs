@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::state::Enclave;
+
+/// Accept a pending enclave ownership transfer (step 2 of 2).
+///
+/// The new owner takes over every owner-gated action, including rewards publishing.
+///
+/// Authority: `enclave.pending_owner`.
+#[derive(Accounts)]
+pub struct AcceptEnclaveOwnership<'info> {
+    #[account(
+        mut,
+        constraint = enclave.pending_owner != Pubkey::default() @ WunderlandError::InvalidPendingOwner,
+        constraint = enclave.pending_owner == new_owner.key() @ WunderlandError::InvalidPendingOwner
+    )]
+    pub enclave: Account<'info, Enclave>,
+
+    /// Pending owner wallet.
+    pub new_owner: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AcceptEnclaveOwnership>) -> Result<()> {
+    let enclave = &mut ctx.accounts.enclave;
+    let previous_owner = enclave.creator_owner;
+    enclave.creator_owner = enclave.pending_owner;
+    enclave.pending_owner = Pubkey::default();
+
+    msg!(
+        "Enclave ownership transferred: enclave={} from={} to={}",
+        enclave.key(),
+        previous_owner,
+        enclave.creator_owner
+    );
+    Ok(())
+}
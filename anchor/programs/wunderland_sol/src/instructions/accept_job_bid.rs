@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::WunderlandError;
-use crate::state::{JobBid, JobBidStatus, JobEscrow, JobPosting, JobStatus};
+use crate::state::{JobBid, JobBidStatus, JobEscrow, JobPosting, JobStatus, ProgramConfig};
 
 /// Accept an active bid for an open job (creator-authored).
 #[derive(Accounts)]
@@ -31,6 +31,14 @@ pub struct AcceptJobBid<'info> {
 
     #[account(mut)]
     pub creator: Signer<'info>,
+
+    /// Program config (pause switches).
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_JOBS) @ WunderlandError::SubsystemPaused,
+    )]
+    pub config: Box<Account<'info, ProgramConfig>>,
 }
 
 pub fn handler(ctx: Context<AcceptJobBid>) -> Result<()> {
@@ -38,6 +46,13 @@ pub fn handler(ctx: Context<AcceptJobBid>) -> Result<()> {
     let bid = &mut ctx.accounts.bid;
     let escrow = &mut ctx.accounts.escrow;
 
+    // Assigning work that can no longer be delivered in time would only lock the escrow.
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        !job.delivery_overdue(now),
+        WunderlandError::JobDeliveryDeadlinePassed
+    );
+
     // If the job was created with buy-it-now enabled, the escrow may contain a premium.
     // Once the creator explicitly accepts a bid, we downgrade escrow to the base budget
     // and immediately refund any premium back to the creator.
@@ -83,7 +98,7 @@ pub fn handler(ctx: Context<AcceptJobBid>) -> Result<()> {
     job.status = JobStatus::Assigned;
     job.assigned_agent = bid.bidder_agent;
     job.accepted_bid = bid.key();
-    job.updated_at = Clock::get()?.unix_timestamp;
+    job.updated_at = now;
 
     bid.status = JobBidStatus::Accepted;
 
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::moderation::record_moderation;
+use crate::state::{
+    AgentIdentity, Enclave, EnclaveModerator, ModerationAction, ModerationLog,
+    CURRENT_ACCOUNT_VERSION,
+};
+
+/// Appoint a moderator agent for an enclave.
+///
+/// Authority: `enclave.creator_owner`.
+#[derive(Accounts)]
+pub struct AddEnclaveModerator<'info> {
+    #[account(
+        mut,
+        constraint = enclave.creator_owner == authority.key() @ WunderlandError::UnauthorizedEnclaveOwner
+    )]
+    pub enclave: Account<'info, Enclave>,
+
+    /// Agent being appointed (must be active).
+    #[account(
+        constraint = moderator_agent.is_active @ WunderlandError::AgentInactive
+    )]
+    pub moderator_agent: Account<'info, AgentIdentity>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = EnclaveModerator::LEN,
+        seeds = [b"moderator", enclave.key().as_ref(), moderator_agent.key().as_ref()],
+        bump
+    )]
+    pub moderator: Account<'info, EnclaveModerator>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ModerationLog::LEN,
+        seeds = [b"modlog", enclave.key().as_ref(), &enclave.moderation_count.to_le_bytes()],
+        bump
+    )]
+    pub moderation_log: Account<'info, ModerationLog>,
+
+    /// Enclave owner wallet.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AddEnclaveModerator>, reason_hash: [u8; 32]) -> Result<()> {
+    let clock = Clock::get()?;
+    let enclave_key = ctx.accounts.enclave.key();
+
+    let moderator = &mut ctx.accounts.moderator;
+    moderator.enclave = enclave_key;
+    moderator.agent = ctx.accounts.moderator_agent.key();
+    moderator.appointed_by = ctx.accounts.authority.key();
+    moderator.appointed_at = clock.unix_timestamp;
+    moderator.bump = ctx.bumps.moderator;
+    moderator.version = CURRENT_ACCOUNT_VERSION;
+    moderator.payer = ctx.accounts.authority.key();
+
+    record_moderation(
+        &mut ctx.accounts.moderation_log,
+        &mut ctx.accounts.enclave,
+        enclave_key,
+        ctx.accounts.authority.key(),
+        ModerationAction::AddModerator,
+        ctx.accounts.moderator_agent.key(),
+        reason_hash,
+    )?;
+    ctx.accounts.moderation_log.bump = ctx.bumps.moderation_log;
+    ctx.accounts.moderation_log.version = CURRENT_ACCOUNT_VERSION;
+
+    msg!(
+        "Moderator added: enclave={} agent={}",
+        enclave_key,
+        ctx.accounts.moderator_agent.key()
+    );
+    Ok(())
+}
@@ -4,13 +4,23 @@ use crate::auth::{
     build_agent_message, require_ed25519_signature_preceding_instruction, ACTION_ANCHOR_COMMENT,
 };
 use crate::errors::WunderlandError;
-use crate::state::{AgentIdentity, Enclave, EntryKind, PostAnchor};
+use crate::fees::charge_enclave_fee;
+use crate::membership::require_enclave_access;
+use crate::state::{
+    AgentIdentity, EconomicsConfig, Enclave, EnclaveMembership, EnclaveTreasury, EntryKind,
+    EntryStatus, PostAnchor, ProgramConfig, CURRENT_ACCOUNT_VERSION,
+};
 
 /// Anchor an on-chain comment entry (optional; off-chain signed comments are the default).
 ///
 /// This creates a `PostAnchor` with `kind=Comment` and `reply_to=parent_entry`.
 /// The parent entry's `comment_count` is incremented (so replies can nest).
 ///
+/// Threading: the comment inherits the parent's `thread_root` with `depth = parent.depth + 1`
+/// (bounded by `Enclave.max_comment_depth` when non-zero), and the root's `thread_reply_count`
+/// is incremented. Pass `thread_root` only when the parent is itself a reply; when replying to
+/// the root directly, the parent is the root and `thread_root` must be omitted.
+///
 /// Authorization:
 /// - Requires an ed25519-signed payload by `agent_identity.agent_signer`.
 #[derive(Accounts)]
@@ -39,10 +49,20 @@ pub struct AnchorComment<'info> {
     )]
     pub enclave: Account<'info, Enclave>,
 
+    /// Ban marker for this agent in this enclave (must not exist).
+    /// CHECK: Address is the `EnclaveBan` PDA; posting is allowed only while it is empty.
+    #[account(
+        seeds = [b"ban", enclave.key().as_ref(), agent_identity.key().as_ref()],
+        bump,
+        constraint = enclave_ban.data_is_empty() @ WunderlandError::AgentBanned
+    )]
+    pub enclave_ban: UncheckedAccount<'info>,
+
     #[account(
         mut,
-        constraint = (parent_post.kind == EntryKind::Post || parent_post.kind == EntryKind::Comment) @ WunderlandError::InvalidReplyTarget,
-        constraint = parent_post.enclave == enclave.key() @ WunderlandError::InvalidReplyTarget
+        constraint = parent_post.kind != EntryKind::Repost @ WunderlandError::InvalidReplyTarget,
+        constraint = parent_post.enclave == enclave.key() @ WunderlandError::InvalidReplyTarget,
+        constraint = !parent_post.is_retracted() @ WunderlandError::EntryRetracted
     )]
     pub parent_post: Account<'info, PostAnchor>,
 
@@ -55,6 +75,41 @@ pub struct AnchorComment<'info> {
     pub instructions: UncheckedAccount<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// Program config (pause switches).
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_POSTING) @ WunderlandError::SubsystemPaused,
+    )]
+    pub config: Box<Account<'info, ProgramConfig>>,
+
+    /// Thread root entry (required iff `parent_post` is not the root; trailing so that
+    /// clients replying to a root entry can omit it).
+    #[account(mut)]
+    pub thread_root: Option<Account<'info, PostAnchor>>,
+
+    /// Author's membership (required when the enclave is not open).
+    #[account(
+        seeds = [b"membership", enclave.key().as_ref(), agent_identity.key().as_ref()],
+        bump = membership.bump,
+    )]
+    pub membership: Option<Account<'info, EnclaveMembership>>,
+
+    /// Enclave treasury receiving the comment fee (required when `enclave.comment_fee_lamports > 0`).
+    #[account(
+        mut,
+        seeds = [b"enclave_treasury", enclave.key().as_ref()],
+        bump = enclave_treasury.bump,
+    )]
+    pub enclave_treasury: Option<Account<'info, EnclaveTreasury>>,
+
+    /// Economics config (caps the enclave fee; required when the enclave charges one).
+    #[account(
+        seeds = [b"econ"],
+        bump = economics.bump,
+    )]
+    pub economics: Option<Account<'info, EconomicsConfig>>,
 }
 
 pub fn handler(
@@ -62,6 +117,32 @@ pub fn handler(
     content_hash: [u8; 32],
     manifest_hash: [u8; 32],
 ) -> Result<()> {
+    require_enclave_access(
+        &ctx.accounts.enclave,
+        &ctx.accounts.agent_identity,
+        ctx.accounts.membership.as_deref(),
+    )?;
+
+    // Resolve thread position and enforce the enclave depth limit.
+    let parent = &ctx.accounts.parent_post;
+    let thread_root = parent.thread_root_of(parent.key());
+    let depth = parent
+        .depth
+        .checked_add(1)
+        .ok_or(WunderlandError::CommentDepthExceeded)?;
+    let max_depth = ctx.accounts.enclave.max_comment_depth;
+    require!(
+        max_depth == 0 || depth <= max_depth,
+        WunderlandError::CommentDepthExceeded
+    );
+    match &ctx.accounts.thread_root {
+        Some(root) => require!(
+            parent.depth > 0 && root.key() == thread_root,
+            WunderlandError::InvalidThreadRoot
+        ),
+        None => require!(parent.depth == 0, WunderlandError::InvalidThreadRoot),
+    }
+
     let agent = &mut ctx.accounts.agent_identity;
     let entry_index = agent.total_entries;
 
@@ -87,6 +168,15 @@ pub fn handler(
         &expected_message,
     )?;
 
+    // Comment fee (enclave treasury, capped globally).
+    charge_enclave_fee(
+        ctx.accounts.enclave.comment_fee_lamports,
+        ctx.accounts.economics.as_ref(),
+        ctx.accounts.enclave_treasury.as_ref(),
+        Some(ctx.accounts.payer.to_account_info()),
+        Some(ctx.accounts.system_program.to_account_info()),
+    )?;
+
     let clock = Clock::get()?;
 
     // Initialize comment anchor
@@ -100,10 +190,22 @@ pub fn handler(
     comment.manifest_hash = manifest_hash;
     comment.upvotes = 0;
     comment.downvotes = 0;
+    comment.upvote_weight = 0;
+    comment.downvote_weight = 0;
+    comment.status = EntryStatus::Active;
+    comment.retracted_at = 0;
+    comment.revision = 0;
+    comment.payer = ctx.accounts.payer.key();
+    comment.repost_count = 0;
+    comment.thread_root = thread_root;
+    comment.depth = depth;
+    comment.thread_reply_count = 0;
+    comment.hidden = false;
     comment.comment_count = 0;
     comment.timestamp = clock.unix_timestamp;
     comment.created_slot = clock.slot;
     comment.bump = ctx.bumps.comment_anchor;
+    comment.version = CURRENT_ACCOUNT_VERSION;
 
     // Increment parent post comment counter
     ctx.accounts.parent_post.comment_count = ctx
@@ -113,6 +215,16 @@ pub fn handler(
         .checked_add(1)
         .ok_or(WunderlandError::VoteCountOverflow)?;
 
+    // Increment thread reply counter on the root
+    let root = match ctx.accounts.thread_root.as_mut() {
+        Some(root) => root,
+        None => &mut ctx.accounts.parent_post,
+    };
+    root.thread_reply_count = root
+        .thread_reply_count
+        .checked_add(1)
+        .ok_or(WunderlandError::VoteCountOverflow)?;
+
     // Increment agent entry counter
     agent.total_entries = agent
         .total_entries
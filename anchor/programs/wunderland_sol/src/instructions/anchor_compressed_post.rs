@@ -0,0 +1,178 @@
+use anchor_lang::prelude::*;
+
+use crate::auth::{
+    build_agent_message, require_ed25519_signature_preceding_instruction,
+    ACTION_ANCHOR_COMPRESSED_POST,
+};
+use crate::errors::WunderlandError;
+use crate::fees::charge_enclave_fee;
+use crate::membership::require_enclave_access;
+use crate::merkle::{append_leaf, compressed_entry_body_hash, compressed_entry_leaf};
+use crate::state::{
+    AgentIdentity, CompressedEntry, EconomicsConfig, Enclave, EnclaveMembership, EnclaveTreasury,
+    EntryKind, PostTree, ProgramConfig,
+};
+
+/// Anchor a compressed post: append its leaf to the enclave `PostTree` instead of creating a
+/// `PostAnchor` PDA (no rent per entry). The enclave post fee still applies.
+///
+/// The leaf preimage (entry index, timestamp, slot) is logged for indexers.
+///
+/// Authorization:
+/// - Requires an ed25519-signed payload by `agent_identity.agent_signer`.
+#[derive(Accounts)]
+pub struct AnchorCompressedPost<'info> {
+    #[account(
+        mut,
+        seeds = [b"post_tree", enclave.key().as_ref()],
+        bump = post_tree.load()?.bump,
+    )]
+    pub post_tree: AccountLoader<'info, PostTree>,
+
+    #[account(
+        mut,
+        constraint = agent_identity.is_active @ WunderlandError::AgentInactive
+    )]
+    pub agent_identity: Account<'info, AgentIdentity>,
+
+    #[account(
+        constraint = enclave.is_active @ WunderlandError::EnclaveInactive
+    )]
+    pub enclave: Account<'info, Enclave>,
+
+    /// Ban marker for this agent in this enclave (must not exist).
+    /// CHECK: Address is the `EnclaveBan` PDA; posting is allowed only while it is empty.
+    #[account(
+        seeds = [b"ban", enclave.key().as_ref(), agent_identity.key().as_ref()],
+        bump,
+        constraint = enclave_ban.data_is_empty() @ WunderlandError::AgentBanned
+    )]
+    pub enclave_ban: UncheckedAccount<'info>,
+
+    /// CHECK: Instruction sysvar (used to verify ed25519 signature instruction).
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
+    pub instructions: UncheckedAccount<'info>,
+
+    /// Program config (pause switches).
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_POSTING) @ WunderlandError::SubsystemPaused,
+    )]
+    pub config: Box<Account<'info, ProgramConfig>>,
+
+    /// Author's membership (required when the enclave is not open).
+    #[account(
+        seeds = [b"membership", enclave.key().as_ref(), agent_identity.key().as_ref()],
+        bump = membership.bump,
+    )]
+    pub membership: Option<Account<'info, EnclaveMembership>>,
+
+    /// Enclave treasury receiving the post fee (required when the enclave charges one).
+    #[account(
+        mut,
+        seeds = [b"enclave_treasury", enclave.key().as_ref()],
+        bump = enclave_treasury.bump,
+    )]
+    pub enclave_treasury: Option<Account<'info, EnclaveTreasury>>,
+
+    /// Economics config (caps the enclave fee; required when the enclave charges one).
+    #[account(
+        seeds = [b"econ"],
+        bump = economics.bump,
+    )]
+    pub economics: Option<Account<'info, EconomicsConfig>>,
+
+    /// Wallet paying the enclave fee (required when the enclave charges one).
+    #[account(mut)]
+    pub fee_payer: Option<Signer<'info>>,
+
+    pub system_program: Option<Program<'info, System>>,
+}
+
+pub fn handler(
+    ctx: Context<AnchorCompressedPost>,
+    content_hash: [u8; 32],
+    manifest_hash: [u8; 32],
+) -> Result<()> {
+    require_enclave_access(
+        &ctx.accounts.enclave,
+        &ctx.accounts.agent_identity,
+        ctx.accounts.membership.as_deref(),
+    )?;
+
+    let agent = &mut ctx.accounts.agent_identity;
+    let entry_index = agent.total_entries;
+
+    // Verify agent signature (must be the immediately previous instruction).
+    let mut payload = Vec::with_capacity(32 + 1 + 32 + 4 + 32 + 32);
+    payload.extend_from_slice(ctx.accounts.enclave.key().as_ref());
+    payload.push(EntryKind::Post as u8);
+    payload.extend_from_slice(&[0u8; 32]); // reply_to = none
+    payload.extend_from_slice(&entry_index.to_le_bytes());
+    payload.extend_from_slice(&content_hash);
+    payload.extend_from_slice(&manifest_hash);
+
+    let expected_message = build_agent_message(
+        ACTION_ANCHOR_COMPRESSED_POST,
+        ctx.program_id,
+        &agent.key(),
+        &payload,
+    );
+
+    require_ed25519_signature_preceding_instruction(
+        &ctx.accounts.instructions.to_account_info(),
+        &agent.agent_signer,
+        &expected_message,
+    )?;
+
+    // Post fee (enclave treasury, capped globally).
+    charge_enclave_fee(
+        ctx.accounts.enclave.post_fee_lamports,
+        ctx.accounts.economics.as_ref(),
+        ctx.accounts.enclave_treasury.as_ref(),
+        ctx.accounts
+            .fee_payer
+            .as_ref()
+            .map(|payer| payer.to_account_info()),
+        ctx.accounts
+            .system_program
+            .as_ref()
+            .map(|program| program.to_account_info()),
+    )?;
+
+    let clock = Clock::get()?;
+    let entry = CompressedEntry {
+        kind: EntryKind::Post,
+        reply_to: [0u8; 32],
+        entry_index,
+        content_hash,
+        manifest_hash,
+        timestamp: clock.unix_timestamp,
+        created_slot: clock.slot,
+    };
+    let leaf = compressed_entry_leaf(
+        &ctx.accounts.enclave.key(),
+        &agent.key(),
+        &compressed_entry_body_hash(&entry),
+    );
+
+    let mut tree = ctx.accounts.post_tree.load_mut()?;
+    let leaf_index = append_leaf(&mut tree, leaf)?;
+
+    agent.total_entries = agent
+        .total_entries
+        .checked_add(1)
+        .ok_or(WunderlandError::PostCountOverflow)?;
+    agent.updated_at = clock.unix_timestamp;
+
+    msg!(
+        "Compressed post anchored: agent={} index={} leaf_index={} timestamp={} slot={}",
+        agent.key(),
+        entry_index,
+        leaf_index,
+        entry.timestamp,
+        entry.created_slot
+    );
+    Ok(())
+}
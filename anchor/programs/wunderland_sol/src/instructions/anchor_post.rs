@@ -4,7 +4,12 @@ use crate::auth::{
     build_agent_message, require_ed25519_signature_preceding_instruction, ACTION_ANCHOR_POST,
 };
 use crate::errors::WunderlandError;
-use crate::state::{AgentIdentity, Enclave, EntryKind, PostAnchor};
+use crate::fees::charge_enclave_fee;
+use crate::membership::require_enclave_access;
+use crate::state::{
+    AgentIdentity, EconomicsConfig, Enclave, EnclaveMembership, EnclaveTreasury, EntryKind,
+    EntryStatus, PostAnchor, ProgramConfig, CURRENT_ACCOUNT_VERSION,
+};
 
 /// Anchor a provenance-verified post (hash commitments only; content is off-chain).
 ///
@@ -36,6 +41,15 @@ pub struct AnchorPost<'info> {
     )]
     pub enclave: Account<'info, Enclave>,
 
+    /// Ban marker for this agent in this enclave (must not exist).
+    /// CHECK: Address is the `EnclaveBan` PDA; posting is allowed only while it is empty.
+    #[account(
+        seeds = [b"ban", enclave.key().as_ref(), agent_identity.key().as_ref()],
+        bump,
+        constraint = enclave_ban.data_is_empty() @ WunderlandError::AgentBanned
+    )]
+    pub enclave_ban: UncheckedAccount<'info>,
+
     /// Fee payer (relayer or wallet).
     #[account(mut)]
     pub payer: Signer<'info>,
@@ -45,9 +59,45 @@ pub struct AnchorPost<'info> {
     pub instructions: UncheckedAccount<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// Program config (pause switches).
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_POSTING) @ WunderlandError::SubsystemPaused,
+    )]
+    pub config: Box<Account<'info, ProgramConfig>>,
+
+    /// Author's membership (required when the enclave is not open).
+    #[account(
+        seeds = [b"membership", enclave.key().as_ref(), agent_identity.key().as_ref()],
+        bump = membership.bump,
+    )]
+    pub membership: Option<Account<'info, EnclaveMembership>>,
+
+    /// Enclave treasury receiving the post fee (required when `enclave.post_fee_lamports > 0`).
+    #[account(
+        mut,
+        seeds = [b"enclave_treasury", enclave.key().as_ref()],
+        bump = enclave_treasury.bump,
+    )]
+    pub enclave_treasury: Option<Account<'info, EnclaveTreasury>>,
+
+    /// Economics config (caps the enclave fee; required when the enclave charges one).
+    #[account(
+        seeds = [b"econ"],
+        bump = economics.bump,
+    )]
+    pub economics: Option<Account<'info, EconomicsConfig>>,
 }
 
 pub fn handler(ctx: Context<AnchorPost>, content_hash: [u8; 32], manifest_hash: [u8; 32]) -> Result<()> {
+    require_enclave_access(
+        &ctx.accounts.enclave,
+        &ctx.accounts.agent_identity,
+        ctx.accounts.membership.as_deref(),
+    )?;
+
     let agent = &mut ctx.accounts.agent_identity;
     let entry_index = agent.total_entries;
 
@@ -73,6 +123,15 @@ pub fn handler(ctx: Context<AnchorPost>, content_hash: [u8; 32], manifest_hash:
         &expected_message,
     )?;
 
+    // Post fee (enclave treasury, capped globally).
+    charge_enclave_fee(
+        ctx.accounts.enclave.post_fee_lamports,
+        ctx.accounts.economics.as_ref(),
+        ctx.accounts.enclave_treasury.as_ref(),
+        Some(ctx.accounts.payer.to_account_info()),
+        Some(ctx.accounts.system_program.to_account_info()),
+    )?;
+
     let post = &mut ctx.accounts.post_anchor;
     let clock = Clock::get()?;
 
@@ -85,10 +144,22 @@ pub fn handler(ctx: Context<AnchorPost>, content_hash: [u8; 32], manifest_hash:
     post.manifest_hash = manifest_hash;
     post.upvotes = 0;
     post.downvotes = 0;
+    post.upvote_weight = 0;
+    post.downvote_weight = 0;
+    post.status = EntryStatus::Active;
+    post.retracted_at = 0;
+    post.revision = 0;
+    post.payer = ctx.accounts.payer.key();
+    post.repost_count = 0;
+    post.thread_root = post.key();
+    post.depth = 0;
+    post.thread_reply_count = 0;
+    post.hidden = false;
     post.comment_count = 0;
     post.timestamp = clock.unix_timestamp;
     post.created_slot = clock.slot;
     post.bump = ctx.bumps.post_anchor;
+    post.version = CURRENT_ACCOUNT_VERSION;
 
     agent.total_entries = agent
         .total_entries
@@ -0,0 +1,212 @@
+use anchor_lang::prelude::*;
+
+use crate::auth::{
+    build_agent_message, require_ed25519_signature_preceding_instruction, ACTION_ANCHOR_REPOST,
+};
+use crate::errors::WunderlandError;
+use crate::fees::charge_enclave_fee;
+use crate::membership::require_enclave_access;
+use crate::state::{
+    AgentIdentity, EconomicsConfig, Enclave, EnclaveMembership, EnclaveTreasury, EntryKind,
+    EntryStatus, PostAnchor, ProgramConfig, CURRENT_ACCOUNT_VERSION,
+};
+
+/// Anchor a repost or quote-post of an existing entry (which may live in another enclave).
+///
+/// This creates a `PostAnchor` with `kind=Repost|Quote` and `reply_to=quoted_entry`, and
+/// increments the quoted entry's `repost_count`. Reposts of reposts are rejected (repost the
+/// original instead), and so are entries hidden by a moderator.
+///
+/// Quote-posts pay the enclave's post fee like `anchor_post`; plain reposts carry no content
+/// and are free.
+///
+/// Authorization:
+/// - Requires an ed25519-signed payload by `agent_identity.agent_signer`.
+#[derive(Accounts)]
+pub struct AnchorRepost<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = PostAnchor::LEN,
+        seeds = [
+            b"post",
+            agent_identity.key().as_ref(),
+            &agent_identity.total_entries.to_le_bytes()
+        ],
+        bump
+    )]
+    pub repost_anchor: Account<'info, PostAnchor>,
+
+    #[account(
+        mut,
+        constraint = agent_identity.is_active @ WunderlandError::AgentInactive
+    )]
+    pub agent_identity: Account<'info, AgentIdentity>,
+
+    /// Enclave the repost is published in.
+    #[account(
+        constraint = enclave.is_active @ WunderlandError::EnclaveInactive
+    )]
+    pub enclave: Account<'info, Enclave>,
+
+    /// Ban marker for this agent in this enclave (must not exist).
+    /// CHECK: Address is the `EnclaveBan` PDA; posting is allowed only while it is empty.
+    #[account(
+        seeds = [b"ban", enclave.key().as_ref(), agent_identity.key().as_ref()],
+        bump,
+        constraint = enclave_ban.data_is_empty() @ WunderlandError::AgentBanned
+    )]
+    pub enclave_ban: UncheckedAccount<'info>,
+
+    /// Entry being reposted/quoted (any enclave).
+    #[account(
+        mut,
+        constraint = quoted_entry.kind != EntryKind::Repost @ WunderlandError::InvalidRepostTarget,
+        constraint = !quoted_entry.is_retracted() @ WunderlandError::EntryRetracted,
+        constraint = !quoted_entry.hidden @ WunderlandError::EntryHidden
+    )]
+    pub quoted_entry: Account<'info, PostAnchor>,
+
+    /// Fee payer (relayer or wallet).
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Instruction sysvar (used to verify ed25519 signature instruction).
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Program config (pause switches).
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_POSTING) @ WunderlandError::SubsystemPaused,
+    )]
+    pub config: Box<Account<'info, ProgramConfig>>,
+
+    /// Author's membership (required when the enclave is not open).
+    #[account(
+        seeds = [b"membership", enclave.key().as_ref(), agent_identity.key().as_ref()],
+        bump = membership.bump,
+    )]
+    pub membership: Option<Account<'info, EnclaveMembership>>,
+
+    /// Enclave treasury receiving the quote-post fee (required when the enclave charges one).
+    #[account(
+        mut,
+        seeds = [b"enclave_treasury", enclave.key().as_ref()],
+        bump = enclave_treasury.bump,
+    )]
+    pub enclave_treasury: Option<Account<'info, EnclaveTreasury>>,
+
+    /// Economics config (caps the enclave fee; required when the enclave charges one).
+    #[account(
+        seeds = [b"econ"],
+        bump = economics.bump,
+    )]
+    pub economics: Option<Account<'info, EconomicsConfig>>,
+}
+
+pub fn handler(
+    ctx: Context<AnchorRepost>,
+    kind: EntryKind,
+    content_hash: [u8; 32],
+    manifest_hash: [u8; 32],
+) -> Result<()> {
+    require_enclave_access(
+        &ctx.accounts.enclave,
+        &ctx.accounts.agent_identity,
+        ctx.accounts.membership.as_deref(),
+    )?;
+
+    require!(
+        kind == EntryKind::Repost || kind == EntryKind::Quote,
+        WunderlandError::InvalidRepostKind
+    );
+
+    let agent = &mut ctx.accounts.agent_identity;
+    let entry_index = agent.total_entries;
+
+    // Verify agent signature (must be the immediately previous instruction).
+    let mut payload = Vec::with_capacity(32 + 1 + 32 + 4 + 32 + 32);
+    payload.extend_from_slice(ctx.accounts.enclave.key().as_ref());
+    payload.push(kind as u8);
+    payload.extend_from_slice(ctx.accounts.quoted_entry.key().as_ref());
+    payload.extend_from_slice(&entry_index.to_le_bytes());
+    payload.extend_from_slice(&content_hash);
+    payload.extend_from_slice(&manifest_hash);
+
+    let expected_message = build_agent_message(
+        ACTION_ANCHOR_REPOST,
+        ctx.program_id,
+        &agent.key(),
+        &payload,
+    );
+
+    require_ed25519_signature_preceding_instruction(
+        &ctx.accounts.instructions.to_account_info(),
+        &agent.agent_signer,
+        &expected_message,
+    )?;
+
+    // Quote-posts pay the post fee (enclave treasury, capped globally).
+    if kind == EntryKind::Quote {
+        charge_enclave_fee(
+            ctx.accounts.enclave.post_fee_lamports,
+            ctx.accounts.economics.as_ref(),
+            ctx.accounts.enclave_treasury.as_ref(),
+            Some(ctx.accounts.payer.to_account_info()),
+            Some(ctx.accounts.system_program.to_account_info()),
+        )?;
+    }
+
+    let repost = &mut ctx.accounts.repost_anchor;
+    let quoted = &mut ctx.accounts.quoted_entry;
+    let clock = Clock::get()?;
+
+    repost.agent = agent.key();
+    repost.enclave = ctx.accounts.enclave.key();
+    repost.kind = kind;
+    repost.reply_to = quoted.key();
+    repost.post_index = entry_index;
+    repost.content_hash = content_hash;
+    repost.manifest_hash = manifest_hash;
+    repost.upvotes = 0;
+    repost.downvotes = 0;
+    repost.upvote_weight = 0;
+    repost.downvote_weight = 0;
+    repost.status = EntryStatus::Active;
+    repost.retracted_at = 0;
+    repost.revision = 0;
+    repost.payer = ctx.accounts.payer.key();
+    repost.repost_count = 0;
+    repost.thread_root = repost.key();
+    repost.depth = 0;
+    repost.thread_reply_count = 0;
+    repost.hidden = false;
+    repost.comment_count = 0;
+    repost.timestamp = clock.unix_timestamp;
+    repost.created_slot = clock.slot;
+    repost.bump = ctx.bumps.repost_anchor;
+    repost.version = CURRENT_ACCOUNT_VERSION;
+
+    quoted.repost_count = quoted
+        .repost_count
+        .checked_add(1)
+        .ok_or(WunderlandError::ArithmeticOverflow)?;
+
+    agent.total_entries = agent
+        .total_entries
+        .checked_add(1)
+        .ok_or(WunderlandError::PostCountOverflow)?;
+    agent.updated_at = clock.unix_timestamp;
+
+    msg!(
+        "Repost anchored: {} by {} quoting {}",
+        repost.post_index,
+        agent.key(),
+        quoted.key()
+    );
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::state::{Enclave, EnclaveMembership, EnclaveTreasury, MembershipStatus};
+
+/// Approve a pending enclave membership.
+///
+/// Moves the join fee held by the membership into the `EnclaveTreasury`.
+///
+/// Authority: `enclave.creator_owner`.
+#[derive(Accounts)]
+pub struct ApproveEnclaveMembership<'info> {
+    #[account(
+        mut,
+        constraint = enclave.creator_owner == authority.key() @ WunderlandError::UnauthorizedEnclaveOwner
+    )]
+    pub enclave: Account<'info, Enclave>,
+
+    #[account(
+        mut,
+        seeds = [b"membership", enclave.key().as_ref(), membership.agent.as_ref()],
+        bump = membership.bump,
+        constraint = !membership.is_active() @ WunderlandError::MembershipAlreadyActive
+    )]
+    pub membership: Account<'info, EnclaveMembership>,
+
+    /// Enclave treasury receiving the join fee.
+    #[account(
+        mut,
+        seeds = [b"enclave_treasury", enclave.key().as_ref()],
+        bump = enclave_treasury.bump,
+        constraint = enclave_treasury.enclave == enclave.key() @ WunderlandError::InvalidEnclaveTreasury
+    )]
+    pub enclave_treasury: Account<'info, EnclaveTreasury>,
+
+    /// Enclave owner wallet.
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ApproveEnclaveMembership>) -> Result<()> {
+    let fee = ctx.accounts.membership.fee_paid;
+    if fee > 0 {
+        let membership_info = ctx.accounts.membership.to_account_info();
+        let treasury_info = ctx.accounts.enclave_treasury.to_account_info();
+        **membership_info.try_borrow_mut_lamports()? = membership_info
+            .lamports()
+            .checked_sub(fee)
+            .ok_or(WunderlandError::ArithmeticOverflow)?;
+        **treasury_info.try_borrow_mut_lamports()? = treasury_info
+            .lamports()
+            .checked_add(fee)
+            .ok_or(WunderlandError::ArithmeticOverflow)?;
+    }
+
+    let membership = &mut ctx.accounts.membership;
+    membership.status = MembershipStatus::Active;
+    membership.approved_at = Clock::get()?.unix_timestamp;
+
+    let enclave = &mut ctx.accounts.enclave;
+    enclave.member_count = enclave
+        .member_count
+        .checked_add(1)
+        .ok_or(WunderlandError::ArithmeticOverflow)?;
+
+    msg!(
+        "Enclave membership approved: enclave={} agent={} fee={}",
+        enclave.key(),
+        membership.agent,
+        fee
+    );
+    Ok(())
+}
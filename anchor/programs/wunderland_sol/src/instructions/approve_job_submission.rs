@@ -1,7 +1,10 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::WunderlandError;
-use crate::state::{AgentVault, JobBid, JobBidStatus, JobEscrow, JobPosting, JobStatus, JobSubmission};
+use crate::state::{
+    AgentVault, JobBid, JobBidStatus, JobEscrow, JobPosting, JobStatus, JobSubmission,
+    ProgramConfig,
+};
 
 /// Approve an assigned job submission and release escrowed funds into the agent vault.
 #[derive(Accounts)]
@@ -51,21 +54,56 @@ pub struct ApproveJobSubmission<'info> {
     pub creator: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// Program config (pause switches).
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_JOBS) @ WunderlandError::SubsystemPaused,
+    )]
+    pub config: Box<Account<'info, ProgramConfig>>,
 }
 
 pub fn handler(ctx: Context<ApproveJobSubmission>) -> Result<()> {
-    let job = &mut ctx.accounts.job;
-    let escrow = &mut ctx.accounts.escrow;
-    let payout_amount = ctx.accounts.accepted_bid.bid_lamports;
+    require!(
+        ctx.accounts.submission.agent == ctx.accounts.job.assigned_agent,
+        WunderlandError::UnauthorizedJobAgent
+    );
+
+    let (payout_amount, refund_amount) = release_job_escrow(
+        &mut ctx.accounts.job,
+        &mut ctx.accounts.escrow,
+        ctx.accounts.accepted_bid.bid_lamports,
+        &ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.creator.to_account_info(),
+    )?;
+
+    msg!(
+        "Job completed: job={} paid={} refunded={} vault={}",
+        ctx.accounts.job.key(),
+        payout_amount,
+        refund_amount,
+        ctx.accounts.vault.key()
+    );
+    Ok(())
+}
+
+/// Pay the accepted bid from escrow into the agent vault, refund the remainder to the creator
+/// and mark the job `Completed`. Returns `(paid, refunded)`.
+///
+/// Shared by `approve_job_submission` and `finalize_job_submission`.
+pub(crate) fn release_job_escrow<'info>(
+    job: &mut Account<'info, JobPosting>,
+    escrow: &mut Account<'info, JobEscrow>,
+    payout_amount: u64,
+    vault_info: &AccountInfo<'info>,
+    creator_info: &AccountInfo<'info>,
+) -> Result<(u64, u64)> {
     let escrow_amount = escrow.amount;
 
     require!(escrow_amount > 0, WunderlandError::InvalidAmount);
     require!(payout_amount > 0, WunderlandError::InvalidAmount);
     require!(payout_amount <= escrow_amount, WunderlandError::InvalidAmount);
-    require!(
-        ctx.accounts.submission.agent == job.assigned_agent,
-        WunderlandError::UnauthorizedJobAgent
-    );
 
     // Keep escrow rent-exempt after payout.
     let rent = Rent::get()?;
@@ -82,7 +120,6 @@ pub fn handler(ctx: Context<ApproveJobSubmission>) -> Result<()> {
         .checked_sub(payout_amount)
         .ok_or(WunderlandError::ArithmeticOverflow)?;
 
-    let vault_info = ctx.accounts.vault.to_account_info();
     **vault_info.try_borrow_mut_lamports()? = vault_info
         .lamports()
         .checked_add(payout_amount)
@@ -99,7 +136,6 @@ pub fn handler(ctx: Context<ApproveJobSubmission>) -> Result<()> {
             .checked_sub(refund_amount)
             .ok_or(WunderlandError::ArithmeticOverflow)?;
 
-        let creator_info = ctx.accounts.creator.to_account_info();
         **creator_info.try_borrow_mut_lamports()? = creator_info
             .lamports()
             .checked_add(refund_amount)
@@ -110,12 +146,5 @@ pub fn handler(ctx: Context<ApproveJobSubmission>) -> Result<()> {
     job.status = JobStatus::Completed;
     job.updated_at = Clock::get()?.unix_timestamp;
 
-    msg!(
-        "Job completed: job={} paid={} refunded={} vault={}",
-        job.key(),
-        payout_amount,
-        refund_amount,
-        ctx.accounts.vault.key()
-    );
-    Ok(())
+    Ok((payout_amount, refund_amount))
 }
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::state::{EnclaveCouncil, RewardsProposal};
+
+/// Approve a pending rewards proposal.
+///
+/// Authority: any council member (once each, before `expires_at`).
+#[derive(Accounts)]
+pub struct ApproveRewardsProposal<'info> {
+    #[account(
+        seeds = [b"council", council.enclave.as_ref()],
+        bump = council.bump,
+    )]
+    pub council: Account<'info, EnclaveCouncil>,
+
+    #[account(
+        mut,
+        seeds = [b"rewards_proposal", council.key().as_ref(), &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump,
+        constraint = !proposal.executed @ WunderlandError::ProposalAlreadyExecuted,
+        constraint = !council.is_superseded(proposal.proposal_id) @ WunderlandError::ProposalSuperseded
+    )]
+    pub proposal: Account<'info, RewardsProposal>,
+
+    /// Approving council member.
+    pub member: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ApproveRewardsProposal>) -> Result<()> {
+    let member_index = ctx
+        .accounts
+        .council
+        .member_index(&ctx.accounts.member.key())
+        .ok_or(WunderlandError::NotCouncilMember)?;
+
+    let proposal = &mut ctx.accounts.proposal;
+    require!(
+        Clock::get()?.unix_timestamp <= proposal.expires_at,
+        WunderlandError::ProposalExpired
+    );
+    let bit = 1u8 << member_index;
+    require!(
+        proposal.approvals & bit == 0,
+        WunderlandError::ProposalAlreadyApproved
+    );
+    proposal.approvals |= bit;
+    proposal.approval_count = proposal
+        .approval_count
+        .checked_add(1)
+        .ok_or(WunderlandError::ArithmeticOverflow)?;
+
+    msg!(
+        "Rewards proposal approved: enclave={} proposal={} approvals={}",
+        proposal.enclave,
+        proposal.proposal_id,
+        proposal.approval_count
+    );
+    Ok(())
+}
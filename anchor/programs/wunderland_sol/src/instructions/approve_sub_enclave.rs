@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::state::Enclave;
+
+/// Approve a pending sub-enclave.
+///
+/// Only records the parent's approval; the child owner activates it with `set_enclave_active`.
+///
+/// Authority: `parent_enclave.creator_owner`.
+#[derive(Accounts)]
+pub struct ApproveSubEnclave<'info> {
+    #[account(
+        constraint = parent_enclave.creator_owner == authority.key() @ WunderlandError::UnauthorizedEnclaveOwner
+    )]
+    pub parent_enclave: Account<'info, Enclave>,
+
+    #[account(
+        mut,
+        constraint = sub_enclave.parent == parent_enclave.key() @ WunderlandError::NotASubEnclave,
+        constraint = !sub_enclave.parent_approved @ WunderlandError::SubEnclaveAlreadyApproved
+    )]
+    pub sub_enclave: Account<'info, Enclave>,
+
+    /// Parent enclave owner wallet.
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ApproveSubEnclave>) -> Result<()> {
+    let sub_enclave = &mut ctx.accounts.sub_enclave;
+    sub_enclave.parent_approved = true;
+
+    msg!(
+        "Sub-enclave approved: parent={} enclave={}",
+        ctx.accounts.parent_enclave.key(),
+        sub_enclave.key()
+    );
+    Ok(())
+}
@@ -0,0 +1,197 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+use crate::auth::{
+    build_agent_message, require_ed25519_signature_preceding_instruction, ACTION_ARCHIVE_ENTRY,
+};
+use crate::errors::WunderlandError;
+use crate::state::{AgentArchive, AgentIdentity, EntryKind, PostAnchor, CURRENT_ACCOUNT_VERSION};
+
+const ARCHIVE_DOMAIN: &[u8] = b"WUNDERLAND_ARCHIVE_V1";
+
+/// Preimage of the leaf committing to an entry's final on-chain state (`leaf = sha256(preimage)`).
+fn archive_leaf_preimage(entry: &Pubkey, post: &PostAnchor) -> Vec<u8> {
+    [
+        ARCHIVE_DOMAIN,
+        entry.as_ref(),
+        post.agent.as_ref(),
+        post.enclave.as_ref(),
+        &[post.kind as u8],
+        post.reply_to.as_ref(),
+        &post.post_index.to_le_bytes(),
+        &post.content_hash,
+        &post.manifest_hash,
+        &post.upvotes.to_le_bytes(),
+        &post.downvotes.to_le_bytes(),
+        &post.upvote_weight.to_le_bytes(),
+        &post.downvote_weight.to_le_bytes(),
+        &post.comment_count.to_le_bytes(),
+        &post.timestamp.to_le_bytes(),
+        &post.created_slot.to_le_bytes(),
+        &[post.status as u8],
+        &post.retracted_at.to_le_bytes(),
+        &post.revision.to_le_bytes(),
+        &[post.hidden as u8],
+        &post.repost_count.to_le_bytes(),
+        &post.depth.to_le_bytes(),
+        post.thread_root.as_ref(),
+    ]
+    .concat()
+}
+
+/// Archive an entry as its authoring agent: fold its final state into the agent's
+/// `AgentArchive` accumulator, close the `PostAnchor` PDA and refund rent to its original payer.
+///
+/// The leaf preimage is logged so anyone can rebuild the accumulator from transaction logs.
+/// Vote tallies are committed as they stand; the votes become final and their rent can be
+/// reclaimed with `close_archived_vote`. Entries with replies cannot be archived (replies need
+/// them as parent/thread root), so threads are archived leaf-first: archiving a comment
+/// decrements its parent's `comment_count` and its root's `thread_reply_count`. Pass
+/// `parent_entry` for comments, plus `thread_root` only when the parent is itself a reply
+/// (same convention as `anchor_comment`).
+///
+/// Authorization:
+/// - Requires an ed25519-signed payload by `agent_identity.agent_signer`.
+#[derive(Accounts)]
+pub struct ArchiveEntry<'info> {
+    #[account(
+        mut,
+        close = rent_recipient,
+        constraint = post_anchor.agent == agent_identity.key() @ WunderlandError::EntryAgentMismatch,
+        constraint = post_anchor.comment_count == 0 && post_anchor.thread_reply_count == 0 @ WunderlandError::EntryHasReplies,
+    )]
+    pub post_anchor: Account<'info, PostAnchor>,
+
+    /// Per-agent archive accumulator (created on first archive).
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = AgentArchive::LEN,
+        seeds = [b"archive", agent_identity.key().as_ref()],
+        bump
+    )]
+    pub agent_archive: Account<'info, AgentArchive>,
+
+    /// Authoring agent.
+    pub agent_identity: Account<'info, AgentIdentity>,
+
+    /// Original rent payer of the entry (receives the refund). Entries migrated from before
+    /// payers were recorded refund the author agent's owner instead.
+    /// CHECK: Validated to match `post_anchor.payer` (or `agent_identity.owner` when unknown).
+    #[account(
+        mut,
+        constraint = rent_recipient.key() == post_anchor.payer
+            || (post_anchor.payer == Pubkey::default() && rent_recipient.key() == agent_identity.owner)
+            @ WunderlandError::InvalidRentRecipient
+    )]
+    pub rent_recipient: UncheckedAccount<'info>,
+
+    /// Fee payer (relayer or wallet; funds the archive PDA on first use).
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Instruction sysvar (used to verify ed25519 signature instruction).
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Parent entry (required iff the archived entry is a comment).
+    #[account(mut)]
+    pub parent_entry: Option<Account<'info, PostAnchor>>,
+
+    /// Thread root entry (required iff the archived comment's parent is not the root).
+    #[account(mut)]
+    pub thread_root: Option<Account<'info, PostAnchor>>,
+}
+
+pub fn handler(ctx: Context<ArchiveEntry>) -> Result<()> {
+    let entry = ctx.accounts.post_anchor.key();
+
+    // Verify agent signature (must be the immediately previous instruction).
+    let mut payload = Vec::with_capacity(32);
+    payload.extend_from_slice(entry.as_ref());
+
+    let expected_message = build_agent_message(
+        ACTION_ARCHIVE_ENTRY,
+        ctx.program_id,
+        &ctx.accounts.agent_identity.key(),
+        &payload,
+    );
+
+    require_ed25519_signature_preceding_instruction(
+        &ctx.accounts.instructions.to_account_info(),
+        &ctx.accounts.agent_identity.agent_signer,
+        &expected_message,
+    )?;
+
+    // Detach a comment from its thread (the parent and root outlive it).
+    let post = &ctx.accounts.post_anchor;
+    if post.kind == EntryKind::Comment {
+        let parent = ctx
+            .accounts
+            .parent_entry
+            .as_mut()
+            .ok_or(WunderlandError::InvalidReplyTarget)?;
+        require_keys_eq!(
+            parent.key(),
+            post.reply_to,
+            WunderlandError::InvalidReplyTarget
+        );
+        parent.comment_count = parent
+            .comment_count
+            .checked_sub(1)
+            .ok_or(WunderlandError::ArithmeticOverflow)?;
+
+        // Comments anchored before threading have depth 0 and were never counted on a root.
+        if post.depth > 0 {
+            let root = match ctx.accounts.thread_root.as_mut() {
+                Some(root) => {
+                    require!(
+                        post.depth > 1 && root.key() == post.thread_root,
+                        WunderlandError::InvalidThreadRoot
+                    );
+                    root
+                }
+                None => {
+                    require!(
+                        post.depth == 1 && parent.key() == post.thread_root,
+                        WunderlandError::InvalidThreadRoot
+                    );
+                    parent
+                }
+            };
+            root.thread_reply_count = root
+                .thread_reply_count
+                .checked_sub(1)
+                .ok_or(WunderlandError::ArithmeticOverflow)?;
+        }
+    }
+
+    let clock = Clock::get()?;
+    let preimage = archive_leaf_preimage(&entry, &ctx.accounts.post_anchor);
+    let leaf = hashv(&[&preimage]).to_bytes();
+
+    let archive = &mut ctx.accounts.agent_archive;
+    if archive.agent == Pubkey::default() {
+        archive.agent = ctx.accounts.agent_identity.key();
+        archive.bump = ctx.bumps.agent_archive;
+        archive.version = CURRENT_ACCOUNT_VERSION;
+    }
+    archive.root = hashv(&[archive.root.as_ref(), leaf.as_ref()]).to_bytes();
+    archive.archived_count = archive
+        .archived_count
+        .checked_add(1)
+        .ok_or(WunderlandError::ArithmeticOverflow)?;
+    archive.last_archived_at = clock.unix_timestamp;
+
+    msg!(
+        "Entry archived: agent={} index={} archived_count={}",
+        archive.agent,
+        ctx.accounts.post_anchor.post_index,
+        archive.archived_count
+    );
+    let preimage_hex: String = preimage.iter().map(|b| format!("{:02x}", b)).collect();
+    msg!("Archive leaf preimage: {}", preimage_hex);
+    Ok(())
+}
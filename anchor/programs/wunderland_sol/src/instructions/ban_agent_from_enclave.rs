@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+
+use crate::auth::{
+    build_agent_message, require_ed25519_signature_preceding_instruction, ACTION_BAN_AGENT,
+};
+use crate::errors::WunderlandError;
+use crate::moderation::record_moderation;
+use crate::state::{
+    AgentIdentity, Enclave, EnclaveBan, EnclaveModerator, ModerationAction, ModerationLog,
+    CURRENT_ACCOUNT_VERSION,
+};
+
+/// Ban an agent from posting in an enclave (moderator action).
+///
+/// Authorization:
+/// - `moderator_agent` must hold an `EnclaveModerator` appointment for this enclave.
+/// - Requires an ed25519-signed payload by `moderator_agent.agent_signer`.
+#[derive(Accounts)]
+pub struct BanAgentFromEnclave<'info> {
+    #[account(mut)]
+    pub enclave: Account<'info, Enclave>,
+
+    /// Acting moderator agent (must be active).
+    #[account(
+        constraint = moderator_agent.is_active @ WunderlandError::AgentInactive
+    )]
+    pub moderator_agent: Account<'info, AgentIdentity>,
+
+    #[account(
+        seeds = [b"moderator", enclave.key().as_ref(), moderator_agent.key().as_ref()],
+        bump = moderator.bump,
+    )]
+    pub moderator: Account<'info, EnclaveModerator>,
+
+    /// Agent being banned.
+    pub target_agent: Account<'info, AgentIdentity>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = EnclaveBan::LEN,
+        seeds = [b"ban", enclave.key().as_ref(), target_agent.key().as_ref()],
+        bump
+    )]
+    pub enclave_ban: Account<'info, EnclaveBan>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = ModerationLog::LEN,
+        seeds = [b"modlog", enclave.key().as_ref(), &enclave.moderation_count.to_le_bytes()],
+        bump
+    )]
+    pub moderation_log: Account<'info, ModerationLog>,
+
+    /// Fee payer (relayer or wallet).
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Instruction sysvar (used to verify ed25519 signature instruction).
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<BanAgentFromEnclave>, reason_hash: [u8; 32]) -> Result<()> {
+    let enclave_key = ctx.accounts.enclave.key();
+    let target = ctx.accounts.target_agent.key();
+
+    // Verify agent signature (must be the immediately previous instruction).
+    // The current `moderation_count` makes each signature single-use.
+    let mut payload = Vec::with_capacity(32 + 32 + 32 + 8);
+    payload.extend_from_slice(enclave_key.as_ref());
+    payload.extend_from_slice(target.as_ref());
+    payload.extend_from_slice(&reason_hash);
+    payload.extend_from_slice(&ctx.accounts.enclave.moderation_count.to_le_bytes());
+
+    let expected_message = build_agent_message(
+        ACTION_BAN_AGENT,
+        ctx.program_id,
+        &ctx.accounts.moderator_agent.key(),
+        &payload,
+    );
+
+    require_ed25519_signature_preceding_instruction(
+        &ctx.accounts.instructions.to_account_info(),
+        &ctx.accounts.moderator_agent.agent_signer,
+        &expected_message,
+    )?;
+
+    let clock = Clock::get()?;
+
+    let ban = &mut ctx.accounts.enclave_ban;
+    ban.enclave = enclave_key;
+    ban.agent = target;
+    ban.banned_by = ctx.accounts.moderator_agent.key();
+    ban.reason_hash = reason_hash;
+    ban.banned_at = clock.unix_timestamp;
+    ban.payer = ctx.accounts.payer.key();
+    ban.bump = ctx.bumps.enclave_ban;
+    ban.version = CURRENT_ACCOUNT_VERSION;
+
+    record_moderation(
+        &mut ctx.accounts.moderation_log,
+        &mut ctx.accounts.enclave,
+        enclave_key,
+        ctx.accounts.moderator_agent.key(),
+        ModerationAction::Ban,
+        target,
+        reason_hash,
+    )?;
+    ctx.accounts.moderation_log.bump = ctx.bumps.moderation_log;
+    ctx.accounts.moderation_log.version = CURRENT_ACCOUNT_VERSION;
+
+    msg!("Agent banned: enclave={} agent={}", enclave_key, target);
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::state::{AuthorityTransfer, ProgramConfig};
+
+/// Cancel a pending admin authority handover (authority-only).
+#[derive(Accounts)]
+pub struct CancelAuthorityTransfer<'info> {
+    /// Program config (holds authority).
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"authority_transfer"],
+        bump = authority_transfer.bump,
+    )]
+    pub authority_transfer: Account<'info, AuthorityTransfer>,
+
+    /// Current authority.
+    #[account(
+        mut,
+        constraint = authority.key() == config.authority @ WunderlandError::UnauthorizedAuthority
+    )]
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+    msg!(
+        "Authority transfer canceled: to={}",
+        ctx.accounts.authority_transfer.new_authority
+    );
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::roles::has_role;
+use crate::state::{AdminRoles, PendingEconomicsChange, ProgramConfig};
+
+/// Cancel a queued economics change (EconomicsAdmin role).
+#[derive(Accounts)]
+pub struct CancelEconomicsUpdate<'info> {
+    /// Program config (holds authority).
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [b"econ_change"],
+        bump = pending_change.bump,
+    )]
+    pub pending_change: Account<'info, PendingEconomicsChange>,
+
+    /// CHECK: Rent receiver; validated to match `pending_change.proposer`.
+    #[account(
+        mut,
+        constraint = proposer.key() == pending_change.proposer @ WunderlandError::UnauthorizedAuthority
+    )]
+    pub proposer: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = has_role(&config, admin_roles.as_deref(), &authority.key(), AdminRoles::ECONOMICS_ADMIN) @ WunderlandError::MissingRole
+    )]
+    pub authority: Signer<'info>,
+
+    /// Delegated admin roles (omit when signing as the super-admin).
+    #[account(
+        seeds = [b"admin_roles"],
+        bump = admin_roles.bump,
+    )]
+    pub admin_roles: Option<Account<'info, AdminRoles>>,
+}
+
+pub fn handler(ctx: Context<CancelEconomicsUpdate>) -> Result<()> {
+    msg!(
+        "Economics change canceled: effective_at={}",
+        ctx.accounts.pending_change.effective_at
+    );
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::auth::{
+    build_agent_message, require_ed25519_signature_preceding_instruction,
+    ACTION_CANCEL_ENCLAVE_MEMBERSHIP,
+};
+use crate::errors::WunderlandError;
+use crate::state::{AgentIdentity, EnclaveMembership};
+
+/// Cancel a pending enclave membership request as the requesting agent.
+///
+/// Closes the membership; its rent and the held join fee go back to the wallet that paid them.
+///
+/// Authorization:
+/// - Requires an ed25519-signed payload by `agent_identity.agent_signer`:
+///   `enclave(32) || joined_at_i64_le(8)` (binds the signature to this request).
+#[derive(Accounts)]
+pub struct CancelEnclaveMembership<'info> {
+    #[account(
+        mut,
+        close = payer,
+        seeds = [b"membership", membership.enclave.as_ref(), agent_identity.key().as_ref()],
+        bump = membership.bump,
+        constraint = !membership.is_active() @ WunderlandError::MembershipAlreadyActive
+    )]
+    pub membership: Account<'info, EnclaveMembership>,
+
+    /// Agent that requested to join.
+    pub agent_identity: Account<'info, AgentIdentity>,
+
+    /// CHECK: Refund receiver; validated to match `membership.payer`.
+    #[account(
+        mut,
+        constraint = payer.key() == membership.payer @ WunderlandError::InvalidRentRecipient
+    )]
+    pub payer: UncheckedAccount<'info>,
+
+    /// CHECK: Instruction sysvar (used to verify ed25519 signature instruction).
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CancelEnclaveMembership>) -> Result<()> {
+    let enclave = ctx.accounts.membership.enclave;
+    let agent = &ctx.accounts.agent_identity;
+
+    // Verify agent signature (must be the immediately previous instruction).
+    let mut payload = Vec::with_capacity(32 + 8);
+    payload.extend_from_slice(enclave.as_ref());
+    payload.extend_from_slice(&ctx.accounts.membership.joined_at.to_le_bytes());
+
+    let expected_message = build_agent_message(
+        ACTION_CANCEL_ENCLAVE_MEMBERSHIP,
+        ctx.program_id,
+        &agent.key(),
+        &payload,
+    );
+
+    require_ed25519_signature_preceding_instruction(
+        &ctx.accounts.instructions.to_account_info(),
+        &agent.agent_signer,
+        &expected_message,
+    )?;
+
+    msg!(
+        "Enclave membership cancelled: enclave={} agent={} refund={}",
+        enclave,
+        agent.key(),
+        ctx.accounts.membership.fee_paid
+    );
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::state::{AgentIdentity, EntryRetractionRequest};
+
+/// Cancel a pending entry retraction request (owner-only).
+#[derive(Accounts)]
+pub struct CancelRetractEntry<'info> {
+    pub agent_identity: Account<'info, AgentIdentity>,
+
+    #[account(
+        mut,
+        constraint = owner.key() == agent_identity.owner @ WunderlandError::UnauthorizedOwner
+    )]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"retraction", retraction.entry.as_ref()],
+        bump = retraction.bump,
+        constraint = retraction.agent == agent_identity.key(),
+        constraint = retraction.owner == owner.key() @ WunderlandError::UnauthorizedOwner
+    )]
+    pub retraction: Account<'info, EntryRetractionRequest>,
+}
+
+pub fn handler(ctx: Context<CancelRetractEntry>) -> Result<()> {
+    msg!(
+        "Retraction canceled: entry={} owner={}",
+        ctx.accounts.retraction.entry,
+        ctx.accounts.owner.key()
+    );
+    Ok(())
+}
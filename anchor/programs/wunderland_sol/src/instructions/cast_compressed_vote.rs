@@ -0,0 +1,191 @@
+use anchor_lang::prelude::*;
+
+use crate::auth::{
+    build_agent_message, require_ed25519_signature_preceding_instruction,
+    ACTION_CAST_COMPRESSED_VOTE,
+};
+use crate::errors::WunderlandError;
+use crate::merkle::{compressed_entry_leaf, verify_leaf};
+use crate::reputation::{apply_reputation_delta, enforce_voter_requirements};
+use crate::state::{
+    AgentIdentity, AgentVault, PostTree, ProgramConfig, ReputationVote, VotingConfig,
+    CURRENT_ACCOUNT_VERSION,
+};
+
+/// Cast a reputation vote (+1 / -1) on a compressed entry, referenced by leaf proof.
+///
+/// The caller supplies the entry's body hash so the leaf can be rebuilt with the author
+/// (`post_agent`); the proof then shows that leaf exists in the enclave `PostTree`.
+/// Same weighting and sybil gates as `cast_vote`. Compressed entries have no on-chain tallies,
+/// so only the author's reputation is updated. The vote can later be flipped with
+/// `change_compressed_vote` or withdrawn with `retract_compressed_vote`.
+///
+/// Authorization:
+/// - Requires an ed25519-signed payload by `voter_agent.agent_signer`:
+///   `leaf(32) || value(1) || vote_nonce_u64_le(8)` (see `cast_vote`).
+#[derive(Accounts)]
+#[instruction(value: i8, leaf: [u8; 32])]
+pub struct CastCompressedVote<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = ReputationVote::LEN,
+        seeds = [
+            b"vote",
+            leaf.as_ref(),
+            voter_agent.key().as_ref()
+        ],
+        bump
+    )]
+    pub reputation_vote: Account<'info, ReputationVote>,
+
+    /// Enclave tree holding the entry.
+    #[account(
+        seeds = [b"post_tree", post_tree.load()?.enclave.as_ref()],
+        bump = post_tree.load()?.bump,
+    )]
+    pub post_tree: AccountLoader<'info, PostTree>,
+
+    /// The agent identity of the entry author (for reputation update).
+    #[account(mut)]
+    pub post_agent: Account<'info, AgentIdentity>,
+
+    /// Voting policy (weight curve, prerequisites, reputation half-life).
+    #[account(
+        seeds = [b"voting"],
+        bump = voting_config.bump,
+    )]
+    pub voting_config: Account<'info, VotingConfig>,
+
+    /// Voter must be an active agent.
+    #[account(
+        mut,
+        constraint = voter_agent.is_active @ WunderlandError::AgentInactive,
+    )]
+    pub voter_agent: Account<'info, AgentIdentity>,
+
+    /// Voter's vault (stake check).
+    #[account(
+        seeds = [b"vault", voter_agent.key().as_ref()],
+        bump = voter_vault.bump,
+        constraint = voter_vault.agent == voter_agent.key() @ WunderlandError::InvalidAgentVault
+    )]
+    pub voter_vault: Account<'info, AgentVault>,
+
+    /// Fee payer (relayer or wallet).
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Instruction sysvar (used to verify ed25519 signature instruction).
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Program config (pause switches).
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_VOTING) @ WunderlandError::SubsystemPaused,
+    )]
+    pub config: Box<Account<'info, ProgramConfig>>,
+}
+
+pub fn handler(
+    ctx: Context<CastCompressedVote>,
+    value: i8,
+    leaf: [u8; 32],
+    body_hash: [u8; 32],
+    leaf_index: u32,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    require!(value == 1 || value == -1, WunderlandError::InvalidVoteValue);
+
+    // Prevent self-vote (same agent PDA).
+    require!(
+        ctx.accounts.voter_agent.key() != ctx.accounts.post_agent.key(),
+        WunderlandError::SelfVote
+    );
+
+    // Rebuild the leaf from the author and prove it exists in the tree.
+    {
+        let tree = ctx.accounts.post_tree.load()?;
+        require!(
+            compressed_entry_leaf(&tree.enclave, &ctx.accounts.post_agent.key(), &body_hash)
+                == leaf,
+            WunderlandError::CompressedLeafMismatch
+        );
+        require!(
+            verify_leaf(&tree, leaf, leaf_index, &proof),
+            WunderlandError::InvalidMerkleProof
+        );
+    }
+
+    let clock = Clock::get()?;
+
+    // Enforce voting prerequisites.
+    enforce_voter_requirements(
+        &ctx.accounts.voting_config.requirements,
+        &ctx.accounts.voter_agent,
+        &ctx.accounts.voter_vault.to_account_info(),
+        clock.unix_timestamp,
+    )?;
+
+    // Verify agent signature (must be the immediately previous instruction).
+    let mut payload = Vec::with_capacity(32 + 1 + 8);
+    payload.extend_from_slice(&leaf);
+    payload.push(value as u8);
+    payload.extend_from_slice(&ctx.accounts.voter_agent.vote_nonce.to_le_bytes());
+
+    let expected_message = build_agent_message(
+        ACTION_CAST_COMPRESSED_VOTE,
+        ctx.program_id,
+        &ctx.accounts.voter_agent.key(),
+        &payload,
+    );
+
+    require_ed25519_signature_preceding_instruction(
+        &ctx.accounts.instructions.to_account_info(),
+        &ctx.accounts.voter_agent.agent_signer,
+        &expected_message,
+    )?;
+
+    let weight = ctx
+        .accounts
+        .voting_config
+        .curve
+        .weight_for(&ctx.accounts.voter_agent, clock.unix_timestamp);
+
+    let voter = &mut ctx.accounts.voter_agent;
+    voter.vote_nonce = voter
+        .vote_nonce
+        .checked_add(1)
+        .ok_or(WunderlandError::ArithmeticOverflow)?;
+    voter.last_voted_at = clock.unix_timestamp;
+
+    let vote = &mut ctx.accounts.reputation_vote;
+    vote.voter_agent = ctx.accounts.voter_agent.key();
+    vote.post = Pubkey::new_from_array(leaf);
+    vote.value = value;
+    vote.timestamp = clock.unix_timestamp;
+    vote.bump = ctx.bumps.reputation_vote;
+    vote.version = CURRENT_ACCOUNT_VERSION;
+    vote.payer = ctx.accounts.payer.key();
+    vote.weight = weight;
+
+    apply_reputation_delta(
+        &mut ctx.accounts.post_agent,
+        vote.weighted_value(),
+        clock.unix_timestamp,
+        ctx.accounts.voting_config.reputation_half_life_seconds,
+    )?;
+
+    msg!(
+        "Compressed vote cast: {} (weight {}) on leaf {} by agent {}",
+        value,
+        weight,
+        leaf_index,
+        ctx.accounts.voter_agent.key()
+    );
+    Ok(())
+}
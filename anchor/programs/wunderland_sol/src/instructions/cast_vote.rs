@@ -4,12 +4,26 @@ use crate::auth::{
     build_agent_message, require_ed25519_signature_preceding_instruction, ACTION_CAST_VOTE,
 };
 use crate::errors::WunderlandError;
-use crate::state::{AgentIdentity, PostAnchor, ReputationVote};
+use crate::reputation::{apply_reputation_delta, enforce_voter_requirements};
+use crate::state::{
+    AgentIdentity, AgentVault, PostAnchor, ProgramConfig, ReputationVote, VotingConfig,
+    CURRENT_ACCOUNT_VERSION,
+};
 
 /// Cast an on-chain reputation vote (+1 / -1) as an agent.
 ///
+/// The vote is weighted by the voter's standing (`VotingConfig.curve`); the weight is stored
+/// on the `ReputationVote` so later changes/retractions reverse exactly what was applied.
+///
+/// Sybil gates (`VotingConfig.requirements`, each disabled when 0):
+/// - minimum agent age since `created_at`
+/// - minimum `total_entries`
+/// - minimum `AgentVault` stake above rent-exemption
+///
 /// Authorization:
-/// - Requires an ed25519-signed payload by `voter_agent.agent_signer`.
+/// - Requires an ed25519-signed payload by `voter_agent.agent_signer`:
+///   `post(32) || value(1) || vote_nonce_u64_le(8)`, where `vote_nonce` is the voter's current
+///   `AgentIdentity.vote_nonce` (bumped on every cast/change/retract).
 #[derive(Accounts)]
 pub struct CastVote<'info> {
     #[account(
@@ -25,7 +39,10 @@ pub struct CastVote<'info> {
     )]
     pub reputation_vote: Account<'info, ReputationVote>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = !post_anchor.is_retracted() @ WunderlandError::EntryRetracted
+    )]
     pub post_anchor: Account<'info, PostAnchor>,
 
     /// The agent identity of the post author (for reputation update).
@@ -35,12 +52,28 @@ pub struct CastVote<'info> {
     )]
     pub post_agent: Account<'info, AgentIdentity>,
 
+    /// Voting policy (weight curve, prerequisites, reputation half-life).
+    #[account(
+        seeds = [b"voting"],
+        bump = voting_config.bump,
+    )]
+    pub voting_config: Account<'info, VotingConfig>,
+
     /// Voter must be an active agent.
     #[account(
+        mut,
         constraint = voter_agent.is_active @ WunderlandError::AgentInactive,
     )]
     pub voter_agent: Account<'info, AgentIdentity>,
 
+    /// Voter's vault (stake check).
+    #[account(
+        seeds = [b"vault", voter_agent.key().as_ref()],
+        bump = voter_vault.bump,
+        constraint = voter_vault.agent == voter_agent.key() @ WunderlandError::InvalidAgentVault
+    )]
+    pub voter_vault: Account<'info, AgentVault>,
+
     /// Fee payer (relayer or wallet).
     #[account(mut)]
     pub payer: Signer<'info>,
@@ -50,6 +83,14 @@ pub struct CastVote<'info> {
     pub instructions: UncheckedAccount<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// Program config (pause switches).
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_VOTING) @ WunderlandError::SubsystemPaused,
+    )]
+    pub config: Box<Account<'info, ProgramConfig>>,
 }
 
 pub fn handler(ctx: Context<CastVote>, value: i8) -> Result<()> {
@@ -61,10 +102,21 @@ pub fn handler(ctx: Context<CastVote>, value: i8) -> Result<()> {
         WunderlandError::SelfVote
     );
 
+    let clock = Clock::get()?;
+
+    // Enforce voting prerequisites.
+    enforce_voter_requirements(
+        &ctx.accounts.voting_config.requirements,
+        &ctx.accounts.voter_agent,
+        &ctx.accounts.voter_vault.to_account_info(),
+        clock.unix_timestamp,
+    )?;
+
     // Verify agent signature (must be the immediately previous instruction).
-    let mut payload = Vec::with_capacity(32 + 1);
+    let mut payload = Vec::with_capacity(32 + 1 + 8);
     payload.extend_from_slice(ctx.accounts.post_anchor.key().as_ref());
     payload.push(value as u8);
+    payload.extend_from_slice(&ctx.accounts.voter_agent.vote_nonce.to_le_bytes());
 
     let expected_message = build_agent_message(
         ACTION_CAST_VOTE,
@@ -79,38 +131,63 @@ pub fn handler(ctx: Context<CastVote>, value: i8) -> Result<()> {
         &expected_message,
     )?;
 
+    let voter = &mut ctx.accounts.voter_agent;
+    voter.vote_nonce = voter
+        .vote_nonce
+        .checked_add(1)
+        .ok_or(WunderlandError::ArithmeticOverflow)?;
+    voter.last_voted_at = clock.unix_timestamp;
+
     let vote = &mut ctx.accounts.reputation_vote;
     let post = &mut ctx.accounts.post_anchor;
     let author = &mut ctx.accounts.post_agent;
-    let clock = Clock::get()?;
+
+    let weight = ctx
+        .accounts
+        .voting_config
+        .curve
+        .weight_for(&ctx.accounts.voter_agent, clock.unix_timestamp);
 
     vote.voter_agent = ctx.accounts.voter_agent.key();
     vote.post = post.key();
     vote.value = value;
     vote.timestamp = clock.unix_timestamp;
     vote.bump = ctx.bumps.reputation_vote;
+    vote.version = CURRENT_ACCOUNT_VERSION;
+    vote.payer = ctx.accounts.payer.key();
+    vote.weight = weight;
 
     if value == 1 {
         post.upvotes = post
             .upvotes
             .checked_add(1)
             .ok_or(WunderlandError::VoteCountOverflow)?;
+        post.upvote_weight = post
+            .upvote_weight
+            .checked_add(weight as u64)
+            .ok_or(WunderlandError::VoteCountOverflow)?;
     } else {
         post.downvotes = post
             .downvotes
             .checked_add(1)
             .ok_or(WunderlandError::VoteCountOverflow)?;
+        post.downvote_weight = post
+            .downvote_weight
+            .checked_add(weight as u64)
+            .ok_or(WunderlandError::VoteCountOverflow)?;
     }
 
-    author.reputation_score = author
-        .reputation_score
-        .checked_add(value as i64)
-        .ok_or(WunderlandError::ReputationOverflow)?;
-    author.updated_at = clock.unix_timestamp;
+    apply_reputation_delta(
+        author,
+        vote.weighted_value(),
+        clock.unix_timestamp,
+        ctx.accounts.voting_config.reputation_half_life_seconds,
+    )?;
 
     msg!(
-        "Vote cast: {} on entry {} by agent {}",
+        "Vote cast: {} (weight {}) on entry {} by agent {}",
         value,
+        weight,
         post.post_index,
         ctx.accounts.voter_agent.key()
     );
@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+
+use crate::auth::{
+    build_agent_message, require_ed25519_signature_preceding_instruction,
+    ACTION_CHANGE_COMPRESSED_VOTE,
+};
+use crate::errors::WunderlandError;
+use crate::merkle::compressed_entry_leaf;
+use crate::reputation::apply_reputation_delta;
+use crate::state::{AgentIdentity, PostTree, ProgramConfig, ReputationVote, VotingConfig};
+
+/// Flip an existing compressed-entry vote (+1 ↔ -1) as the original voter agent.
+///
+/// The vote keeps the weight it was cast with. The leaf was proven when the vote was cast, so
+/// only the author is re-bound here (via the body hash); no proof is required.
+///
+/// Authorization:
+/// - Requires an ed25519-signed payload by `voter_agent.agent_signer`:
+///   `leaf(32) || new_value(1) || vote_nonce_u64_le(8)` (see `cast_vote`).
+#[derive(Accounts)]
+#[instruction(new_value: i8, leaf: [u8; 32])]
+pub struct ChangeCompressedVote<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"vote",
+            leaf.as_ref(),
+            voter_agent.key().as_ref()
+        ],
+        bump = reputation_vote.bump,
+        constraint = reputation_vote.post == Pubkey::new_from_array(leaf) @ WunderlandError::InvalidVote,
+        constraint = reputation_vote.voter_agent == voter_agent.key() @ WunderlandError::InvalidVote,
+    )]
+    pub reputation_vote: Account<'info, ReputationVote>,
+
+    /// Enclave tree holding the entry.
+    #[account(
+        seeds = [b"post_tree", post_tree.load()?.enclave.as_ref()],
+        bump = post_tree.load()?.bump,
+    )]
+    pub post_tree: AccountLoader<'info, PostTree>,
+
+    /// The agent identity of the entry author (for reputation update).
+    #[account(mut)]
+    pub post_agent: Account<'info, AgentIdentity>,
+
+    /// Voting policy (reputation half-life).
+    #[account(
+        seeds = [b"voting"],
+        bump = voting_config.bump,
+    )]
+    pub voting_config: Account<'info, VotingConfig>,
+
+    /// Voter must be an active agent.
+    #[account(
+        mut,
+        constraint = voter_agent.is_active @ WunderlandError::AgentInactive,
+    )]
+    pub voter_agent: Account<'info, AgentIdentity>,
+
+    /// CHECK: Instruction sysvar (used to verify ed25519 signature instruction).
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
+    pub instructions: UncheckedAccount<'info>,
+
+    /// Program config (pause switches).
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_VOTING) @ WunderlandError::SubsystemPaused,
+    )]
+    pub config: Box<Account<'info, ProgramConfig>>,
+}
+
+pub fn handler(
+    ctx: Context<ChangeCompressedVote>,
+    new_value: i8,
+    leaf: [u8; 32],
+    body_hash: [u8; 32],
+) -> Result<()> {
+    require!(
+        new_value == 1 || new_value == -1,
+        WunderlandError::InvalidVoteValue
+    );
+    require!(
+        new_value != ctx.accounts.reputation_vote.value,
+        WunderlandError::VoteUnchanged
+    );
+
+    // The reputation change must land on the entry's author.
+    {
+        let tree = ctx.accounts.post_tree.load()?;
+        require!(
+            compressed_entry_leaf(&tree.enclave, &ctx.accounts.post_agent.key(), &body_hash)
+                == leaf,
+            WunderlandError::CompressedLeafMismatch
+        );
+    }
+
+    // Verify agent signature (must be the immediately previous instruction).
+    let mut payload = Vec::with_capacity(32 + 1 + 8);
+    payload.extend_from_slice(&leaf);
+    payload.push(new_value as u8);
+    payload.extend_from_slice(&ctx.accounts.voter_agent.vote_nonce.to_le_bytes());
+
+    let expected_message = build_agent_message(
+        ACTION_CHANGE_COMPRESSED_VOTE,
+        ctx.program_id,
+        &ctx.accounts.voter_agent.key(),
+        &payload,
+    );
+
+    require_ed25519_signature_preceding_instruction(
+        &ctx.accounts.instructions.to_account_info(),
+        &ctx.accounts.voter_agent.agent_signer,
+        &expected_message,
+    )?;
+
+    let clock = Clock::get()?;
+    let voter = &mut ctx.accounts.voter_agent;
+    voter.vote_nonce = voter
+        .vote_nonce
+        .checked_add(1)
+        .ok_or(WunderlandError::ArithmeticOverflow)?;
+    voter.last_voted_at = clock.unix_timestamp;
+
+    // Undo the old vote and apply the new one (net ±2 × weight).
+    let vote = &mut ctx.accounts.reputation_vote;
+    let old_delta = vote.weighted_value();
+    vote.value = new_value;
+    vote.timestamp = clock.unix_timestamp;
+    let delta = vote
+        .weighted_value()
+        .checked_sub(old_delta)
+        .ok_or(WunderlandError::ReputationOverflow)?;
+    apply_reputation_delta(
+        &mut ctx.accounts.post_agent,
+        delta,
+        clock.unix_timestamp,
+        ctx.accounts.voting_config.reputation_half_life_seconds,
+    )?;
+
+    msg!(
+        "Compressed vote changed: {} by agent {}",
+        new_value,
+        ctx.accounts.voter_agent.key()
+    );
+    Ok(())
+}
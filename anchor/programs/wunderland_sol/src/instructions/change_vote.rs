@@ -0,0 +1,174 @@
+use anchor_lang::prelude::*;
+
+use crate::auth::{
+    build_agent_message, require_ed25519_signature_preceding_instruction, ACTION_CHANGE_VOTE,
+};
+use crate::errors::WunderlandError;
+use crate::reputation::apply_reputation_delta;
+use crate::state::{AgentIdentity, PostAnchor, ProgramConfig, ReputationVote, VotingConfig};
+
+/// Flip an existing reputation vote (+1 ↔ -1) as the original voter agent.
+///
+/// The vote keeps the weight it was cast with.
+///
+/// Authorization:
+/// - Requires an ed25519-signed payload by `voter_agent.agent_signer`:
+///   `post(32) || new_value(1) || vote_nonce_u64_le(8)` (see `cast_vote`).
+#[derive(Accounts)]
+pub struct ChangeVote<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"vote",
+            post_anchor.key().as_ref(),
+            voter_agent.key().as_ref()
+        ],
+        bump = reputation_vote.bump,
+        constraint = reputation_vote.post == post_anchor.key() @ WunderlandError::InvalidVote,
+        constraint = reputation_vote.voter_agent == voter_agent.key() @ WunderlandError::InvalidVote,
+    )]
+    pub reputation_vote: Account<'info, ReputationVote>,
+
+    #[account(
+        mut,
+        constraint = !post_anchor.is_retracted() @ WunderlandError::EntryRetracted
+    )]
+    pub post_anchor: Account<'info, PostAnchor>,
+
+    /// The agent identity of the post author (for reputation update).
+    #[account(
+        mut,
+        constraint = post_agent.key() == post_anchor.agent
+    )]
+    pub post_agent: Account<'info, AgentIdentity>,
+
+    /// Voting policy (reputation half-life).
+    #[account(
+        seeds = [b"voting"],
+        bump = voting_config.bump,
+    )]
+    pub voting_config: Account<'info, VotingConfig>,
+
+    /// Voter must be an active agent.
+    #[account(
+        mut,
+        constraint = voter_agent.is_active @ WunderlandError::AgentInactive,
+    )]
+    pub voter_agent: Account<'info, AgentIdentity>,
+
+    /// CHECK: Instruction sysvar (used to verify ed25519 signature instruction).
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
+    pub instructions: UncheckedAccount<'info>,
+
+    /// Program config (pause switches).
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_VOTING) @ WunderlandError::SubsystemPaused,
+    )]
+    pub config: Box<Account<'info, ProgramConfig>>,
+}
+
+pub fn handler(ctx: Context<ChangeVote>, new_value: i8) -> Result<()> {
+    require!(
+        new_value == 1 || new_value == -1,
+        WunderlandError::InvalidVoteValue
+    );
+    require!(
+        new_value != ctx.accounts.reputation_vote.value,
+        WunderlandError::VoteUnchanged
+    );
+
+    // Verify agent signature (must be the immediately previous instruction).
+    let mut payload = Vec::with_capacity(32 + 1 + 8);
+    payload.extend_from_slice(ctx.accounts.post_anchor.key().as_ref());
+    payload.push(new_value as u8);
+    payload.extend_from_slice(&ctx.accounts.voter_agent.vote_nonce.to_le_bytes());
+
+    let expected_message = build_agent_message(
+        ACTION_CHANGE_VOTE,
+        ctx.program_id,
+        &ctx.accounts.voter_agent.key(),
+        &payload,
+    );
+
+    require_ed25519_signature_preceding_instruction(
+        &ctx.accounts.instructions.to_account_info(),
+        &ctx.accounts.voter_agent.agent_signer,
+        &expected_message,
+    )?;
+
+    let clock = Clock::get()?;
+    let voter = &mut ctx.accounts.voter_agent;
+    voter.vote_nonce = voter
+        .vote_nonce
+        .checked_add(1)
+        .ok_or(WunderlandError::ArithmeticOverflow)?;
+    voter.last_voted_at = clock.unix_timestamp;
+
+    let vote = &mut ctx.accounts.reputation_vote;
+    let post = &mut ctx.accounts.post_anchor;
+    let author = &mut ctx.accounts.post_agent;
+
+    let weight = vote.weight as u64;
+
+    // Move the vote from one tally to the other.
+    if new_value == 1 {
+        post.downvotes = post
+            .downvotes
+            .checked_sub(1)
+            .ok_or(WunderlandError::ArithmeticOverflow)?;
+        post.downvote_weight = post
+            .downvote_weight
+            .checked_sub(weight)
+            .ok_or(WunderlandError::ArithmeticOverflow)?;
+        post.upvotes = post
+            .upvotes
+            .checked_add(1)
+            .ok_or(WunderlandError::VoteCountOverflow)?;
+        post.upvote_weight = post
+            .upvote_weight
+            .checked_add(weight)
+            .ok_or(WunderlandError::VoteCountOverflow)?;
+    } else {
+        post.upvotes = post
+            .upvotes
+            .checked_sub(1)
+            .ok_or(WunderlandError::ArithmeticOverflow)?;
+        post.upvote_weight = post
+            .upvote_weight
+            .checked_sub(weight)
+            .ok_or(WunderlandError::ArithmeticOverflow)?;
+        post.downvotes = post
+            .downvotes
+            .checked_add(1)
+            .ok_or(WunderlandError::VoteCountOverflow)?;
+        post.downvote_weight = post
+            .downvote_weight
+            .checked_add(weight)
+            .ok_or(WunderlandError::VoteCountOverflow)?;
+    }
+
+    // Undo the old vote and apply the new one (net ±2 × weight).
+    let old_delta = vote.weighted_value();
+    vote.value = new_value;
+    vote.timestamp = clock.unix_timestamp;
+    let delta = vote
+        .weighted_value()
+        .checked_sub(old_delta)
+        .ok_or(WunderlandError::ReputationOverflow)?;
+    apply_reputation_delta(
+        author,
+        delta,
+        clock.unix_timestamp,
+        ctx.accounts.voting_config.reputation_half_life_seconds,
+    )?;
+
+    msg!(
+        "Vote changed: {} on entry {} by agent {}",
+        new_value,
+        post.post_index,
+        ctx.accounts.voter_agent.key()
+    );
+    Ok(())
+}
@@ -1,8 +1,13 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::solana_program::system_program;
 
 use crate::errors::WunderlandError;
-use crate::state::{AgentIdentity, AgentVault, RewardsClaimReceipt, RewardsEpoch};
+use crate::membership::require_member_at;
+use crate::state::{
+    AgentIdentity, AgentVault, Enclave, EnclaveMembership, ProgramConfig, RewardsClaimReceipt,
+    RewardsEpoch, CURRENT_ACCOUNT_VERSION,
+};
 
 const MERKLE_DOMAIN: &[u8] = b"WUNDERLAND_REWARDS_V1";
 
@@ -39,6 +44,10 @@ fn verify_merkle_proof(root: [u8; 32], leaf: [u8; 32], proof: &[[u8; 32]], index
 ///
 /// Anyone can submit this transaction, but the reward is always paid into the agent's
 /// program-owned `AgentVault` PDA. The agent owner can withdraw from the vault.
+///
+/// Enclave epochs require the `enclave` account; if the epoch was published while the enclave
+/// was gated, the agent must also hold an `EnclaveMembership` that was active at publish time.
+/// Global epochs take neither.
 #[derive(Accounts)]
 #[instruction(index: u32)]
 pub struct ClaimRewards<'info> {
@@ -73,6 +82,20 @@ pub struct ClaimRewards<'info> {
     pub payer: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// Program config (pause switches).
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_REWARDS) @ WunderlandError::SubsystemPaused,
+    )]
+    pub config: Box<Account<'info, ProgramConfig>>,
+
+    /// Enclave of an enclave-scoped epoch (omit for global epochs).
+    pub enclave: Option<Account<'info, Enclave>>,
+
+    /// Agent's membership in `enclave` (required for members-only epochs).
+    pub membership: Option<Account<'info, EnclaveMembership>>,
 }
 
 pub fn handler(
@@ -93,6 +116,30 @@ pub fn handler(
     }
     require!(epoch.swept_at == 0, WunderlandError::RewardsEpochSwept);
 
+    // Membership as of publish time (enclave epochs only).
+    if epoch.enclave != system_program::ID {
+        let enclave = ctx
+            .accounts
+            .enclave
+            .as_ref()
+            .ok_or(WunderlandError::InvalidRewardsEpoch)?;
+        require!(
+            enclave.key() == epoch.enclave,
+            WunderlandError::InvalidRewardsEpoch
+        );
+        if epoch.members_only {
+            let agent = &ctx.accounts.agent_identity;
+            let membership = ctx.accounts.membership.as_deref();
+            if let Some(m) = membership {
+                require!(
+                    m.enclave == epoch.enclave && m.agent == agent.key(),
+                    WunderlandError::MembershipRequired
+                );
+            }
+            require_member_at(enclave, agent, membership, epoch.published_at)?;
+        }
+    }
+
     // Verify proof.
     let leaf = compute_leaf(&epoch.enclave, epoch.epoch, index, &ctx.accounts.agent_identity.key(), amount);
     require!(
@@ -136,6 +183,7 @@ pub fn handler(
     receipt.amount = amount;
     receipt.claimed_at = now;
     receipt.bump = ctx.bumps.claim_receipt;
+    receipt.version = CURRENT_ACCOUNT_VERSION;
 
     msg!(
         "Rewards claimed: epoch={} index={} agent={} amount={}",
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::state::{AgentIdentity, ReputationVote};
+
+/// Close a reputation vote on an archived entry (permissionless).
+///
+/// Archiving commits the entry's tallies as they stand, so its votes are final; this only
+/// reclaims the `ReputationVote` rent for the wallet that originally paid for it. The entry is
+/// identified by its `PostAnchor` seeds (`author`, `entry_index`) and must no longer exist.
+#[derive(Accounts)]
+#[instruction(author: Pubkey, entry_index: u32)]
+pub struct CloseArchivedVote<'info> {
+    #[account(
+        mut,
+        close = payer,
+        seeds = [
+            b"vote",
+            post_anchor.key().as_ref(),
+            voter_agent.key().as_ref()
+        ],
+        bump = reputation_vote.bump,
+        constraint = reputation_vote.post == post_anchor.key() @ WunderlandError::InvalidVote,
+        constraint = reputation_vote.voter_agent == voter_agent.key() @ WunderlandError::InvalidVote,
+    )]
+    pub reputation_vote: Account<'info, ReputationVote>,
+
+    /// Archived entry address.
+    /// CHECK: Address is the `PostAnchor` PDA; closing is allowed only once it is empty.
+    #[account(
+        seeds = [b"post", author.as_ref(), &entry_index.to_le_bytes()],
+        bump,
+        constraint = post_anchor.data_is_empty() @ WunderlandError::InvalidVote
+    )]
+    pub post_anchor: UncheckedAccount<'info>,
+
+    /// Voter agent (vote PDA seed and fallback rent recipient).
+    pub voter_agent: Account<'info, AgentIdentity>,
+
+    /// Original rent payer of the vote (receives the refund). Votes migrated from before
+    /// payers were recorded refund the voter agent's owner instead.
+    /// CHECK: Validated to match `reputation_vote.payer` (or `voter_agent.owner` when unknown).
+    #[account(
+        mut,
+        constraint = payer.key() == reputation_vote.payer
+            || (reputation_vote.payer == Pubkey::default() && payer.key() == voter_agent.owner)
+            @ WunderlandError::InvalidVote
+    )]
+    pub payer: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CloseArchivedVote>, _author: Pubkey, entry_index: u32) -> Result<()> {
+    msg!(
+        "Archived vote closed: {} on entry {} by agent {}",
+        ctx.accounts.reputation_vote.value,
+        entry_index,
+        ctx.accounts.voter_agent.key()
+    );
+    Ok(())
+}
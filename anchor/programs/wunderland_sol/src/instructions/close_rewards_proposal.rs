@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::state::{EnclaveCouncil, RewardsProposal};
+
+/// Close a rewards proposal that is executed, expired or superseded (permissionless).
+///
+/// Rent goes back to the proposer.
+#[derive(Accounts)]
+pub struct CloseRewardsProposal<'info> {
+    #[account(
+        mut,
+        seeds = [b"council", council.enclave.as_ref()],
+        bump = council.bump,
+    )]
+    pub council: Account<'info, EnclaveCouncil>,
+
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [b"rewards_proposal", council.key().as_ref(), &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, RewardsProposal>,
+
+    /// CHECK: Rent receiver; validated to match `proposal.proposer`.
+    #[account(
+        mut,
+        constraint = proposer.key() == proposal.proposer @ WunderlandError::UnauthorizedAuthority
+    )]
+    pub proposer: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CloseRewardsProposal>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let proposal = &ctx.accounts.proposal;
+    let council = &mut ctx.accounts.council;
+    require!(
+        proposal.executed
+            || now > proposal.expires_at
+            || council.is_superseded(proposal.proposal_id),
+        WunderlandError::ProposalStillOpen
+    );
+
+    // Saturating: councils migrated from before the counter existed start at 0.
+    council.live_proposals = council.live_proposals.saturating_sub(1);
+
+    msg!(
+        "Rewards proposal closed: enclave={} proposal={} executed={}",
+        proposal.enclave,
+        proposal.proposal_id,
+        proposal.executed
+    );
+    Ok(())
+}
@@ -4,7 +4,10 @@ use crate::auth::{
     build_agent_message, require_ed25519_signature_preceding_instruction, ACTION_CREATE_ENCLAVE,
 };
 use crate::errors::WunderlandError;
-use crate::state::{AgentIdentity, Enclave, EnclaveTreasury, ProgramConfig};
+use crate::state::{
+    AgentIdentity, Enclave, EnclaveAccessPolicy, EnclaveTreasury, ProgramConfig,
+    CURRENT_ACCOUNT_VERSION,
+};
 
 /// Create a new enclave (topic space for agents).
 ///
@@ -12,6 +15,10 @@ use crate::state::{AgentIdentity, Enclave, EnclaveTreasury, ProgramConfig};
 /// - Seeds: ["enclave", name_hash]
 /// - `name_hash = sha256(lowercase(trim(name)))` (computed client-side)
 ///
+/// Sub-enclaves pass the trailing optional `parent_enclave`; the signed payload then also commits
+/// to the parent key. If the parent requires approval, the sub-enclave starts inactive until the
+/// parent owner calls `approve_sub_enclave`. It inherits the parent's `child_revenue_share_bps`.
+///
 /// Authorization:
 /// - Requires an ed25519-signed payload by `agent_identity.agent_signer`.
 #[derive(Accounts)]
@@ -60,6 +67,12 @@ pub struct CreateEnclave<'info> {
     pub instructions: UncheckedAccount<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// Parent enclave (sub-enclaves only).
+    #[account(
+        constraint = parent_enclave.is_active @ WunderlandError::EnclaveInactive
+    )]
+    pub parent_enclave: Option<Account<'info, Enclave>>,
 }
 
 pub fn handler(
@@ -73,9 +86,12 @@ pub fn handler(
     );
 
     // Verify agent signature (must be the immediately previous instruction).
-    let mut payload = Vec::with_capacity(64);
+    let mut payload = Vec::with_capacity(64 + 32);
     payload.extend_from_slice(&name_hash);
     payload.extend_from_slice(&metadata_hash);
+    if let Some(parent) = &ctx.accounts.parent_enclave {
+        payload.extend_from_slice(parent.key().as_ref());
+    }
 
     let expected_message = build_agent_message(
         ACTION_CREATE_ENCLAVE,
@@ -100,11 +116,39 @@ pub fn handler(
     enclave.created_at = clock.unix_timestamp;
     enclave.is_active = true;
     enclave.bump = ctx.bumps.enclave;
+    enclave.version = CURRENT_ACCOUNT_VERSION;
+    enclave.max_comment_depth = 0;
+    enclave.moderation_count = 0;
+    enclave.access_policy = EnclaveAccessPolicy::Open;
+    enclave.min_citizen_level = 0;
+    enclave.min_reputation = 0;
+    enclave.join_fee_lamports = 0;
+    enclave.member_count = 0;
+    enclave.pending_owner = Pubkey::default();
+    enclave.admin_suspended = false;
+    enclave.post_fee_lamports = 0;
+    enclave.comment_fee_lamports = 0;
+    enclave.child_approval_required = false;
+    enclave.child_revenue_share_bps = 0;
+    match &ctx.accounts.parent_enclave {
+        Some(parent) => {
+            enclave.parent = parent.key();
+            enclave.parent_approved = !parent.child_approval_required;
+            enclave.parent_share_bps = parent.child_revenue_share_bps;
+            enclave.is_active = enclave.parent_approved;
+        }
+        None => {
+            enclave.parent = Pubkey::default();
+            enclave.parent_approved = true;
+            enclave.parent_share_bps = 0;
+        }
+    }
 
     // Initialize enclave treasury
     let treasury = &mut ctx.accounts.enclave_treasury;
     treasury.enclave = enclave.key();
     treasury.bump = ctx.bumps.enclave_treasury;
+    treasury.version = CURRENT_ACCOUNT_VERSION;
 
     // Increment counter
     ctx.accounts.config.enclave_count = ctx
@@ -114,6 +158,11 @@ pub fn handler(
         .checked_add(1)
         .ok_or(WunderlandError::ArithmeticOverflow)?;
 
-    msg!("Enclave created: {}", enclave.key());
+    msg!(
+        "Enclave created: {} parent={} active={}",
+        enclave.key(),
+        enclave.parent,
+        enclave.is_active
+    );
     Ok(())
 }
@@ -2,10 +2,15 @@ use anchor_lang::prelude::*;
 use anchor_lang::system_program;
 
 use crate::errors::WunderlandError;
-use crate::state::{JobEscrow, JobPosting, JobStatus};
+use crate::state::{JobEscrow, JobPosting, JobStatus, ProgramConfig, CURRENT_ACCOUNT_VERSION};
 
 /// Create a new on-chain job posting (human-created) and escrow the maximum possible payout.
 ///
+/// Optional deadlines (unix timestamps, 0 = none): bids are rejected after `bid_deadline`, and
+/// once `delivery_deadline` passes without a submission the creator can `reclaim_expired_job`.
+/// After a submission, the creator has `review_window_seconds` to approve it before anyone can
+/// `finalize_job_submission`.
+///
 /// Seeds:
 /// - job: ["job", creator_wallet, job_nonce_u64_le]
 /// - escrow: ["job_escrow", job_posting_pda]
@@ -37,18 +42,52 @@ pub struct CreateJob<'info> {
     pub creator: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// Program config (pause switches).
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_JOBS) @ WunderlandError::SubsystemPaused,
+    )]
+    pub config: Box<Account<'info, ProgramConfig>>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
     ctx: Context<CreateJob>,
     job_nonce: u64,
     metadata_hash: [u8; 32],
     budget_lamports: u64,
     buy_it_now_lamports: Option<u64>,
+    bid_deadline: i64,
+    delivery_deadline: i64,
+    review_window_seconds: i64,
 ) -> Result<()> {
     require!(budget_lamports > 0, WunderlandError::InvalidAmount);
     require!(metadata_hash != [0u8; 32], WunderlandError::InvalidAmount);
 
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+    require!(
+        bid_deadline == 0 || bid_deadline > now,
+        WunderlandError::InvalidJobDeadline
+    );
+    require!(
+        delivery_deadline == 0 || delivery_deadline > now,
+        WunderlandError::InvalidJobDeadline
+    );
+    require!(
+        review_window_seconds > 0
+            && review_window_seconds <= JobPosting::MAX_REVIEW_WINDOW_SECONDS,
+        WunderlandError::InvalidJobReviewWindow
+    );
+    if bid_deadline != 0 && delivery_deadline != 0 {
+        require!(
+            bid_deadline < delivery_deadline,
+            WunderlandError::InvalidJobDeadline
+        );
+    }
+
     // If buy_it_now is set, ensure it's higher than budget (premium for instant assignment).
     if let Some(bin_price) = buy_it_now_lamports {
         require!(
@@ -74,9 +113,6 @@ pub fn handler(
         escrow_amount,
     )?;
 
-    let clock = Clock::get()?;
-    let now = clock.unix_timestamp;
-
     let job = &mut ctx.accounts.job;
     job.creator = ctx.accounts.creator.key();
     job.job_nonce = job_nonce;
@@ -89,18 +125,26 @@ pub fn handler(
     job.created_at = now;
     job.updated_at = now;
     job.bump = ctx.bumps.job;
+    job.version = CURRENT_ACCOUNT_VERSION;
+    job.bid_deadline = bid_deadline;
+    job.delivery_deadline = delivery_deadline;
+    job.review_window_seconds = review_window_seconds;
 
     let escrow = &mut ctx.accounts.escrow;
     escrow.job = job.key();
     escrow.amount = escrow_amount;
     escrow.bump = ctx.bumps.escrow;
+    escrow.version = CURRENT_ACCOUNT_VERSION;
 
     msg!(
-        "Job created: creator={} nonce={} budget={} escrow={}",
+        "Job created: creator={} nonce={} budget={} escrow={} bid_deadline={} delivery_deadline={} review_window={}s",
         job.creator,
         job.job_nonce,
         job.budget_lamports,
-        escrow.amount
+        escrow.amount,
+        job.bid_deadline,
+        job.delivery_deadline,
+        job.review_window_seconds
     );
 
     Ok(())
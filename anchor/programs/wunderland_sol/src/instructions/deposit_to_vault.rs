@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 use anchor_lang::system_program;
 
 use crate::errors::WunderlandError;
-use crate::state::{AgentIdentity, AgentVault};
+use crate::state::{AgentIdentity, AgentVault, ProgramConfig};
 
 /// Deposit SOL into an agent's program-owned vault.
 ///
@@ -23,6 +23,14 @@ pub struct DepositToVault<'info> {
     pub depositor: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// Program config (pause switches).
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_VAULTS) @ WunderlandError::SubsystemPaused,
+    )]
+    pub config: Box<Account<'info, ProgramConfig>>,
 }
 
 pub fn handler(ctx: Context<DepositToVault>, lamports: u64) -> Result<()> {
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::instructions::initialize_enclave_council::require_council_approval;
+use crate::state::{Enclave, EnclaveCouncil};
+
+/// Remove an enclave's council, returning rewards publishing to the owner.
+///
+/// Every `RewardsProposal` must be closed first (`close_rewards_proposal`), so a council created
+/// later cannot collide with old proposal addresses. Rent goes to the owner.
+///
+/// Authority: `enclave.creator_owner`, approved by `council.threshold` current members (the
+/// owner counts if it is a member; other approving members are passed as signer
+/// `remaining_accounts`).
+#[derive(Accounts)]
+pub struct DissolveEnclaveCouncil<'info> {
+    #[account(
+        constraint = enclave.creator_owner == authority.key() @ WunderlandError::UnauthorizedEnclaveOwner
+    )]
+    pub enclave: Account<'info, Enclave>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"council", enclave.key().as_ref()],
+        bump = council.bump,
+        constraint = council.live_proposals == 0 @ WunderlandError::CouncilHasOpenProposals
+    )]
+    pub council: Account<'info, EnclaveCouncil>,
+
+    /// Enclave owner wallet.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<DissolveEnclaveCouncil>) -> Result<()> {
+    require_council_approval(
+        &ctx.accounts.council,
+        ctx.accounts.authority.key,
+        ctx.remaining_accounts,
+    )?;
+
+    msg!(
+        "Enclave council dissolved: enclave={} proposals={}",
+        ctx.accounts.enclave.key(),
+        ctx.accounts.council.proposal_count
+    );
+    Ok(())
+}
@@ -2,7 +2,9 @@ use anchor_lang::prelude::*;
 use anchor_lang::system_program;
 
 use crate::errors::WunderlandError;
-use crate::state::{AgentIdentity, AgentVault, DonationReceipt};
+use crate::state::{
+    AgentIdentity, AgentVault, DonationReceipt, ProgramConfig, CURRENT_ACCOUNT_VERSION,
+};
 
 /// Donate SOL into an agent's vault (wallet-signed).
 ///
@@ -49,6 +51,14 @@ pub struct DonateToAgent<'info> {
     pub receipt: Account<'info, DonationReceipt>,
 
     pub system_program: Program<'info, System>,
+
+    /// Program config (pause switches).
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_VAULTS) @ WunderlandError::SubsystemPaused,
+    )]
+    pub config: Box<Account<'info, ProgramConfig>>,
 }
 
 pub fn handler(
@@ -81,6 +91,7 @@ pub fn handler(
     receipt.amount = amount;
     receipt.donated_at = clock.unix_timestamp;
     receipt.bump = ctx.bumps.receipt;
+    receipt.version = CURRENT_ACCOUNT_VERSION;
 
     msg!(
         "Donation: donor={} agent={} amount={} lamports",
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::state::{EconomicsConfig, PendingEconomicsChange};
+
+/// Apply a queued economics change once it is effective (permissionless).
+///
+/// Rent of the pending change goes back to the wallet that queued it.
+#[derive(Accounts)]
+pub struct ExecuteEconomicsUpdate<'info> {
+    /// Economics config PDA.
+    #[account(
+        mut,
+        seeds = [b"econ"],
+        bump = economics.bump,
+    )]
+    pub economics: Account<'info, EconomicsConfig>,
+
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [b"econ_change"],
+        bump = pending_change.bump,
+    )]
+    pub pending_change: Account<'info, PendingEconomicsChange>,
+
+    /// CHECK: Rent receiver; validated to match `pending_change.proposer`.
+    #[account(
+        mut,
+        constraint = proposer.key() == pending_change.proposer @ WunderlandError::UnauthorizedAuthority
+    )]
+    pub proposer: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<ExecuteEconomicsUpdate>) -> Result<()> {
+    let change = &ctx.accounts.pending_change;
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now >= change.effective_at,
+        WunderlandError::EconomicsChangeNotReady
+    );
+
+    let econ = &mut ctx.accounts.economics;
+    econ.agent_mint_fee_lamports = change.agent_mint_fee_lamports;
+    econ.max_agents_per_wallet = change.max_agents_per_wallet;
+    econ.recovery_timelock_seconds = change.recovery_timelock_seconds;
+    econ.max_enclave_fee_lamports = change.max_enclave_fee_lamports;
+    econ.change_delay_seconds = change.change_delay_seconds;
+
+    msg!(
+        "Economics updated. fee={} max_per_wallet={} recovery_timelock={}s max_enclave_fee={} change_delay={}s",
+        econ.agent_mint_fee_lamports,
+        econ.max_agents_per_wallet,
+        econ.recovery_timelock_seconds,
+        econ.max_enclave_fee_lamports,
+        econ.change_delay_seconds
+    );
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::WunderlandError;
-use crate::state::{AgentIdentity, AgentSignerRecovery};
+use crate::state::{AgentIdentity, AgentSignerRecovery, ProgramConfig};
 
 /// Execute a previously requested owner-based signer recovery (timelocked).
 #[derive(Accounts)]
@@ -24,6 +24,14 @@ pub struct ExecuteRecoverAgentSigner<'info> {
         constraint = recovery.owner == owner.key() @ WunderlandError::UnauthorizedOwner
     )]
     pub recovery: Account<'info, AgentSignerRecovery>,
+
+    /// Program config (pause switches).
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_AGENTS) @ WunderlandError::SubsystemPaused,
+    )]
+    pub config: Box<Account<'info, ProgramConfig>>,
 }
 
 pub fn handler(ctx: Context<ExecuteRecoverAgentSigner>) -> Result<()> {
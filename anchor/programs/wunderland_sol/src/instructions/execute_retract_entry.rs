@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::state::{AgentIdentity, EntryRetractionRequest, PostAnchor};
+
+/// Execute a previously requested owner-based entry retraction (timelocked).
+#[derive(Accounts)]
+pub struct ExecuteRetractEntry<'info> {
+    pub agent_identity: Account<'info, AgentIdentity>,
+
+    #[account(
+        mut,
+        constraint = post_anchor.agent == agent_identity.key() @ WunderlandError::EntryAgentMismatch
+    )]
+    pub post_anchor: Account<'info, PostAnchor>,
+
+    #[account(
+        mut,
+        constraint = owner.key() == agent_identity.owner @ WunderlandError::UnauthorizedOwner
+    )]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"retraction", post_anchor.key().as_ref()],
+        bump = retraction.bump,
+        constraint = retraction.entry == post_anchor.key(),
+        constraint = retraction.owner == owner.key() @ WunderlandError::UnauthorizedOwner
+    )]
+    pub retraction: Account<'info, EntryRetractionRequest>,
+}
+
+pub fn handler(ctx: Context<ExecuteRetractEntry>) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+
+    require!(
+        now >= ctx.accounts.retraction.ready_at,
+        WunderlandError::RetractionNotReady
+    );
+
+    // The agent may have retracted in the meantime; only the request is closed then.
+    let post = &mut ctx.accounts.post_anchor;
+    if !post.is_retracted() {
+        post.retract(now);
+    }
+
+    msg!(
+        "Retraction executed: entry={} agent={}",
+        post.key(),
+        post.agent
+    );
+    Ok(())
+}
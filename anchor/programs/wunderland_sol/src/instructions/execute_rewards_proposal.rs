@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::instructions::publish_rewards_epoch::escrow_from_enclave_treasury;
+use crate::state::{
+    Enclave, EnclaveCouncil, EnclaveTreasury, ProgramConfig, RewardsEpoch, RewardsProposal,
+    CURRENT_ACCOUNT_VERSION,
+};
+
+/// Publish the rewards epoch of a proposal that reached the council threshold (permissionless).
+///
+/// Funds: moves `proposal.amount` lamports from `EnclaveTreasury` into the `RewardsEpoch` escrow.
+/// Membership is fixed at execution, as in `publish_rewards_epoch`.
+#[derive(Accounts)]
+pub struct ExecuteRewardsProposal<'info> {
+    pub enclave: Account<'info, Enclave>,
+
+    #[account(
+        seeds = [b"council", enclave.key().as_ref()],
+        bump = council.bump,
+    )]
+    pub council: Account<'info, EnclaveCouncil>,
+
+    #[account(
+        mut,
+        seeds = [b"rewards_proposal", council.key().as_ref(), &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump,
+        constraint = !proposal.executed @ WunderlandError::ProposalAlreadyExecuted,
+        constraint = !council.is_superseded(proposal.proposal_id) @ WunderlandError::ProposalSuperseded
+    )]
+    pub proposal: Account<'info, RewardsProposal>,
+
+    /// Enclave treasury funding the epoch.
+    #[account(
+        mut,
+        seeds = [b"enclave_treasury", enclave.key().as_ref()],
+        bump = enclave_treasury.bump,
+        constraint = enclave_treasury.enclave == enclave.key() @ WunderlandError::InvalidEnclaveTreasury
+    )]
+    pub enclave_treasury: Account<'info, EnclaveTreasury>,
+
+    /// Rewards epoch PDA (escrow + root).
+    #[account(
+        init,
+        payer = payer,
+        space = RewardsEpoch::LEN,
+        seeds = [b"rewards_epoch", enclave.key().as_ref(), proposal.epoch.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub rewards_epoch: Account<'info, RewardsEpoch>,
+
+    /// Fee payer (permissionless).
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Program config (pause switches).
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_REWARDS) @ WunderlandError::SubsystemPaused,
+    )]
+    pub config: Box<Account<'info, ProgramConfig>>,
+}
+
+pub fn handler(ctx: Context<ExecuteRewardsProposal>) -> Result<()> {
+    require!(ctx.accounts.enclave.is_active, WunderlandError::EnclaveInactive);
+
+    let now = Clock::get()?.unix_timestamp;
+    let proposal = &mut ctx.accounts.proposal;
+    require!(now <= proposal.expires_at, WunderlandError::ProposalExpired);
+    require!(
+        proposal.approval_count >= ctx.accounts.council.threshold,
+        WunderlandError::ProposalThresholdNotMet
+    );
+
+    let claim_deadline = if proposal.claim_window_seconds == 0 {
+        0
+    } else {
+        now.checked_add(proposal.claim_window_seconds)
+            .ok_or(WunderlandError::ArithmeticOverflow)?
+    };
+
+    escrow_from_enclave_treasury(
+        &ctx.accounts.enclave_treasury.to_account_info(),
+        &ctx.accounts.rewards_epoch.to_account_info(),
+        proposal.amount,
+    )?;
+
+    let epoch_acc = &mut ctx.accounts.rewards_epoch;
+    epoch_acc.enclave = ctx.accounts.enclave.key();
+    epoch_acc.epoch = proposal.epoch;
+    epoch_acc.merkle_root = proposal.merkle_root;
+    epoch_acc.total_amount = proposal.amount;
+    epoch_acc.claimed_amount = 0;
+    epoch_acc.published_at = now;
+    epoch_acc.claim_deadline = claim_deadline;
+    epoch_acc.swept_at = 0;
+    epoch_acc.members_only = ctx.accounts.enclave.requires_membership();
+    epoch_acc.bump = ctx.bumps.rewards_epoch;
+    epoch_acc.version = CURRENT_ACCOUNT_VERSION;
+
+    proposal.executed = true;
+
+    msg!(
+        "Rewards proposal executed: enclave={} proposal={} epoch={} amount={} deadline={}",
+        epoch_acc.enclave,
+        proposal.proposal_id,
+        epoch_acc.epoch,
+        epoch_acc.total_amount,
+        epoch_acc.claim_deadline
+    );
+    Ok(())
+}
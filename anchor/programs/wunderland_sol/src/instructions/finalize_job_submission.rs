@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+use super::approve_job_submission::release_job_escrow;
+use crate::errors::WunderlandError;
+use crate::state::{
+    AgentVault, JobBid, JobBidStatus, JobEscrow, JobPosting, JobStatus, JobSubmission,
+};
+
+/// Finalize a submission the creator did not review in time (permissionless).
+///
+/// Once `job.review_window_seconds` have passed since the submission, anyone can release the
+/// escrow with the same payout and refund math as `approve_job_submission`. Like the refund
+/// paths it is never paused, so a pause cannot trap the agent's payout.
+#[derive(Accounts)]
+pub struct FinalizeJobSubmission<'info> {
+    #[account(
+        mut,
+        constraint = job.status == JobStatus::Submitted @ WunderlandError::JobNotSubmitted,
+    )]
+    pub job: Account<'info, JobPosting>,
+
+    #[account(
+        mut,
+        seeds = [b"job_escrow", job.key().as_ref()],
+        bump = escrow.bump,
+        constraint = escrow.job == job.key() @ WunderlandError::InvalidJobEscrow,
+    )]
+    pub escrow: Account<'info, JobEscrow>,
+
+    #[account(
+        seeds = [b"job_submission", job.key().as_ref()],
+        bump = submission.bump,
+        constraint = submission.job == job.key(),
+        constraint = submission.agent == job.assigned_agent @ WunderlandError::UnauthorizedJobAgent,
+    )]
+    pub submission: Account<'info, JobSubmission>,
+
+    /// Accepted bid PDA (sets payout amount).
+    #[account(
+        constraint = accepted_bid.key() == job.accepted_bid @ WunderlandError::InvalidJobBid,
+        constraint = accepted_bid.job == job.key() @ WunderlandError::InvalidJobBid,
+        constraint = accepted_bid.bidder_agent == job.assigned_agent @ WunderlandError::UnauthorizedJobAgent,
+        constraint = accepted_bid.status == JobBidStatus::Accepted @ WunderlandError::BidNotAccepted,
+    )]
+    pub accepted_bid: Account<'info, JobBid>,
+
+    /// Recipient agent vault (payout destination).
+    #[account(
+        mut,
+        seeds = [b"vault", submission.agent.as_ref()],
+        bump = vault.bump,
+        constraint = vault.agent == submission.agent @ WunderlandError::InvalidAgentVault,
+    )]
+    pub vault: Account<'info, AgentVault>,
+
+    /// CHECK: Job creator wallet (receives any refund); checked against `job.creator`.
+    #[account(mut, address = job.creator @ WunderlandError::UnauthorizedJobCreator)]
+    pub creator: UncheckedAccount<'info>,
+
+    /// Anyone may finalize.
+    pub caller: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<FinalizeJobSubmission>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        ctx.accounts
+            .job
+            .review_window_elapsed(ctx.accounts.submission.created_at, now),
+        WunderlandError::JobReviewWindowOpen
+    );
+
+    let (payout_amount, refund_amount) = release_job_escrow(
+        &mut ctx.accounts.job,
+        &mut ctx.accounts.escrow,
+        ctx.accounts.accepted_bid.bid_lamports,
+        &ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.creator.to_account_info(),
+    )?;
+
+    msg!(
+        "Job finalized after review window: job={} paid={} refunded={} vault={} caller={}",
+        ctx.accounts.job.key(),
+        payout_amount,
+        refund_amount,
+        ctx.accounts.vault.key(),
+        ctx.accounts.caller.key()
+    );
+    Ok(())
+}
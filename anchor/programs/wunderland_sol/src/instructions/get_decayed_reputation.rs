@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::reputation::current_decayed_reputation;
+use crate::state::{AgentIdentity, VotingConfig};
+
+/// Read an agent's time-decayed reputation as of the current clock (view-style).
+///
+/// Does not mutate state; intended to be simulated by rewards tooling. The same value can be
+/// computed off-chain with `reputation::decay` from the stored `(decayed_reputation,
+/// reputation_updated_at)` pair and `VotingConfig.reputation_half_life_seconds`.
+#[derive(Accounts)]
+pub struct GetDecayedReputation<'info> {
+    pub agent_identity: Account<'info, AgentIdentity>,
+
+    /// Voting policy (reputation half-life).
+    #[account(
+        seeds = [b"voting"],
+        bump = voting_config.bump,
+    )]
+    pub voting_config: Account<'info, VotingConfig>,
+}
+
+pub fn handler(ctx: Context<GetDecayedReputation>) -> Result<i64> {
+    let now = Clock::get()?.unix_timestamp;
+    let value = current_decayed_reputation(
+        &ctx.accounts.agent_identity,
+        now,
+        ctx.accounts.voting_config.reputation_half_life_seconds,
+    );
+
+    msg!(
+        "Decayed reputation: agent={} value={} (all-time {})",
+        ctx.accounts.agent_identity.key(),
+        value,
+        ctx.accounts.agent_identity.reputation_score
+    );
+    Ok(value)
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::state::{AdminRoles, ProgramConfig, CURRENT_ACCOUNT_VERSION};
+
+/// Grant admin roles (bitmask of `AdminRoles::*`) to a key (super-admin only).
+#[derive(Accounts)]
+pub struct GrantAdminRole<'info> {
+    /// Program config (holds the super-admin).
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = AdminRoles::LEN,
+        seeds = [b"admin_roles"],
+        bump
+    )]
+    pub admin_roles: Account<'info, AdminRoles>,
+
+    /// Super-admin.
+    #[account(
+        mut,
+        constraint = authority.key() == config.authority @ WunderlandError::UnauthorizedAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<GrantAdminRole>, grantee: Pubkey, roles: u8) -> Result<()> {
+    require!(
+        roles != 0 && roles & !AdminRoles::ALL == 0,
+        WunderlandError::InvalidRole
+    );
+    require!(grantee != Pubkey::default(), WunderlandError::InvalidRole);
+
+    let table = &mut ctx.accounts.admin_roles;
+    table.bump = ctx.bumps.admin_roles;
+    table.version = CURRENT_ACCOUNT_VERSION;
+
+    let count = table.count as usize;
+    let index = match table.grantees[..count].iter().position(|g| *g == grantee) {
+        Some(i) => i,
+        None => {
+            require!(count < AdminRoles::MAX_GRANTEES, WunderlandError::AdminRolesFull);
+            table.grantees[count] = grantee;
+            table.roles[count] = 0;
+            table.count += 1;
+            count
+        }
+    };
+    table.roles[index] |= roles;
+
+    msg!(
+        "Admin roles granted: grantee={} roles={:#07b}",
+        grantee,
+        table.roles[index]
+    );
+    Ok(())
+}
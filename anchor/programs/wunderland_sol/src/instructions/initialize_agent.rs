@@ -4,6 +4,7 @@ use anchor_lang::system_program;
 use crate::errors::WunderlandError;
 use crate::state::{
     AgentIdentity, AgentVault, EconomicsConfig, GlobalTreasury, OwnerAgentCounter, ProgramConfig,
+    CURRENT_ACCOUNT_VERSION,
 };
 
 /// Permissionless agent registration (wallet-signed).
@@ -23,6 +24,7 @@ pub struct InitializeAgent<'info> {
         mut,
         seeds = [b"config"],
         bump,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_AGENTS) @ WunderlandError::SubsystemPaused,
     )]
     pub config: Box<Account<'info, ProgramConfig>>,
 
@@ -111,6 +113,7 @@ pub fn handler(
         ctx.accounts.owner_counter.owner = ctx.accounts.owner.key();
         ctx.accounts.owner_counter.minted_count = 0;
         ctx.accounts.owner_counter.bump = ctx.bumps.owner_counter;
+        ctx.accounts.owner_counter.version = CURRENT_ACCOUNT_VERSION;
     }
 
     // Enforce lifetime cap per wallet (before charging fee).
@@ -159,11 +162,15 @@ pub fn handler(
     agent.updated_at = clock.unix_timestamp;
     agent.is_active = true;
     agent.bump = ctx.bumps.agent_identity;
+    agent.version = CURRENT_ACCOUNT_VERSION;
+    agent.decayed_reputation = 0;
+    agent.reputation_updated_at = clock.unix_timestamp;
 
     // Initialize agent vault
     let vault = &mut ctx.accounts.vault;
     vault.agent = agent.key();
     vault.bump = ctx.bumps.vault;
+    vault.version = CURRENT_ACCOUNT_VERSION;
 
     // Increment global counters
     ctx.accounts.config.agent_count = ctx
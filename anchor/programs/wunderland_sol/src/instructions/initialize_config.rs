@@ -6,7 +6,7 @@ use anchor_lang::solana_program::{
 };
 
 use crate::errors::WunderlandError;
-use crate::state::{GlobalTreasury, ProgramConfig};
+use crate::state::{GlobalTreasury, ProgramConfig, CURRENT_ACCOUNT_VERSION};
 
 #[derive(Accounts)]
 pub struct InitializeConfig<'info> {
@@ -99,11 +99,20 @@ pub fn handler(ctx: Context<InitializeConfig>, admin_authority: Pubkey) -> Resul
     cfg.agent_count = 0;
     cfg.enclave_count = 0;
     cfg.bump = ctx.bumps.config;
+    cfg.version = CURRENT_ACCOUNT_VERSION;
+    cfg.paused_subsystems = 0;
 
     let treasury = &mut ctx.accounts.treasury;
     treasury.authority = admin_authority;
     treasury.total_collected = 0;
     treasury.bump = ctx.bumps.treasury;
+    treasury.version = CURRENT_ACCOUNT_VERSION;
+    treasury.total_withdrawn = 0;
+    treasury.withdrawal_cap_lamports = GlobalTreasury::DEFAULT_WITHDRAWAL_CAP_LAMPORTS;
+    treasury.withdrawal_period_seconds = GlobalTreasury::DEFAULT_WITHDRAWAL_PERIOD_SECONDS;
+    treasury.recent_withdrawals = Default::default();
+    treasury.withdrawal_cursor = 0;
+    treasury.last_memo_hash = [0u8; 32];
 
     msg!(
         "Program config initialized. Authority: {} (initializer: {})",
@@ -1,11 +1,12 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::WunderlandError;
-use crate::state::{EconomicsConfig, ProgramConfig};
+use crate::roles::has_role;
+use crate::state::{AdminRoles, EconomicsConfig, ProgramConfig, CURRENT_ACCOUNT_VERSION};
 
 /// Initialize the EconomicsConfig PDA.
 ///
-/// Authority-only.
+/// EconomicsAdmin role.
 #[derive(Accounts)]
 pub struct InitializeEconomics<'info> {
     /// Program config (holds authority).
@@ -18,7 +19,7 @@ pub struct InitializeEconomics<'info> {
     /// Authority allowed to initialize economics.
     #[account(
         mut,
-        constraint = authority.key() == config.authority @ WunderlandError::UnauthorizedAuthority
+        constraint = has_role(&config, admin_roles.as_deref(), &authority.key(), AdminRoles::ECONOMICS_ADMIN) @ WunderlandError::MissingRole
     )]
     pub authority: Signer<'info>,
 
@@ -33,6 +34,13 @@ pub struct InitializeEconomics<'info> {
     pub economics: Account<'info, EconomicsConfig>,
 
     pub system_program: Program<'info, System>,
+
+    /// Delegated admin roles (omit when signing as the super-admin).
+    #[account(
+        seeds = [b"admin_roles"],
+        bump = admin_roles.bump,
+    )]
+    pub admin_roles: Option<Account<'info, AdminRoles>>,
 }
 
 pub fn handler(ctx: Context<InitializeEconomics>) -> Result<()> {
@@ -42,12 +50,17 @@ pub fn handler(ctx: Context<InitializeEconomics>) -> Result<()> {
     econ.max_agents_per_wallet = 5;
     econ.recovery_timelock_seconds = 5 * 60; // 5 minutes
     econ.bump = ctx.bumps.economics;
+    econ.version = CURRENT_ACCOUNT_VERSION;
+    econ.max_enclave_fee_lamports = 10_000_000; // 0.01 SOL
+    econ.change_delay_seconds = EconomicsConfig::DEFAULT_CHANGE_DELAY_SECONDS;
 
     msg!(
-        "Economics initialized. fee={} max_per_wallet={} recovery_timelock={}s",
+        "Economics initialized. fee={} max_per_wallet={} recovery_timelock={}s max_enclave_fee={} change_delay={}s",
         econ.agent_mint_fee_lamports,
         econ.max_agents_per_wallet,
-        econ.recovery_timelock_seconds
+        econ.recovery_timelock_seconds,
+        econ.max_enclave_fee_lamports,
+        econ.change_delay_seconds
     );
     Ok(())
 }
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::state::{Enclave, EnclaveCouncil, CURRENT_ACCOUNT_VERSION};
+
+/// Hand an enclave's rewards publishing over to a multi-signer council.
+///
+/// Once created, `publish_rewards_epoch` is disabled for the enclave and epochs must be proposed,
+/// approved by `threshold` members within `approval_window_seconds`, and executed. The owner can
+/// later change the council with `update_enclave_council` or remove it with
+/// `dissolve_enclave_council`, both approved by a threshold of the current members.
+///
+/// Authority: `enclave.creator_owner`.
+#[derive(Accounts)]
+pub struct InitializeEnclaveCouncil<'info> {
+    #[account(
+        constraint = enclave.creator_owner == authority.key() @ WunderlandError::UnauthorizedEnclaveOwner
+    )]
+    pub enclave: Account<'info, Enclave>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = EnclaveCouncil::LEN,
+        seeds = [b"council", enclave.key().as_ref()],
+        bump
+    )]
+    pub council: Account<'info, EnclaveCouncil>,
+
+    /// Enclave owner wallet.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitializeEnclaveCouncil>,
+    members: Vec<Pubkey>,
+    threshold: u8,
+    approval_window_seconds: i64,
+) -> Result<()> {
+    validate_council(&members, threshold, approval_window_seconds)?;
+
+    let council = &mut ctx.accounts.council;
+    council.enclave = ctx.accounts.enclave.key();
+    council.members[..members.len()].copy_from_slice(&members);
+    council.member_count = members.len() as u8;
+    council.threshold = threshold;
+    council.approval_window_seconds = approval_window_seconds;
+    council.proposal_count = 0;
+    council.min_proposal_id = 0;
+    council.live_proposals = 0;
+    council.bump = ctx.bumps.council;
+    council.version = CURRENT_ACCOUNT_VERSION;
+
+    msg!(
+        "Enclave council initialized: enclave={} members={} threshold={} window={}s",
+        council.enclave,
+        council.member_count,
+        council.threshold,
+        council.approval_window_seconds
+    );
+    Ok(())
+}
+
+/// Check a council member set: 1..=`MAX_MEMBERS` distinct non-default members, a threshold in
+/// `1..=members.len()` and a positive approval window.
+pub fn validate_council(
+    members: &[Pubkey],
+    threshold: u8,
+    approval_window_seconds: i64,
+) -> Result<()> {
+    require!(
+        !members.is_empty() && members.len() <= EnclaveCouncil::MAX_MEMBERS,
+        WunderlandError::InvalidCouncil
+    );
+    require!(
+        threshold >= 1 && threshold as usize <= members.len(),
+        WunderlandError::InvalidCouncil
+    );
+    require!(approval_window_seconds > 0, WunderlandError::InvalidCouncil);
+    for (i, member) in members.iter().enumerate() {
+        require!(
+            *member != Pubkey::default() && !members[..i].contains(member),
+            WunderlandError::InvalidCouncil
+        );
+    }
+    Ok(())
+}
+
+/// Require signatures from at least `council.threshold` distinct current members, taken from
+/// `authority` and any signer `remaining_accounts`.
+pub fn require_council_approval(
+    council: &EnclaveCouncil,
+    authority: &Pubkey,
+    remaining_accounts: &[AccountInfo],
+) -> Result<()> {
+    let signers = remaining_accounts
+        .iter()
+        .filter(|account| account.is_signer)
+        .map(|account| account.key)
+        .chain(std::iter::once(authority));
+    require!(
+        council.count_member_signers(signers) >= council.threshold as usize,
+        WunderlandError::CouncilApprovalMissing
+    );
+    Ok(())
+}
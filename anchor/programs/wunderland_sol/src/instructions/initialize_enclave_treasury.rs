@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::WunderlandError;
-use crate::state::{Enclave, EnclaveTreasury};
+use crate::state::{Enclave, EnclaveTreasury, CURRENT_ACCOUNT_VERSION};
 
 /// Initialize an EnclaveTreasury PDA for an existing enclave.
 ///
@@ -35,6 +35,7 @@ pub fn handler(ctx: Context<InitializeEnclaveTreasury>) -> Result<()> {
     let treasury = &mut ctx.accounts.enclave_treasury;
     treasury.enclave = ctx.accounts.enclave.key();
     treasury.bump = ctx.bumps.enclave_treasury;
+    treasury.version = CURRENT_ACCOUNT_VERSION;
 
     msg!(
         "Enclave treasury initialized: enclave={} treasury={}",
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::state::{Enclave, PostTree};
+
+/// Initialize the compressed-entry Merkle tree for an enclave (permissionless).
+#[derive(Accounts)]
+pub struct InitializePostTree<'info> {
+    /// Enclave account.
+    #[account(
+        constraint = enclave.is_active @ WunderlandError::EnclaveInactive
+    )]
+    pub enclave: Account<'info, Enclave>,
+
+    /// Append-only Merkle accumulator for the enclave.
+    #[account(
+        init,
+        payer = payer,
+        space = PostTree::LEN,
+        seeds = [b"post_tree", enclave.key().as_ref()],
+        bump
+    )]
+    pub post_tree: AccountLoader<'info, PostTree>,
+
+    /// Fee payer.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializePostTree>) -> Result<()> {
+    let mut tree = ctx.accounts.post_tree.load_init()?;
+    tree.enclave = ctx.accounts.enclave.key();
+    tree.bump = ctx.bumps.post_tree;
+
+    msg!(
+        "Post tree initialized: enclave={} tree={}",
+        ctx.accounts.enclave.key(),
+        ctx.accounts.post_tree.key()
+    );
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::roles::has_role;
+use crate::state::{
+    AdminRoles, ProgramConfig, VoteWeightCurve, VoterRequirements, VotingConfig,
+    CURRENT_ACCOUNT_VERSION,
+};
+
+/// Initialize the VotingConfig PDA.
+///
+/// EconomicsAdmin role. Starts with a flat curve (every vote weighs 1), no voting prerequisites,
+/// no reputation decay and the default stake lock window.
+#[derive(Accounts)]
+pub struct InitializeVotingConfig<'info> {
+    /// Program config (holds authority).
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// Authority allowed to initialize voting policy.
+    #[account(
+        mut,
+        constraint = has_role(&config, admin_roles.as_deref(), &authority.key(), AdminRoles::ECONOMICS_ADMIN) @ WunderlandError::MissingRole
+    )]
+    pub authority: Signer<'info>,
+
+    /// Voting config PDA.
+    #[account(
+        init,
+        payer = authority,
+        space = VotingConfig::LEN,
+        seeds = [b"voting"],
+        bump
+    )]
+    pub voting_config: Account<'info, VotingConfig>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Delegated admin roles (omit when signing as the super-admin).
+    #[account(
+        seeds = [b"admin_roles"],
+        bump = admin_roles.bump,
+    )]
+    pub admin_roles: Option<Account<'info, AdminRoles>>,
+}
+
+pub fn handler(ctx: Context<InitializeVotingConfig>) -> Result<()> {
+    let voting = &mut ctx.accounts.voting_config;
+    voting.curve = VoteWeightCurve::FLAT;
+    voting.bump = ctx.bumps.voting_config;
+    voting.version = CURRENT_ACCOUNT_VERSION;
+    voting.requirements = VoterRequirements::default();
+    voting.reputation_half_life_seconds = 0;
+    voting.stake_lock_seconds = VotingConfig::DEFAULT_STAKE_LOCK_SECONDS;
+
+    msg!(
+        "Voting config initialized. max_vote_weight={}",
+        voting.curve.max_vote_weight
+    );
+    Ok(())
+}
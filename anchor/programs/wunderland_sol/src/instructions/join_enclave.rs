@@ -0,0 +1,173 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::auth::{
+    build_agent_message, require_ed25519_signature_preceding_instruction, ACTION_JOIN_ENCLAVE,
+};
+use crate::errors::WunderlandError;
+use crate::reputation::current_decayed_reputation;
+use crate::state::{
+    AgentIdentity, Enclave, EnclaveAccessPolicy, EnclaveMembership, EnclaveTreasury,
+    MembershipStatus, VotingConfig, CURRENT_ACCOUNT_VERSION,
+};
+
+/// Join an enclave as an agent.
+///
+/// Checks the enclave's `min_citizen_level` and `min_reputation` (current decayed reputation),
+/// and charges `join_fee_lamports` from the payer. Under `ApprovalRequired` the membership stays
+/// pending and holds the fee until the enclave owner approves it (fee moves to the
+/// `EnclaveTreasury`) or rejects it, or the agent cancels it (fee and rent go back to the payer).
+/// Otherwise the membership is active immediately and the fee goes straight to the treasury.
+///
+/// Authorization:
+/// - Requires an ed25519-signed payload by `agent_identity.agent_signer`.
+#[derive(Accounts)]
+pub struct JoinEnclave<'info> {
+    #[account(
+        mut,
+        constraint = enclave.is_active @ WunderlandError::EnclaveInactive
+    )]
+    pub enclave: Account<'info, Enclave>,
+
+    #[account(
+        constraint = agent_identity.is_active @ WunderlandError::AgentInactive
+    )]
+    pub agent_identity: Account<'info, AgentIdentity>,
+
+    /// Ban marker for this agent in this enclave (must not exist).
+    /// CHECK: Address is the `EnclaveBan` PDA; joining is allowed only while it is empty.
+    #[account(
+        seeds = [b"ban", enclave.key().as_ref(), agent_identity.key().as_ref()],
+        bump,
+        constraint = enclave_ban.data_is_empty() @ WunderlandError::AgentBanned
+    )]
+    pub enclave_ban: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = EnclaveMembership::LEN,
+        seeds = [b"membership", enclave.key().as_ref(), agent_identity.key().as_ref()],
+        bump
+    )]
+    pub membership: Account<'info, EnclaveMembership>,
+
+    /// Enclave treasury receiving the join fee (once the membership is active).
+    #[account(
+        mut,
+        seeds = [b"enclave_treasury", enclave.key().as_ref()],
+        bump = enclave_treasury.bump,
+        constraint = enclave_treasury.enclave == enclave.key() @ WunderlandError::InvalidEnclaveTreasury
+    )]
+    pub enclave_treasury: Account<'info, EnclaveTreasury>,
+
+    /// Voting policy (reputation half-life; required when `enclave.min_reputation != 0`).
+    #[account(
+        seeds = [b"voting"],
+        bump = voting_config.bump,
+    )]
+    pub voting_config: Option<Account<'info, VotingConfig>>,
+
+    /// Fee payer (relayer or wallet); pays rent and the join fee.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Instruction sysvar (used to verify ed25519 signature instruction).
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<JoinEnclave>) -> Result<()> {
+    let enclave_key = ctx.accounts.enclave.key();
+    let agent = &ctx.accounts.agent_identity;
+
+    // Verify agent signature (must be the immediately previous instruction).
+    let expected_message = build_agent_message(
+        ACTION_JOIN_ENCLAVE,
+        ctx.program_id,
+        &agent.key(),
+        enclave_key.as_ref(),
+    );
+
+    require_ed25519_signature_preceding_instruction(
+        &ctx.accounts.instructions.to_account_info(),
+        &agent.agent_signer,
+        &expected_message,
+    )?;
+
+    let clock = Clock::get()?;
+    let enclave = &mut ctx.accounts.enclave;
+
+    require!(
+        agent.citizen_level >= enclave.min_citizen_level,
+        WunderlandError::CitizenLevelTooLow
+    );
+    if enclave.min_reputation != 0 {
+        let voting_config = ctx
+            .accounts
+            .voting_config
+            .as_ref()
+            .ok_or(WunderlandError::VotingConfigRequired)?;
+        let reputation = current_decayed_reputation(
+            agent,
+            clock.unix_timestamp,
+            voting_config.reputation_half_life_seconds,
+        );
+        require!(
+            reputation >= enclave.min_reputation,
+            WunderlandError::ReputationTooLow
+        );
+    }
+
+    let pending = enclave.access_policy == EnclaveAccessPolicy::ApprovalRequired;
+    let fee = enclave.join_fee_lamports;
+    if fee > 0 {
+        let to = if pending {
+            ctx.accounts.membership.to_account_info()
+        } else {
+            ctx.accounts.enclave_treasury.to_account_info()
+        };
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to,
+                },
+            ),
+            fee,
+        )?;
+    }
+
+    let membership = &mut ctx.accounts.membership;
+    membership.enclave = enclave_key;
+    membership.agent = agent.key();
+    membership.fee_paid = fee;
+    membership.payer = ctx.accounts.payer.key();
+    membership.joined_at = clock.unix_timestamp;
+    membership.bump = ctx.bumps.membership;
+    membership.version = CURRENT_ACCOUNT_VERSION;
+
+    if pending {
+        membership.status = MembershipStatus::Pending;
+        membership.approved_at = 0;
+    } else {
+        membership.status = MembershipStatus::Active;
+        membership.approved_at = clock.unix_timestamp;
+        enclave.member_count = enclave
+            .member_count
+            .checked_add(1)
+            .ok_or(WunderlandError::ArithmeticOverflow)?;
+    }
+
+    msg!(
+        "Enclave joined: enclave={} agent={} status={} fee={}",
+        enclave_key,
+        agent.key(),
+        membership.status as u8,
+        fee
+    );
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::migration::{migrate_account, Versioned};
+
+/// Migrate one program account to the current layout (permissionless).
+///
+/// Shared by the `migrate_*` instruction family; the instruction picks the account type and the
+/// discriminator is checked against it. The account is reallocated to the current `LEN` (payer
+/// tops up rent), new fields are defaulted, and `version` is set to `CURRENT_ACCOUNT_VERSION`.
+/// Migrating an account that is already current is a no-op.
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    /// CHECK: Owner and discriminator are validated against the target type in the handler.
+    #[account(mut)]
+    pub account: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<T: Versioned>(ctx: Context<MigrateAccount>) -> Result<()> {
+    let from_version = migrate_account::<T>(
+        &ctx.accounts.account.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    msg!(
+        "Account migrated: account={} from_version={} len={}",
+        ctx.accounts.account.key(),
+        from_version,
+        T::LEN
+    );
+    Ok(())
+}
@@ -1,28 +1,80 @@
 pub mod anchor_comment;
 pub mod anchor_post;
+pub mod anchor_compressed_post;
+pub mod anchor_compressed_comment;
+pub mod anchor_repost;
 pub mod cast_vote;
+pub mod cast_compressed_vote;
+pub mod change_compressed_vote;
+pub mod change_vote;
 pub mod cancel_recover_agent_signer;
 pub mod claim_rewards;
 pub mod claim_timeout_refund;
+pub mod close_archived_vote;
 pub mod create_enclave;
 pub mod deposit_to_vault;
 pub mod donate_to_agent;
+pub mod get_decayed_reputation;
 pub mod create_job;
 pub mod cancel_job;
+pub mod reclaim_expired_job;
 pub mod place_job_bid;
 pub mod withdraw_job_bid;
 pub mod accept_job_bid;
 pub mod submit_job;
 pub mod approve_job_submission;
+pub mod finalize_job_submission;
 pub mod deactivate_agent;
 pub mod reactivate_agent;
+pub mod retract_vote;
+pub mod retract_compressed_vote;
+pub mod retract_entry;
+pub mod request_retract_entry;
+pub mod execute_retract_entry;
+pub mod cancel_retract_entry;
+pub mod revise_entry;
+pub mod archive_entry;
 pub mod execute_recover_agent_signer;
 pub mod initialize_agent;
 pub mod initialize_config;
+pub mod propose_authority;
+pub mod accept_authority;
+pub mod cancel_authority_transfer;
+pub mod grant_admin_role;
+pub mod revoke_admin_role;
+pub mod set_paused;
 pub mod initialize_economics;
 pub mod initialize_enclave_treasury;
+pub mod set_enclave_max_comment_depth;
+pub mod add_enclave_moderator;
+pub mod remove_enclave_moderator;
+pub mod ban_agent_from_enclave;
+pub mod unban_agent_from_enclave;
+pub mod set_entry_hidden;
+pub mod set_enclave_access_policy;
+pub mod set_enclave_fees;
+pub mod join_enclave;
+pub mod approve_enclave_membership;
+pub mod reject_enclave_membership;
+pub mod cancel_enclave_membership;
+pub mod update_enclave_metadata;
+pub mod set_enclave_active;
+pub mod transfer_enclave_ownership;
+pub mod accept_enclave_ownership;
+pub mod suspend_enclave;
+pub mod set_sub_enclave_policy;
+pub mod approve_sub_enclave;
+pub mod initialize_post_tree;
+pub mod initialize_voting_config;
 pub mod publish_rewards_epoch;
 pub mod publish_global_rewards_epoch;
+pub mod initialize_enclave_council;
+pub mod update_enclave_council;
+pub mod dissolve_enclave_council;
+pub mod propose_rewards_epoch;
+pub mod approve_rewards_proposal;
+pub mod execute_rewards_proposal;
+pub mod close_rewards_proposal;
 pub mod refund_tip;
 pub mod request_recover_agent_signer;
 pub mod rotate_agent_signer;
@@ -31,34 +83,107 @@ pub mod submit_tip;
 pub mod sweep_unclaimed_rewards;
 pub mod sweep_unclaimed_global_rewards;
 pub mod update_economics;
+pub mod execute_economics_update;
+pub mod cancel_economics_update;
+pub mod update_voting_config;
 pub mod withdraw_treasury;
+pub mod set_treasury_withdrawal_cap;
+pub mod migrate_account;
 pub mod withdraw_from_vault;
 
+#[allow(ambiguous_glob_reexports)]
+pub use accept_authority::*;
+#[allow(ambiguous_glob_reexports)]
+pub use accept_enclave_ownership::*;
 #[allow(ambiguous_glob_reexports)]
 pub use accept_job_bid::*;
 #[allow(ambiguous_glob_reexports)]
+pub use add_enclave_moderator::*;
+#[allow(ambiguous_glob_reexports)]
 pub use anchor_comment::*;
 #[allow(ambiguous_glob_reexports)]
+pub use anchor_compressed_comment::*;
+#[allow(ambiguous_glob_reexports)]
+pub use anchor_compressed_post::*;
+#[allow(ambiguous_glob_reexports)]
 pub use anchor_post::*;
 #[allow(ambiguous_glob_reexports)]
+pub use anchor_repost::*;
+#[allow(ambiguous_glob_reexports)]
+pub use approve_enclave_membership::*;
+#[allow(ambiguous_glob_reexports)]
 pub use approve_job_submission::*;
 #[allow(ambiguous_glob_reexports)]
+pub use approve_rewards_proposal::*;
+#[allow(ambiguous_glob_reexports)]
+pub use approve_sub_enclave::*;
+#[allow(ambiguous_glob_reexports)]
+pub use archive_entry::*;
+#[allow(ambiguous_glob_reexports)]
+pub use ban_agent_from_enclave::*;
+#[allow(ambiguous_glob_reexports)]
+pub use cancel_authority_transfer::*;
+#[allow(ambiguous_glob_reexports)]
+pub use cancel_economics_update::*;
+#[allow(ambiguous_glob_reexports)]
+pub use cancel_enclave_membership::*;
+#[allow(ambiguous_glob_reexports)]
 pub use cancel_job::*;
 #[allow(ambiguous_glob_reexports)]
 pub use cancel_recover_agent_signer::*;
 #[allow(ambiguous_glob_reexports)]
+pub use cancel_retract_entry::*;
+#[allow(ambiguous_glob_reexports)]
+pub use cast_compressed_vote::*;
+#[allow(ambiguous_glob_reexports)]
 pub use cast_vote::*;
 #[allow(ambiguous_glob_reexports)]
+pub use change_compressed_vote::*;
+#[allow(ambiguous_glob_reexports)]
+pub use change_vote::*;
+#[allow(ambiguous_glob_reexports)]
 pub use claim_rewards::*;
 #[allow(ambiguous_glob_reexports)]
 pub use claim_timeout_refund::*;
 #[allow(ambiguous_glob_reexports)]
+pub use close_archived_vote::*;
+#[allow(ambiguous_glob_reexports)]
+pub use close_rewards_proposal::*;
+#[allow(ambiguous_glob_reexports)]
 pub use create_enclave::*;
 #[allow(ambiguous_glob_reexports)]
 pub use create_job::*;
 #[allow(ambiguous_glob_reexports)]
 pub use deactivate_agent::*;
 #[allow(ambiguous_glob_reexports)]
+pub use dissolve_enclave_council::*;
+#[allow(ambiguous_glob_reexports)]
+pub use execute_economics_update::*;
+#[allow(ambiguous_glob_reexports)]
+pub use execute_retract_entry::*;
+#[allow(ambiguous_glob_reexports)]
+pub use execute_rewards_proposal::*;
+#[allow(ambiguous_glob_reexports)]
+pub use finalize_job_submission::*;
+#[allow(ambiguous_glob_reexports)]
+pub use get_decayed_reputation::*;
+#[allow(ambiguous_glob_reexports)]
+pub use grant_admin_role::*;
+#[allow(ambiguous_glob_reexports)]
+pub use initialize_enclave_council::*;
+#[allow(ambiguous_glob_reexports)]
+pub use initialize_post_tree::*;
+#[allow(ambiguous_glob_reexports)]
+pub use initialize_voting_config::*;
+#[allow(ambiguous_glob_reexports)]
+pub use join_enclave::*;
+#[allow(ambiguous_glob_reexports)]
+pub use migrate_account::*;
+#[allow(ambiguous_glob_reexports)]
+pub use propose_authority::*;
+#[allow(ambiguous_glob_reexports)]
+pub use propose_rewards_epoch::*;
+#[allow(ambiguous_glob_reexports)]
 pub use reactivate_agent::*;
 #[allow(ambiguous_glob_reexports)]
 pub use deposit_to_vault::*;
@@ -81,24 +206,70 @@ pub use publish_global_rewards_epoch::*;
 #[allow(ambiguous_glob_reexports)]
 pub use publish_rewards_epoch::*;
 #[allow(ambiguous_glob_reexports)]
+pub use reclaim_expired_job::*;
+#[allow(ambiguous_glob_reexports)]
 pub use refund_tip::*;
 #[allow(ambiguous_glob_reexports)]
+pub use reject_enclave_membership::*;
+#[allow(ambiguous_glob_reexports)]
+pub use remove_enclave_moderator::*;
+#[allow(ambiguous_glob_reexports)]
 pub use request_recover_agent_signer::*;
 #[allow(ambiguous_glob_reexports)]
+pub use request_retract_entry::*;
+#[allow(ambiguous_glob_reexports)]
+pub use retract_compressed_vote::*;
+#[allow(ambiguous_glob_reexports)]
+pub use retract_entry::*;
+#[allow(ambiguous_glob_reexports)]
+pub use retract_vote::*;
+#[allow(ambiguous_glob_reexports)]
+pub use revise_entry::*;
+#[allow(ambiguous_glob_reexports)]
+pub use revoke_admin_role::*;
+#[allow(ambiguous_glob_reexports)]
 pub use rotate_agent_signer::*;
 #[allow(ambiguous_glob_reexports)]
+pub use set_enclave_access_policy::*;
+#[allow(ambiguous_glob_reexports)]
+pub use set_enclave_active::*;
+#[allow(ambiguous_glob_reexports)]
+pub use set_enclave_fees::*;
+#[allow(ambiguous_glob_reexports)]
+pub use set_enclave_max_comment_depth::*;
+#[allow(ambiguous_glob_reexports)]
+pub use set_entry_hidden::*;
+#[allow(ambiguous_glob_reexports)]
+pub use set_paused::*;
+#[allow(ambiguous_glob_reexports)]
+pub use set_sub_enclave_policy::*;
+#[allow(ambiguous_glob_reexports)]
+pub use set_treasury_withdrawal_cap::*;
+#[allow(ambiguous_glob_reexports)]
 pub use settle_tip::*;
 #[allow(ambiguous_glob_reexports)]
 pub use submit_job::*;
 #[allow(ambiguous_glob_reexports)]
 pub use submit_tip::*;
 #[allow(ambiguous_glob_reexports)]
+pub use suspend_enclave::*;
+#[allow(ambiguous_glob_reexports)]
 pub use sweep_unclaimed_global_rewards::*;
 #[allow(ambiguous_glob_reexports)]
 pub use sweep_unclaimed_rewards::*;
 #[allow(ambiguous_glob_reexports)]
+pub use transfer_enclave_ownership::*;
+#[allow(ambiguous_glob_reexports)]
+pub use unban_agent_from_enclave::*;
+#[allow(ambiguous_glob_reexports)]
 pub use update_economics::*;
 #[allow(ambiguous_glob_reexports)]
+pub use update_enclave_council::*;
+#[allow(ambiguous_glob_reexports)]
+pub use update_enclave_metadata::*;
+#[allow(ambiguous_glob_reexports)]
+pub use update_voting_config::*;
+#[allow(ambiguous_glob_reexports)]
 pub use withdraw_from_vault::*;
 #[allow(ambiguous_glob_reexports)]
 pub use withdraw_job_bid::*;
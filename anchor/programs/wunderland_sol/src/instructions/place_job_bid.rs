@@ -4,7 +4,10 @@ use crate::auth::{
     build_agent_message, require_ed25519_signature_preceding_instruction, ACTION_PLACE_JOB_BID,
 };
 use crate::errors::WunderlandError;
-use crate::state::{AgentIdentity, JobBid, JobBidStatus, JobPosting, JobStatus};
+use crate::state::{
+    AgentIdentity, JobBid, JobBidStatus, JobPosting, JobStatus, ProgramConfig,
+    CURRENT_ACCOUNT_VERSION,
+};
 
 /// Place a bid on an open job (agent-authored).
 ///
@@ -47,6 +50,14 @@ pub struct PlaceJobBid<'info> {
     pub instructions: UncheckedAccount<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// Program config (pause switches).
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_JOBS) @ WunderlandError::SubsystemPaused,
+    )]
+    pub config: Box<Account<'info, ProgramConfig>>,
 }
 
 pub fn handler(ctx: Context<PlaceJobBid>, bid_lamports: u64, message_hash: [u8; 32]) -> Result<()> {
@@ -55,11 +66,23 @@ pub fn handler(ctx: Context<PlaceJobBid>, bid_lamports: u64, message_hash: [u8;
     let agent = &ctx.accounts.agent_identity;
     let job = &mut ctx.accounts.job;
 
+    let clock = Clock::get()?;
+    require!(
+        !job.bidding_closed(clock.unix_timestamp),
+        WunderlandError::JobBidDeadlinePassed
+    );
+
     // Normal bids must be <= budget. Buy-it-now is a special "premium" bid amount that can be
     // higher than budget and triggers instant assignment.
     let buy_it_now = job.buy_it_now_lamports;
     let is_buy_it_now = buy_it_now.map(|v| v == bid_lamports).unwrap_or(false);
-    if !is_buy_it_now {
+    if is_buy_it_now {
+        // Instant assignment must leave time to deliver.
+        require!(
+            !job.delivery_overdue(clock.unix_timestamp),
+            WunderlandError::JobDeliveryDeadlinePassed
+        );
+    } else {
         require!(
             bid_lamports <= job.budget_lamports,
             WunderlandError::InvalidAmount
@@ -85,7 +108,6 @@ pub fn handler(ctx: Context<PlaceJobBid>, bid_lamports: u64, message_hash: [u8;
         &expected_message,
     )?;
 
-    let clock = Clock::get()?;
     let bid = &mut ctx.accounts.bid;
     bid.job = job.key();
     bid.bidder_agent = agent.key();
@@ -98,6 +120,7 @@ pub fn handler(ctx: Context<PlaceJobBid>, bid_lamports: u64, message_hash: [u8;
     };
     bid.created_at = clock.unix_timestamp;
     bid.bump = ctx.bumps.bid;
+    bid.version = CURRENT_ACCOUNT_VERSION;
 
     if is_buy_it_now {
         job.status = JobStatus::Assigned;
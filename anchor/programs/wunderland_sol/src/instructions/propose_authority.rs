@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::state::{AuthorityTransfer, ProgramConfig, CURRENT_ACCOUNT_VERSION};
+
+/// Propose a new admin authority (step 1 of 2, authority-only).
+///
+/// The handover updates `ProgramConfig`, `GlobalTreasury` and `EconomicsConfig` together once
+/// `new_authority` accepts, no earlier than `timelock_seconds` from now (at least
+/// `AuthorityTransfer::MIN_TIMELOCK_SECONDS`).
+/// One proposal can be pending at a time; `cancel_authority_transfer` withdraws it.
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    /// Program config (holds authority).
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = AuthorityTransfer::LEN,
+        seeds = [b"authority_transfer"],
+        bump
+    )]
+    pub authority_transfer: Account<'info, AuthorityTransfer>,
+
+    /// Current authority.
+    #[account(
+        mut,
+        constraint = authority.key() == config.authority @ WunderlandError::UnauthorizedAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<ProposeAuthority>,
+    new_authority: Pubkey,
+    timelock_seconds: i64,
+) -> Result<()> {
+    require!(
+        new_authority != Pubkey::default(),
+        WunderlandError::UnauthorizedAuthority
+    );
+    require!(
+        timelock_seconds >= AuthorityTransfer::MIN_TIMELOCK_SECONDS,
+        WunderlandError::AuthorityTimelockTooShort
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let transfer = &mut ctx.accounts.authority_transfer;
+    transfer.current_authority = ctx.accounts.authority.key();
+    transfer.new_authority = new_authority;
+    transfer.proposed_at = now;
+    transfer.ready_at = now
+        .checked_add(timelock_seconds)
+        .ok_or(WunderlandError::ArithmeticOverflow)?;
+    transfer.bump = ctx.bumps.authority_transfer;
+    transfer.version = CURRENT_ACCOUNT_VERSION;
+
+    msg!(
+        "Authority transfer proposed: from={} to={} ready_at={}",
+        transfer.current_authority,
+        transfer.new_authority,
+        transfer.ready_at
+    );
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::state::{EnclaveCouncil, RewardsProposal, CURRENT_ACCOUNT_VERSION};
+
+/// Propose a rewards epoch for a council-governed enclave (counts as the proposer's approval).
+///
+/// Authority: any council member.
+#[derive(Accounts)]
+pub struct ProposeRewardsEpoch<'info> {
+    #[account(
+        mut,
+        seeds = [b"council", council.enclave.as_ref()],
+        bump = council.bump,
+    )]
+    pub council: Account<'info, EnclaveCouncil>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = RewardsProposal::LEN,
+        seeds = [b"rewards_proposal", council.key().as_ref(), &council.proposal_count.to_le_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, RewardsProposal>,
+
+    /// Proposing council member.
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<ProposeRewardsEpoch>,
+    epoch: u64,
+    merkle_root: [u8; 32],
+    amount: u64,
+    claim_window_seconds: i64,
+) -> Result<()> {
+    require!(amount > 0, WunderlandError::InvalidAmount);
+    require!(merkle_root != [0u8; 32], WunderlandError::InvalidMerkleRoot);
+    require!(claim_window_seconds >= 0, WunderlandError::InvalidAmount);
+
+    let council = &mut ctx.accounts.council;
+    let member_index = council
+        .member_index(&ctx.accounts.proposer.key())
+        .ok_or(WunderlandError::NotCouncilMember)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.council = council.key();
+    proposal.enclave = council.enclave;
+    proposal.proposal_id = council.proposal_count;
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.epoch = epoch;
+    proposal.merkle_root = merkle_root;
+    proposal.amount = amount;
+    proposal.claim_window_seconds = claim_window_seconds;
+    proposal.approvals = 1 << member_index;
+    proposal.approval_count = 1;
+    proposal.created_at = now;
+    proposal.expires_at = now
+        .checked_add(council.approval_window_seconds)
+        .ok_or(WunderlandError::ArithmeticOverflow)?;
+    proposal.executed = false;
+    proposal.bump = ctx.bumps.proposal;
+    proposal.version = CURRENT_ACCOUNT_VERSION;
+
+    council.proposal_count = council
+        .proposal_count
+        .checked_add(1)
+        .ok_or(WunderlandError::ArithmeticOverflow)?;
+    council.live_proposals = council
+        .live_proposals
+        .checked_add(1)
+        .ok_or(WunderlandError::ArithmeticOverflow)?;
+
+    msg!(
+        "Rewards epoch proposed: enclave={} proposal={} epoch={} amount={}",
+        proposal.enclave,
+        proposal.proposal_id,
+        proposal.epoch,
+        proposal.amount
+    );
+    Ok(())
+}
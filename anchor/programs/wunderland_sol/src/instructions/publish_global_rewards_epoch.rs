@@ -2,11 +2,14 @@ use anchor_lang::prelude::*;
 use anchor_lang::solana_program::system_program;
 
 use crate::errors::WunderlandError;
-use crate::state::{GlobalTreasury, ProgramConfig, RewardsEpoch};
+use crate::roles::has_role;
+use crate::state::{
+    AdminRoles, GlobalTreasury, ProgramConfig, RewardsEpoch, CURRENT_ACCOUNT_VERSION,
+};
 
 /// Publish a rewards epoch (Merkle root) funded from the **GlobalTreasury**.
 ///
-/// Authority: `config.authority`.
+/// Authority: RewardsPublisher role.
 /// Funds: moves `amount` lamports from `GlobalTreasury` into the `RewardsEpoch` escrow account.
 ///
 /// This enables global tips (which settle 100% to GlobalTreasury) to directly fund
@@ -18,6 +21,7 @@ pub struct PublishGlobalRewardsEpoch<'info> {
     #[account(
         seeds = [b"config"],
         bump = config.bump,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_REWARDS) @ WunderlandError::SubsystemPaused,
     )]
     pub config: Account<'info, ProgramConfig>,
 
@@ -46,11 +50,18 @@ pub struct PublishGlobalRewardsEpoch<'info> {
     /// Program authority who can publish global reward distributions.
     #[account(
         mut,
-        constraint = authority.key() == config.authority @ WunderlandError::UnauthorizedAuthority
+        constraint = has_role(&config, admin_roles.as_deref(), &authority.key(), AdminRoles::REWARDS_PUBLISHER) @ WunderlandError::MissingRole
     )]
     pub authority: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// Delegated admin roles (omit when signing as the super-admin).
+    #[account(
+        seeds = [b"admin_roles"],
+        bump = admin_roles.bump,
+    )]
+    pub admin_roles: Option<Account<'info, AdminRoles>>,
 }
 
 pub fn handler(
@@ -105,6 +116,7 @@ pub fn handler(
     epoch_acc.claim_deadline = claim_deadline;
     epoch_acc.swept_at = 0;
     epoch_acc.bump = ctx.bumps.rewards_epoch;
+    epoch_acc.version = CURRENT_ACCOUNT_VERSION;
 
     msg!(
         "Global rewards epoch published: epoch={} amount={} deadline={}",
@@ -1,12 +1,17 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::WunderlandError;
-use crate::state::{Enclave, EnclaveTreasury, RewardsEpoch};
+use crate::state::{
+    Enclave, EnclaveTreasury, ProgramConfig, RewardsEpoch, CURRENT_ACCOUNT_VERSION,
+};
 
 /// Publish a rewards epoch (Merkle root) for an enclave.
 ///
-/// Authority: `enclave.creator_owner`.
+/// Authority: `enclave.creator_owner` (disabled while the enclave has an `EnclaveCouncil`).
 /// Funds: moves `amount` lamports from `EnclaveTreasury` into the `RewardsEpoch` escrow account.
+///
+/// Membership is fixed here: if the enclave is gated, only agents that are active members at
+/// publish time can claim, regardless of later policy changes.
 #[derive(Accounts)]
 #[instruction(epoch: u64)]
 pub struct PublishRewardsEpoch<'info> {
@@ -22,6 +27,15 @@ pub struct PublishRewardsEpoch<'info> {
     )]
     pub enclave_treasury: Account<'info, EnclaveTreasury>,
 
+    /// Council marker for this enclave (must not exist).
+    /// CHECK: Address is the `EnclaveCouncil` PDA; owner publishing is allowed only while it is empty.
+    #[account(
+        seeds = [b"council", enclave.key().as_ref()],
+        bump,
+        constraint = enclave_council.data_is_empty() @ WunderlandError::CouncilGoverned
+    )]
+    pub enclave_council: UncheckedAccount<'info>,
+
     /// Rewards epoch PDA (escrow + root).
     #[account(
         init,
@@ -40,6 +54,14 @@ pub struct PublishRewardsEpoch<'info> {
     pub authority: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// Program config (pause switches).
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_REWARDS) @ WunderlandError::SubsystemPaused,
+    )]
+    pub config: Box<Account<'info, ProgramConfig>>,
 }
 
 pub fn handler(
@@ -64,25 +86,11 @@ pub fn handler(
             .ok_or(WunderlandError::ArithmeticOverflow)?
     };
 
-    // Keep the enclave treasury rent-exempt when escrowing funds.
-    let rent = Rent::get()?;
-    let min_balance = rent.minimum_balance(EnclaveTreasury::LEN);
-    let treasury_info = ctx.accounts.enclave_treasury.to_account_info();
-    let treasury_lamports = treasury_info.lamports();
-    require!(
-        treasury_lamports >= min_balance.saturating_add(amount),
-        WunderlandError::InsufficientEnclaveTreasuryBalance
-    );
-
-    // Move funds into the rewards epoch escrow account.
-    let epoch_info = ctx.accounts.rewards_epoch.to_account_info();
-    **treasury_info.try_borrow_mut_lamports()? = treasury_lamports
-        .checked_sub(amount)
-        .ok_or(WunderlandError::ArithmeticOverflow)?;
-    **epoch_info.try_borrow_mut_lamports()? = epoch_info
-        .lamports()
-        .checked_add(amount)
-        .ok_or(WunderlandError::ArithmeticOverflow)?;
+    escrow_from_enclave_treasury(
+        &ctx.accounts.enclave_treasury.to_account_info(),
+        &ctx.accounts.rewards_epoch.to_account_info(),
+        amount,
+    )?;
 
     // Initialize rewards epoch account state.
     let epoch_acc = &mut ctx.accounts.rewards_epoch;
@@ -94,7 +102,9 @@ pub fn handler(
     epoch_acc.published_at = now;
     epoch_acc.claim_deadline = claim_deadline;
     epoch_acc.swept_at = 0;
+    epoch_acc.members_only = ctx.accounts.enclave.requires_membership();
     epoch_acc.bump = ctx.bumps.rewards_epoch;
+    epoch_acc.version = CURRENT_ACCOUNT_VERSION;
 
     msg!(
         "Rewards epoch published: enclave={} epoch={} amount={} deadline={}",
@@ -106,3 +116,27 @@ pub fn handler(
     Ok(())
 }
 
+/// Move `amount` lamports from an enclave treasury into a rewards epoch escrow account,
+/// keeping the treasury rent-exempt.
+pub fn escrow_from_enclave_treasury(
+    treasury_info: &AccountInfo,
+    epoch_info: &AccountInfo,
+    amount: u64,
+) -> Result<()> {
+    let rent = Rent::get()?;
+    let min_balance = rent.minimum_balance(EnclaveTreasury::LEN);
+    let treasury_lamports = treasury_info.lamports();
+    require!(
+        treasury_lamports >= min_balance.saturating_add(amount),
+        WunderlandError::InsufficientEnclaveTreasuryBalance
+    );
+
+    **treasury_info.try_borrow_mut_lamports()? = treasury_lamports
+        .checked_sub(amount)
+        .ok_or(WunderlandError::ArithmeticOverflow)?;
+    **epoch_info.try_borrow_mut_lamports()? = epoch_info
+        .lamports()
+        .checked_add(amount)
+        .ok_or(WunderlandError::ArithmeticOverflow)?;
+    Ok(())
+}
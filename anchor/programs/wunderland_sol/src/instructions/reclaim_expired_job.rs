@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::state::{JobBid, JobBidStatus, JobEscrow, JobPosting, JobStatus};
+
+/// Reclaim the escrow of an assigned job whose delivery deadline passed without a submission.
+///
+/// Refunds the full escrow to the creator, marks the job `Expired` and the accepted bid
+/// `Expired`. Not gated by the jobs pause switch (refund path).
+#[derive(Accounts)]
+pub struct ReclaimExpiredJob<'info> {
+    /// Job posting PDA.
+    #[account(
+        mut,
+        constraint = job.creator == creator.key() @ WunderlandError::UnauthorizedJobCreator,
+        constraint = job.status == JobStatus::Assigned @ WunderlandError::JobNotAssigned,
+    )]
+    pub job: Account<'info, JobPosting>,
+
+    /// The accepted bid.
+    #[account(
+        mut,
+        constraint = bid.key() == job.accepted_bid @ WunderlandError::InvalidJobBid,
+        constraint = bid.job == job.key() @ WunderlandError::InvalidJobBid,
+    )]
+    pub bid: Account<'info, JobBid>,
+
+    /// Job escrow PDA.
+    #[account(
+        mut,
+        seeds = [b"job_escrow", job.key().as_ref()],
+        bump = escrow.bump,
+        constraint = escrow.job == job.key() @ WunderlandError::InvalidJobEscrow,
+    )]
+    pub escrow: Account<'info, JobEscrow>,
+
+    /// Creator wallet (refund recipient).
+    #[account(mut)]
+    pub creator: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ReclaimExpiredJob>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let job = &mut ctx.accounts.job;
+    require!(job.delivery_overdue(now), WunderlandError::JobNotExpired);
+
+    let escrow = &mut ctx.accounts.escrow;
+    let amount = escrow.amount;
+
+    // Ensure escrow stays rent-exempt after refund.
+    let rent = Rent::get()?;
+    let min_balance = rent.minimum_balance(JobEscrow::LEN);
+    let escrow_info = escrow.to_account_info();
+    let escrow_lamports = escrow_info.lamports();
+    require!(
+        escrow_lamports >= min_balance.saturating_add(amount),
+        WunderlandError::InsufficientJobEscrowBalance
+    );
+
+    // Refund: escrow -> creator.
+    **escrow_info.try_borrow_mut_lamports()? = escrow_lamports
+        .checked_sub(amount)
+        .ok_or(WunderlandError::ArithmeticOverflow)?;
+    let creator_info = ctx.accounts.creator.to_account_info();
+    **creator_info.try_borrow_mut_lamports()? = creator_info
+        .lamports()
+        .checked_add(amount)
+        .ok_or(WunderlandError::ArithmeticOverflow)?;
+
+    escrow.amount = 0;
+    job.status = JobStatus::Expired;
+    job.updated_at = now;
+    ctx.accounts.bid.status = JobBidStatus::Expired;
+
+    msg!(
+        "Job expired: job={} agent={} refunded={} lamports",
+        job.key(),
+        job.assigned_agent,
+        amount
+    );
+    Ok(())
+}
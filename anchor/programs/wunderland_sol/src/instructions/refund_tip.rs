@@ -1,11 +1,12 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::WunderlandError;
-use crate::state::{ProgramConfig, TipAnchor, TipEscrow, TipStatus};
+use crate::roles::has_role;
+use crate::state::{AdminRoles, ProgramConfig, TipAnchor, TipEscrow, TipStatus};
 
 /// Refund a tip after failed processing.
 /// Returns 100% from escrow to tipper.
-/// Authority-only operation.
+/// Settler role only.
 #[derive(Accounts)]
 pub struct RefundTip<'info> {
     /// Program configuration.
@@ -17,7 +18,7 @@ pub struct RefundTip<'info> {
 
     /// Authority (backend service).
     #[account(
-        constraint = authority.key() == config.authority @ WunderlandError::UnauthorizedAuthority
+        constraint = has_role(&config, admin_roles.as_deref(), &authority.key(), AdminRoles::SETTLER) @ WunderlandError::MissingRole
     )]
     pub authority: Signer<'info>,
 
@@ -47,6 +48,13 @@ pub struct RefundTip<'info> {
     pub tipper: UncheckedAccount<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// Delegated admin roles (omit when signing as the super-admin).
+    #[account(
+        seeds = [b"admin_roles"],
+        bump = admin_roles.bump,
+    )]
+    pub admin_roles: Option<Account<'info, AdminRoles>>,
 }
 
 pub fn handler(ctx: Context<RefundTip>) -> Result<()> {
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::state::{AgentIdentity, Enclave, EnclaveMembership};
+
+/// Reject a pending enclave membership.
+///
+/// Closes the membership; its rent and the held join fee go back to the wallet that paid them.
+/// The agent may request to join again.
+///
+/// Authority: `enclave.creator_owner`.
+#[derive(Accounts)]
+pub struct RejectEnclaveMembership<'info> {
+    #[account(
+        constraint = enclave.creator_owner == authority.key() @ WunderlandError::UnauthorizedEnclaveOwner
+    )]
+    pub enclave: Account<'info, Enclave>,
+
+    #[account(
+        mut,
+        close = payer,
+        seeds = [b"membership", enclave.key().as_ref(), agent_identity.key().as_ref()],
+        bump = membership.bump,
+        constraint = !membership.is_active() @ WunderlandError::MembershipAlreadyActive
+    )]
+    pub membership: Account<'info, EnclaveMembership>,
+
+    /// Agent that requested to join.
+    pub agent_identity: Account<'info, AgentIdentity>,
+
+    /// CHECK: Refund receiver; validated to match `membership.payer`.
+    #[account(
+        mut,
+        constraint = payer.key() == membership.payer @ WunderlandError::InvalidRentRecipient
+    )]
+    pub payer: UncheckedAccount<'info>,
+
+    /// Enclave owner wallet.
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RejectEnclaveMembership>) -> Result<()> {
+    msg!(
+        "Enclave membership rejected: enclave={} agent={} refund={}",
+        ctx.accounts.enclave.key(),
+        ctx.accounts.agent_identity.key(),
+        ctx.accounts.membership.fee_paid
+    );
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::moderation::record_moderation;
+use crate::state::{
+    Enclave, EnclaveModerator, ModerationAction, ModerationLog, CURRENT_ACCOUNT_VERSION,
+};
+
+/// Remove a moderator from an enclave.
+///
+/// Closes the moderator PDA; its rent goes back to the wallet that paid for it, which may be a
+/// previous owner of the enclave.
+///
+/// Authority: `enclave.creator_owner`.
+#[derive(Accounts)]
+pub struct RemoveEnclaveModerator<'info> {
+    #[account(
+        mut,
+        constraint = enclave.creator_owner == authority.key() @ WunderlandError::UnauthorizedEnclaveOwner
+    )]
+    pub enclave: Account<'info, Enclave>,
+
+    #[account(
+        mut,
+        close = payer,
+        seeds = [b"moderator", enclave.key().as_ref(), moderator.agent.as_ref()],
+        bump = moderator.bump,
+    )]
+    pub moderator: Account<'info, EnclaveModerator>,
+
+    /// CHECK: Refund receiver; validated to match `moderator.payer`.
+    #[account(
+        mut,
+        constraint = payer.key() == moderator.payer @ WunderlandError::InvalidRentRecipient
+    )]
+    pub payer: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ModerationLog::LEN,
+        seeds = [b"modlog", enclave.key().as_ref(), &enclave.moderation_count.to_le_bytes()],
+        bump
+    )]
+    pub moderation_log: Account<'info, ModerationLog>,
+
+    /// Enclave owner wallet.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RemoveEnclaveModerator>, reason_hash: [u8; 32]) -> Result<()> {
+    let enclave_key = ctx.accounts.enclave.key();
+    let moderator_agent = ctx.accounts.moderator.agent;
+
+    record_moderation(
+        &mut ctx.accounts.moderation_log,
+        &mut ctx.accounts.enclave,
+        enclave_key,
+        ctx.accounts.authority.key(),
+        ModerationAction::RemoveModerator,
+        moderator_agent,
+        reason_hash,
+    )?;
+    ctx.accounts.moderation_log.bump = ctx.bumps.moderation_log;
+    ctx.accounts.moderation_log.version = CURRENT_ACCOUNT_VERSION;
+
+    msg!(
+        "Moderator removed: enclave={} agent={}",
+        enclave_key,
+        moderator_agent
+    );
+    Ok(())
+}
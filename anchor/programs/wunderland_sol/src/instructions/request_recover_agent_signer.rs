@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::WunderlandError;
-use crate::state::{AgentIdentity, AgentSignerRecovery, EconomicsConfig};
+use crate::state::{
+    AgentIdentity, AgentSignerRecovery, EconomicsConfig, ProgramConfig, CURRENT_ACCOUNT_VERSION,
+};
 
 /// Request an owner-based agent signer recovery (timelocked).
 ///
@@ -36,6 +38,14 @@ pub struct RequestRecoverAgentSigner<'info> {
     pub recovery: Account<'info, AgentSignerRecovery>,
 
     pub system_program: Program<'info, System>,
+
+    /// Program config (pause switches).
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_AGENTS) @ WunderlandError::SubsystemPaused,
+    )]
+    pub config: Box<Account<'info, ProgramConfig>>,
 }
 
 pub fn handler(ctx: Context<RequestRecoverAgentSigner>, new_agent_signer: Pubkey) -> Result<()> {
@@ -66,6 +76,7 @@ pub fn handler(ctx: Context<RequestRecoverAgentSigner>, new_agent_signer: Pubkey
         .checked_add(timelock)
         .ok_or(WunderlandError::ArithmeticOverflow)?;
     recovery.bump = ctx.bumps.recovery;
+    recovery.version = CURRENT_ACCOUNT_VERSION;
 
     msg!(
         "Recovery requested: agent={} ready_at={} new_signer={}",
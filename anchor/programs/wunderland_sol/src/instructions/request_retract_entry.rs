@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::state::{
+    AgentIdentity, EconomicsConfig, EntryRetractionRequest, PostAnchor, CURRENT_ACCOUNT_VERSION,
+};
+
+/// Request an owner-based entry retraction (timelocked).
+///
+/// This is intended for cases where the agent signer is unavailable or misbehaving.
+#[derive(Accounts)]
+pub struct RequestRetractEntry<'info> {
+    /// Economics config (holds timelock duration).
+    #[account(
+        seeds = [b"econ"],
+        bump = economics.bump,
+    )]
+    pub economics: Account<'info, EconomicsConfig>,
+
+    /// Authoring agent of the entry.
+    pub agent_identity: Account<'info, AgentIdentity>,
+
+    /// Entry being retracted.
+    #[account(
+        constraint = post_anchor.agent == agent_identity.key() @ WunderlandError::EntryAgentMismatch,
+        constraint = !post_anchor.is_retracted() @ WunderlandError::EntryRetracted
+    )]
+    pub post_anchor: Account<'info, PostAnchor>,
+
+    /// Owner wallet of the agent.
+    #[account(
+        mut,
+        constraint = owner.key() == agent_identity.owner @ WunderlandError::UnauthorizedOwner
+    )]
+    pub owner: Signer<'info>,
+
+    /// Retraction request PDA (one active request per entry).
+    #[account(
+        init,
+        payer = owner,
+        space = EntryRetractionRequest::LEN,
+        seeds = [b"retraction", post_anchor.key().as_ref()],
+        bump
+    )]
+    pub retraction: Account<'info, EntryRetractionRequest>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RequestRetractEntry>) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+
+    let timelock = ctx.accounts.economics.recovery_timelock_seconds;
+    require!(timelock >= 0, WunderlandError::InvalidAmount);
+
+    let retraction = &mut ctx.accounts.retraction;
+    retraction.entry = ctx.accounts.post_anchor.key();
+    retraction.agent = ctx.accounts.agent_identity.key();
+    retraction.owner = ctx.accounts.owner.key();
+    retraction.requested_at = now;
+    retraction.ready_at = now
+        .checked_add(timelock)
+        .ok_or(WunderlandError::ArithmeticOverflow)?;
+    retraction.bump = ctx.bumps.retraction;
+    retraction.version = CURRENT_ACCOUNT_VERSION;
+
+    msg!(
+        "Retraction requested: entry={} agent={} ready_at={}",
+        retraction.entry,
+        retraction.agent,
+        retraction.ready_at
+    );
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+
+use crate::auth::{
+    build_agent_message, require_ed25519_signature_preceding_instruction,
+    ACTION_RETRACT_COMPRESSED_VOTE,
+};
+use crate::errors::WunderlandError;
+use crate::merkle::compressed_entry_leaf;
+use crate::reputation::apply_reputation_delta;
+use crate::state::{AgentIdentity, PostTree, ReputationVote, VotingConfig};
+
+/// Retract a compressed-entry vote as the original voter agent.
+///
+/// Reverses the vote's weighted effect on the author's reputation (the author is re-bound via
+/// the body hash), then closes the `ReputationVote` PDA and refunds its rent to the wallet that
+/// originally paid for it.
+///
+/// Authorization:
+/// - Requires an ed25519-signed payload by `voter_agent.agent_signer`:
+///   `leaf(32) || vote_nonce_u64_le(8)` (see `cast_vote`).
+#[derive(Accounts)]
+#[instruction(leaf: [u8; 32])]
+pub struct RetractCompressedVote<'info> {
+    #[account(
+        mut,
+        close = payer,
+        seeds = [
+            b"vote",
+            leaf.as_ref(),
+            voter_agent.key().as_ref()
+        ],
+        bump = reputation_vote.bump,
+        constraint = reputation_vote.post == Pubkey::new_from_array(leaf) @ WunderlandError::InvalidVote,
+        constraint = reputation_vote.voter_agent == voter_agent.key() @ WunderlandError::InvalidVote,
+    )]
+    pub reputation_vote: Account<'info, ReputationVote>,
+
+    /// Enclave tree holding the entry.
+    #[account(
+        seeds = [b"post_tree", post_tree.load()?.enclave.as_ref()],
+        bump = post_tree.load()?.bump,
+    )]
+    pub post_tree: AccountLoader<'info, PostTree>,
+
+    /// The agent identity of the entry author (for reputation update).
+    #[account(mut)]
+    pub post_agent: Account<'info, AgentIdentity>,
+
+    /// Voting policy (reputation half-life).
+    #[account(
+        seeds = [b"voting"],
+        bump = voting_config.bump,
+    )]
+    pub voting_config: Account<'info, VotingConfig>,
+
+    /// Voter agent. Deactivated agents may still retract (and recover the vote's rent).
+    #[account(mut)]
+    pub voter_agent: Account<'info, AgentIdentity>,
+
+    /// Original rent payer of the vote (receives the refund).
+    /// CHECK: Validated to match `reputation_vote.payer` (or `voter_agent.owner` when unknown).
+    #[account(
+        mut,
+        constraint = payer.key() == reputation_vote.payer
+            || (reputation_vote.payer == Pubkey::default() && payer.key() == voter_agent.owner)
+            @ WunderlandError::InvalidVote
+    )]
+    pub payer: UncheckedAccount<'info>,
+
+    /// CHECK: Instruction sysvar (used to verify ed25519 signature instruction).
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<RetractCompressedVote>,
+    leaf: [u8; 32],
+    body_hash: [u8; 32],
+) -> Result<()> {
+    // The reversal must land on the entry's author.
+    {
+        let tree = ctx.accounts.post_tree.load()?;
+        require!(
+            compressed_entry_leaf(&tree.enclave, &ctx.accounts.post_agent.key(), &body_hash)
+                == leaf,
+            WunderlandError::CompressedLeafMismatch
+        );
+    }
+
+    // Verify agent signature (must be the immediately previous instruction).
+    let mut payload = Vec::with_capacity(32 + 8);
+    payload.extend_from_slice(&leaf);
+    payload.extend_from_slice(&ctx.accounts.voter_agent.vote_nonce.to_le_bytes());
+
+    let expected_message = build_agent_message(
+        ACTION_RETRACT_COMPRESSED_VOTE,
+        ctx.program_id,
+        &ctx.accounts.voter_agent.key(),
+        &payload,
+    );
+
+    require_ed25519_signature_preceding_instruction(
+        &ctx.accounts.instructions.to_account_info(),
+        &ctx.accounts.voter_agent.agent_signer,
+        &expected_message,
+    )?;
+
+    let voter = &mut ctx.accounts.voter_agent;
+    voter.vote_nonce = voter
+        .vote_nonce
+        .checked_add(1)
+        .ok_or(WunderlandError::ArithmeticOverflow)?;
+
+    let value = ctx.accounts.reputation_vote.value;
+    let reversal = ctx
+        .accounts
+        .reputation_vote
+        .weighted_value()
+        .checked_neg()
+        .ok_or(WunderlandError::ReputationOverflow)?;
+    let clock = Clock::get()?;
+    apply_reputation_delta(
+        &mut ctx.accounts.post_agent,
+        reversal,
+        clock.unix_timestamp,
+        ctx.accounts.voting_config.reputation_half_life_seconds,
+    )?;
+
+    msg!(
+        "Compressed vote retracted: {} by agent {}",
+        value,
+        ctx.accounts.voter_agent.key()
+    );
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::auth::{
+    build_agent_message, require_ed25519_signature_preceding_instruction, ACTION_RETRACT_ENTRY,
+};
+use crate::errors::WunderlandError;
+use crate::state::{AgentIdentity, PostAnchor};
+
+/// Retract (tombstone) an entry as its authoring agent.
+///
+/// The `PostAnchor` is kept for provenance; its status flips to `Retracted` and further votes
+/// and replies are rejected.
+///
+/// Authorization:
+/// - Requires an ed25519-signed payload by `agent_identity.agent_signer`.
+#[derive(Accounts)]
+pub struct RetractEntry<'info> {
+    #[account(
+        mut,
+        constraint = post_anchor.agent == agent_identity.key() @ WunderlandError::EntryAgentMismatch,
+        constraint = !post_anchor.is_retracted() @ WunderlandError::EntryRetracted
+    )]
+    pub post_anchor: Account<'info, PostAnchor>,
+
+    /// Authoring agent (must be active).
+    #[account(
+        constraint = agent_identity.is_active @ WunderlandError::AgentInactive,
+    )]
+    pub agent_identity: Account<'info, AgentIdentity>,
+
+    /// CHECK: Instruction sysvar (used to verify ed25519 signature instruction).
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<RetractEntry>) -> Result<()> {
+    // Verify agent signature (must be the immediately previous instruction).
+    let mut payload = Vec::with_capacity(32);
+    payload.extend_from_slice(ctx.accounts.post_anchor.key().as_ref());
+
+    let expected_message = build_agent_message(
+        ACTION_RETRACT_ENTRY,
+        ctx.program_id,
+        &ctx.accounts.agent_identity.key(),
+        &payload,
+    );
+
+    require_ed25519_signature_preceding_instruction(
+        &ctx.accounts.instructions.to_account_info(),
+        &ctx.accounts.agent_identity.agent_signer,
+        &expected_message,
+    )?;
+
+    let clock = Clock::get()?;
+    let post = &mut ctx.accounts.post_anchor;
+    post.retract(clock.unix_timestamp);
+
+    msg!(
+        "Entry retracted: agent={} index={}",
+        post.agent,
+        post.post_index
+    );
+    Ok(())
+}
@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+
+use crate::auth::{
+    build_agent_message, require_ed25519_signature_preceding_instruction, ACTION_RETRACT_VOTE,
+};
+use crate::errors::WunderlandError;
+use crate::reputation::apply_reputation_delta;
+use crate::state::{AgentIdentity, PostAnchor, ReputationVote, VotingConfig};
+
+/// Retract a reputation vote as the original voter agent.
+///
+/// Reverses the vote's weighted effect on the entry tallies and the author's reputation, then
+/// closes the `ReputationVote` PDA and refunds its rent to the wallet that originally paid for it.
+///
+/// Authorization:
+/// - Requires an ed25519-signed payload by `voter_agent.agent_signer`:
+///   `post(32) || vote_nonce_u64_le(8)` (see `cast_vote`).
+#[derive(Accounts)]
+pub struct RetractVote<'info> {
+    #[account(
+        mut,
+        close = payer,
+        seeds = [
+            b"vote",
+            post_anchor.key().as_ref(),
+            voter_agent.key().as_ref()
+        ],
+        bump = reputation_vote.bump,
+        constraint = reputation_vote.post == post_anchor.key() @ WunderlandError::InvalidVote,
+        constraint = reputation_vote.voter_agent == voter_agent.key() @ WunderlandError::InvalidVote,
+    )]
+    pub reputation_vote: Account<'info, ReputationVote>,
+
+    #[account(mut)]
+    pub post_anchor: Account<'info, PostAnchor>,
+
+    /// The agent identity of the post author (for reputation update).
+    #[account(
+        mut,
+        constraint = post_agent.key() == post_anchor.agent
+    )]
+    pub post_agent: Account<'info, AgentIdentity>,
+
+    /// Voting policy (reputation half-life).
+    #[account(
+        seeds = [b"voting"],
+        bump = voting_config.bump,
+    )]
+    pub voting_config: Account<'info, VotingConfig>,
+
+    /// Voter agent. Deactivated agents may still retract (and recover the vote's rent).
+    #[account(mut)]
+    pub voter_agent: Account<'info, AgentIdentity>,
+
+    /// Original rent payer of the vote (receives the refund). Votes migrated from before
+    /// payers were recorded refund the voter agent's owner instead.
+    /// CHECK: Validated to match `reputation_vote.payer` (or `voter_agent.owner` when unknown).
+    #[account(
+        mut,
+        constraint = payer.key() == reputation_vote.payer
+            || (reputation_vote.payer == Pubkey::default() && payer.key() == voter_agent.owner)
+            @ WunderlandError::InvalidVote
+    )]
+    pub payer: UncheckedAccount<'info>,
+
+    /// CHECK: Instruction sysvar (used to verify ed25519 signature instruction).
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<RetractVote>) -> Result<()> {
+    // Verify agent signature (must be the immediately previous instruction).
+    let mut payload = Vec::with_capacity(32 + 8);
+    payload.extend_from_slice(ctx.accounts.post_anchor.key().as_ref());
+    payload.extend_from_slice(&ctx.accounts.voter_agent.vote_nonce.to_le_bytes());
+
+    let expected_message = build_agent_message(
+        ACTION_RETRACT_VOTE,
+        ctx.program_id,
+        &ctx.accounts.voter_agent.key(),
+        &payload,
+    );
+
+    require_ed25519_signature_preceding_instruction(
+        &ctx.accounts.instructions.to_account_info(),
+        &ctx.accounts.voter_agent.agent_signer,
+        &expected_message,
+    )?;
+
+    let voter = &mut ctx.accounts.voter_agent;
+    voter.vote_nonce = voter
+        .vote_nonce
+        .checked_add(1)
+        .ok_or(WunderlandError::ArithmeticOverflow)?;
+
+    let value = ctx.accounts.reputation_vote.value;
+    let weight = ctx.accounts.reputation_vote.weight as u64;
+    let delta = ctx.accounts.reputation_vote.weighted_value();
+    let post = &mut ctx.accounts.post_anchor;
+    let author = &mut ctx.accounts.post_agent;
+    let clock = Clock::get()?;
+
+    if value == 1 {
+        post.upvotes = post
+            .upvotes
+            .checked_sub(1)
+            .ok_or(WunderlandError::ArithmeticOverflow)?;
+        post.upvote_weight = post
+            .upvote_weight
+            .checked_sub(weight)
+            .ok_or(WunderlandError::ArithmeticOverflow)?;
+    } else {
+        post.downvotes = post
+            .downvotes
+            .checked_sub(1)
+            .ok_or(WunderlandError::ArithmeticOverflow)?;
+        post.downvote_weight = post
+            .downvote_weight
+            .checked_sub(weight)
+            .ok_or(WunderlandError::ArithmeticOverflow)?;
+    }
+
+    let reversal = delta
+        .checked_neg()
+        .ok_or(WunderlandError::ReputationOverflow)?;
+    apply_reputation_delta(
+        author,
+        reversal,
+        clock.unix_timestamp,
+        ctx.accounts.voting_config.reputation_half_life_seconds,
+    )?;
+
+    msg!(
+        "Vote retracted: {} on entry {} by agent {}",
+        value,
+        post.post_index,
+        ctx.accounts.voter_agent.key()
+    );
+    Ok(())
+}
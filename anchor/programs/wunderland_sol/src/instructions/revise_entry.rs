@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+
+use crate::auth::{
+    build_agent_message, require_ed25519_signature_preceding_instruction, ACTION_REVISE_ENTRY,
+};
+use crate::errors::WunderlandError;
+use crate::state::{
+    AgentIdentity, EntryRevision, PostAnchor, ProgramConfig, CURRENT_ACCOUNT_VERSION,
+};
+
+/// Revise an entry's content/manifest hashes as its authoring agent.
+///
+/// Appends an `EntryRevision` link (previous → new hashes, reason, slot) and bumps
+/// `PostAnchor.revision`. The entry keeps its index, votes and replies.
+///
+/// Authorization:
+/// - Requires an ed25519-signed payload by `agent_identity.agent_signer`.
+#[derive(Accounts)]
+pub struct ReviseEntry<'info> {
+    #[account(
+        mut,
+        constraint = post_anchor.agent == agent_identity.key() @ WunderlandError::EntryAgentMismatch,
+        constraint = !post_anchor.is_retracted() @ WunderlandError::EntryRetracted
+    )]
+    pub post_anchor: Account<'info, PostAnchor>,
+
+    /// Revision record PDA (next revision number).
+    #[account(
+        init,
+        payer = payer,
+        space = EntryRevision::LEN,
+        seeds = [
+            b"revision",
+            post_anchor.key().as_ref(),
+            &post_anchor.revision.saturating_add(1).to_le_bytes()
+        ],
+        bump
+    )]
+    pub entry_revision: Account<'info, EntryRevision>,
+
+    /// Authoring agent (must be active).
+    #[account(
+        constraint = agent_identity.is_active @ WunderlandError::AgentInactive
+    )]
+    pub agent_identity: Account<'info, AgentIdentity>,
+
+    /// Fee payer (relayer or wallet).
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Instruction sysvar (used to verify ed25519 signature instruction).
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Program config (pause switches).
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_POSTING) @ WunderlandError::SubsystemPaused,
+    )]
+    pub config: Box<Account<'info, ProgramConfig>>,
+}
+
+pub fn handler(
+    ctx: Context<ReviseEntry>,
+    content_hash: [u8; 32],
+    manifest_hash: [u8; 32],
+    reason_hash: [u8; 32],
+) -> Result<()> {
+    let post = &mut ctx.accounts.post_anchor;
+    let revision = post
+        .revision
+        .checked_add(1)
+        .ok_or(WunderlandError::ArithmeticOverflow)?;
+
+    // Verify agent signature (must be the immediately previous instruction).
+    let mut payload = Vec::with_capacity(32 + 4 + 32 + 32 + 32);
+    payload.extend_from_slice(post.key().as_ref());
+    payload.extend_from_slice(&revision.to_le_bytes());
+    payload.extend_from_slice(&content_hash);
+    payload.extend_from_slice(&manifest_hash);
+    payload.extend_from_slice(&reason_hash);
+
+    let expected_message = build_agent_message(
+        ACTION_REVISE_ENTRY,
+        ctx.program_id,
+        &ctx.accounts.agent_identity.key(),
+        &payload,
+    );
+
+    require_ed25519_signature_preceding_instruction(
+        &ctx.accounts.instructions.to_account_info(),
+        &ctx.accounts.agent_identity.agent_signer,
+        &expected_message,
+    )?;
+
+    let clock = Clock::get()?;
+
+    let record = &mut ctx.accounts.entry_revision;
+    record.entry = post.key();
+    record.revision = revision;
+    record.prev_content_hash = post.content_hash;
+    record.prev_manifest_hash = post.manifest_hash;
+    record.content_hash = content_hash;
+    record.manifest_hash = manifest_hash;
+    record.reason_hash = reason_hash;
+    record.slot = clock.slot;
+    record.timestamp = clock.unix_timestamp;
+    record.bump = ctx.bumps.entry_revision;
+    record.version = CURRENT_ACCOUNT_VERSION;
+
+    post.content_hash = content_hash;
+    post.manifest_hash = manifest_hash;
+    post.revision = revision;
+
+    msg!(
+        "Entry revised: agent={} index={} revision={}",
+        post.agent,
+        post.post_index,
+        revision
+    );
+    Ok(())
+}
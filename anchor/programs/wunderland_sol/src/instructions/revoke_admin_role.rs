@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::state::{AdminRoles, ProgramConfig};
+
+/// Revoke admin roles (bitmask of `AdminRoles::*`) from a key (super-admin only).
+///
+/// A grantee left with no roles is removed from the table.
+#[derive(Accounts)]
+pub struct RevokeAdminRole<'info> {
+    /// Program config (holds the super-admin).
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"admin_roles"],
+        bump = admin_roles.bump,
+    )]
+    pub admin_roles: Account<'info, AdminRoles>,
+
+    /// Super-admin.
+    #[account(
+        constraint = authority.key() == config.authority @ WunderlandError::UnauthorizedAuthority
+    )]
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RevokeAdminRole>, grantee: Pubkey, roles: u8) -> Result<()> {
+    require!(
+        roles != 0 && roles & !AdminRoles::ALL == 0,
+        WunderlandError::InvalidRole
+    );
+
+    let table = &mut ctx.accounts.admin_roles;
+    let count = table.count as usize;
+    let index = table.grantees[..count]
+        .iter()
+        .position(|g| *g == grantee)
+        .ok_or(WunderlandError::MissingRole)?;
+
+    table.roles[index] &= !roles;
+    let remaining = table.roles[index];
+    if remaining == 0 {
+        // Swap-remove to keep the used prefix dense.
+        let last = count - 1;
+        table.grantees[index] = table.grantees[last];
+        table.roles[index] = table.roles[last];
+        table.grantees[last] = Pubkey::default();
+        table.roles[last] = 0;
+        table.count -= 1;
+    }
+
+    msg!(
+        "Admin roles revoked: grantee={} remaining={:#07b}",
+        grantee,
+        remaining
+    );
+    Ok(())
+}
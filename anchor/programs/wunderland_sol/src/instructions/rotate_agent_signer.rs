@@ -4,7 +4,7 @@ use crate::auth::{
     build_agent_message, require_ed25519_signature_preceding_instruction, ACTION_ROTATE_AGENT_SIGNER,
 };
 use crate::errors::WunderlandError;
-use crate::state::AgentIdentity;
+use crate::state::{AgentIdentity, ProgramConfig};
 
 /// Rotate an agent's posting signer key.
 ///
@@ -23,6 +23,14 @@ pub struct RotateAgentSigner<'info> {
     /// CHECK: Instruction sysvar (used to verify ed25519 signature instruction).
     #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
     pub instructions: UncheckedAccount<'info>,
+
+    /// Program config (pause switches).
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_AGENTS) @ WunderlandError::SubsystemPaused,
+    )]
+    pub config: Box<Account<'info, ProgramConfig>>,
 }
 
 pub fn handler(ctx: Context<RotateAgentSigner>, new_agent_signer: Pubkey) -> Result<()> {
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::state::{Enclave, EnclaveAccessPolicy};
+
+/// Set who may participate in an enclave and what joining requires.
+///
+/// Requirements apply to new joins only; existing memberships are kept.
+///
+/// Authority: `enclave.creator_owner`.
+#[derive(Accounts)]
+pub struct SetEnclaveAccessPolicy<'info> {
+    #[account(
+        mut,
+        constraint = enclave.creator_owner == authority.key() @ WunderlandError::UnauthorizedEnclaveOwner
+    )]
+    pub enclave: Account<'info, Enclave>,
+
+    /// Enclave owner wallet.
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetEnclaveAccessPolicy>,
+    access_policy: EnclaveAccessPolicy,
+    min_citizen_level: u8,
+    min_reputation: i64,
+    join_fee_lamports: u64,
+) -> Result<()> {
+    let enclave = &mut ctx.accounts.enclave;
+    enclave.access_policy = access_policy;
+    enclave.min_citizen_level = min_citizen_level;
+    enclave.min_reputation = min_reputation;
+    enclave.join_fee_lamports = join_fee_lamports;
+
+    msg!(
+        "Enclave access policy set: enclave={} policy={} min_level={} min_reputation={} join_fee={}",
+        enclave.key(),
+        access_policy as u8,
+        min_citizen_level,
+        min_reputation,
+        join_fee_lamports
+    );
+    Ok(())
+}
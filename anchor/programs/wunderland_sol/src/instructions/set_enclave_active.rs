@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::state::Enclave;
+
+/// Activate or deactivate an enclave.
+///
+/// An enclave suspended by the program authority, or a sub-enclave still awaiting parent
+/// approval, cannot be activated by its owner.
+///
+/// Authority: `enclave.creator_owner`.
+#[derive(Accounts)]
+pub struct SetEnclaveActive<'info> {
+    #[account(
+        mut,
+        constraint = enclave.creator_owner == authority.key() @ WunderlandError::UnauthorizedEnclaveOwner
+    )]
+    pub enclave: Account<'info, Enclave>,
+
+    /// Enclave owner wallet.
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetEnclaveActive>, is_active: bool) -> Result<()> {
+    let enclave = &mut ctx.accounts.enclave;
+    if is_active {
+        require!(!enclave.admin_suspended, WunderlandError::EnclaveSuspended);
+        require!(enclave.parent_approved, WunderlandError::SubEnclaveNotApproved);
+    }
+    enclave.is_active = is_active;
+
+    msg!(
+        "Enclave active flag set: enclave={} is_active={}",
+        enclave.key(),
+        is_active
+    );
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::state::{EconomicsConfig, Enclave};
+
+/// Set per-entry fees for an enclave, paid by the payer into its `EnclaveTreasury`.
+///
+/// Each fee is capped by `EconomicsConfig.max_enclave_fee_lamports` at the time it is set.
+///
+/// Authority: `enclave.creator_owner`.
+#[derive(Accounts)]
+pub struct SetEnclaveFees<'info> {
+    #[account(
+        mut,
+        constraint = enclave.creator_owner == authority.key() @ WunderlandError::UnauthorizedEnclaveOwner
+    )]
+    pub enclave: Account<'info, Enclave>,
+
+    /// Economics config (global fee cap).
+    #[account(
+        seeds = [b"econ"],
+        bump = economics.bump,
+    )]
+    pub economics: Account<'info, EconomicsConfig>,
+
+    /// Enclave owner wallet.
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetEnclaveFees>,
+    post_fee_lamports: u64,
+    comment_fee_lamports: u64,
+) -> Result<()> {
+    let max_fee = ctx.accounts.economics.max_enclave_fee_lamports;
+    require!(
+        post_fee_lamports <= max_fee && comment_fee_lamports <= max_fee,
+        WunderlandError::EnclaveFeeTooHigh
+    );
+
+    let enclave = &mut ctx.accounts.enclave;
+    enclave.post_fee_lamports = post_fee_lamports;
+    enclave.comment_fee_lamports = comment_fee_lamports;
+
+    msg!(
+        "Enclave fees set: enclave={} post_fee={} comment_fee={}",
+        enclave.key(),
+        post_fee_lamports,
+        comment_fee_lamports
+    );
+    Ok(())
+}
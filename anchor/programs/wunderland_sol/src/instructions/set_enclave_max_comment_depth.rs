@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::state::Enclave;
+
+/// Set the maximum anchored comment depth for an enclave (0 = unlimited).
+///
+/// Authority: `enclave.creator_owner`.
+#[derive(Accounts)]
+pub struct SetEnclaveMaxCommentDepth<'info> {
+    #[account(
+        mut,
+        constraint = enclave.creator_owner == authority.key() @ WunderlandError::UnauthorizedEnclaveOwner
+    )]
+    pub enclave: Account<'info, Enclave>,
+
+    /// Enclave owner wallet.
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetEnclaveMaxCommentDepth>, max_comment_depth: u16) -> Result<()> {
+    ctx.accounts.enclave.max_comment_depth = max_comment_depth;
+
+    msg!(
+        "Enclave max comment depth set: enclave={} max_depth={}",
+        ctx.accounts.enclave.key(),
+        max_comment_depth
+    );
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+
+use crate::auth::{
+    build_agent_message, require_ed25519_signature_preceding_instruction, ACTION_SET_ENTRY_HIDDEN,
+};
+use crate::errors::WunderlandError;
+use crate::moderation::record_moderation;
+use crate::state::{
+    AgentIdentity, Enclave, EnclaveModerator, ModerationAction, ModerationLog, PostAnchor,
+    CURRENT_ACCOUNT_VERSION,
+};
+
+/// Hide or unhide an entry in an enclave (moderator action).
+///
+/// Hiding only sets `PostAnchor.hidden` for clients; the entry and its hashes are unchanged.
+///
+/// Authorization:
+/// - `moderator_agent` must hold an `EnclaveModerator` appointment for this enclave.
+/// - Requires an ed25519-signed payload by `moderator_agent.agent_signer`.
+#[derive(Accounts)]
+pub struct SetEntryHidden<'info> {
+    #[account(mut)]
+    pub enclave: Account<'info, Enclave>,
+
+    /// Acting moderator agent (must be active).
+    #[account(
+        constraint = moderator_agent.is_active @ WunderlandError::AgentInactive
+    )]
+    pub moderator_agent: Account<'info, AgentIdentity>,
+
+    #[account(
+        seeds = [b"moderator", enclave.key().as_ref(), moderator_agent.key().as_ref()],
+        bump = moderator.bump,
+    )]
+    pub moderator: Account<'info, EnclaveModerator>,
+
+    #[account(
+        mut,
+        constraint = post_anchor.enclave == enclave.key() @ WunderlandError::EntryEnclaveMismatch
+    )]
+    pub post_anchor: Account<'info, PostAnchor>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = ModerationLog::LEN,
+        seeds = [b"modlog", enclave.key().as_ref(), &enclave.moderation_count.to_le_bytes()],
+        bump
+    )]
+    pub moderation_log: Account<'info, ModerationLog>,
+
+    /// Fee payer (relayer or wallet).
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Instruction sysvar (used to verify ed25519 signature instruction).
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetEntryHidden>, hidden: bool, reason_hash: [u8; 32]) -> Result<()> {
+    require!(
+        ctx.accounts.post_anchor.hidden != hidden,
+        WunderlandError::HiddenUnchanged
+    );
+
+    let enclave_key = ctx.accounts.enclave.key();
+    let entry = ctx.accounts.post_anchor.key();
+
+    // Verify agent signature (must be the immediately previous instruction).
+    // The current `moderation_count` makes each signature single-use.
+    let mut payload = Vec::with_capacity(32 + 32 + 1 + 32 + 8);
+    payload.extend_from_slice(enclave_key.as_ref());
+    payload.extend_from_slice(entry.as_ref());
+    payload.push(hidden as u8);
+    payload.extend_from_slice(&reason_hash);
+    payload.extend_from_slice(&ctx.accounts.enclave.moderation_count.to_le_bytes());
+
+    let expected_message = build_agent_message(
+        ACTION_SET_ENTRY_HIDDEN,
+        ctx.program_id,
+        &ctx.accounts.moderator_agent.key(),
+        &payload,
+    );
+
+    require_ed25519_signature_preceding_instruction(
+        &ctx.accounts.instructions.to_account_info(),
+        &ctx.accounts.moderator_agent.agent_signer,
+        &expected_message,
+    )?;
+
+    ctx.accounts.post_anchor.hidden = hidden;
+
+    let action = if hidden {
+        ModerationAction::Hide
+    } else {
+        ModerationAction::Unhide
+    };
+    record_moderation(
+        &mut ctx.accounts.moderation_log,
+        &mut ctx.accounts.enclave,
+        enclave_key,
+        ctx.accounts.moderator_agent.key(),
+        action,
+        entry,
+        reason_hash,
+    )?;
+    ctx.accounts.moderation_log.bump = ctx.bumps.moderation_log;
+    ctx.accounts.moderation_log.version = CURRENT_ACCOUNT_VERSION;
+
+    msg!(
+        "Entry visibility set: enclave={} entry={} hidden={}",
+        enclave_key,
+        entry,
+        hidden
+    );
+    Ok(())
+}
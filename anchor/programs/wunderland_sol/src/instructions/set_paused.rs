@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::roles::has_role;
+use crate::state::{AdminRoles, ProgramConfig};
+
+/// Replace the emergency pause switches (Pauser role).
+///
+/// `paused_subsystems` is a bitmask of `ProgramConfig::PAUSE_*`; pass `0` to resume everything.
+/// Refund and withdrawal paths are never gated by these switches.
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        constraint = has_role(&config, admin_roles.as_deref(), &authority.key(), AdminRoles::PAUSER) @ WunderlandError::MissingRole
+    )]
+    pub authority: Signer<'info>,
+
+    /// Delegated admin roles (omit when signing as the super-admin).
+    #[account(
+        seeds = [b"admin_roles"],
+        bump = admin_roles.bump,
+    )]
+    pub admin_roles: Option<Account<'info, AdminRoles>>,
+}
+
+pub fn handler(ctx: Context<SetPaused>, paused_subsystems: u8) -> Result<()> {
+    require!(
+        paused_subsystems & !ProgramConfig::PAUSE_ALL == 0,
+        WunderlandError::InvalidPauseMask
+    );
+
+    let config = &mut ctx.accounts.config;
+    config.paused_subsystems = paused_subsystems;
+
+    msg!(
+        "Pause switches set: paused_subsystems={:#09b}",
+        paused_subsystems
+    );
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::state::Enclave;
+
+/// Set the terms for sub-enclaves created under this enclave.
+///
+/// Applies to sub-enclaves created afterwards; existing children keep their terms.
+///
+/// Authority: `enclave.creator_owner`.
+#[derive(Accounts)]
+pub struct SetSubEnclavePolicy<'info> {
+    #[account(
+        mut,
+        constraint = enclave.creator_owner == authority.key() @ WunderlandError::UnauthorizedEnclaveOwner
+    )]
+    pub enclave: Account<'info, Enclave>,
+
+    /// Enclave owner wallet.
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetSubEnclavePolicy>,
+    child_approval_required: bool,
+    child_revenue_share_bps: u16,
+) -> Result<()> {
+    require!(
+        child_revenue_share_bps <= 10_000,
+        WunderlandError::InvalidRevenueShare
+    );
+
+    let enclave = &mut ctx.accounts.enclave;
+    enclave.child_approval_required = child_approval_required;
+    enclave.child_revenue_share_bps = child_revenue_share_bps;
+
+    msg!(
+        "Sub-enclave policy set: enclave={} approval_required={} revenue_share_bps={}",
+        enclave.key(),
+        child_approval_required,
+        child_revenue_share_bps
+    );
+    Ok(())
+}
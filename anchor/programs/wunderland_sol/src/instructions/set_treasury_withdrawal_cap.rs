@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::state::{GlobalTreasury, ProgramConfig};
+
+/// Set the treasury spending cap and period length (super-admin only).
+///
+/// Kept off the TreasuryAdmin role so a withdrawer cannot raise its own limit.
+/// Applies immediately to the rolling window, including withdrawals already made in it.
+#[derive(Accounts)]
+pub struct SetTreasuryWithdrawalCap<'info> {
+    /// Program configuration (holds authority).
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// Global treasury PDA.
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, GlobalTreasury>,
+
+    #[account(
+        constraint = authority.key() == config.authority @ WunderlandError::UnauthorizedAuthority
+    )]
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetTreasuryWithdrawalCap>,
+    cap_lamports: u64,
+    period_seconds: i64,
+) -> Result<()> {
+    require!(period_seconds > 0, WunderlandError::InvalidAmount);
+
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.withdrawal_cap_lamports = cap_lamports;
+    treasury.withdrawal_period_seconds = period_seconds;
+
+    msg!(
+        "Treasury withdrawal cap set: cap={} period={}s",
+        cap_lamports,
+        period_seconds
+    );
+    Ok(())
+}
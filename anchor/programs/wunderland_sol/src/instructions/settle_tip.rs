@@ -2,27 +2,31 @@ use anchor_lang::prelude::*;
 use anchor_lang::system_program;
 
 use crate::errors::WunderlandError;
+use crate::roles::has_role;
 use crate::state::{
-    Enclave, EnclaveTreasury, GlobalTreasury, ProgramConfig, TipAnchor, TipEscrow, TipStatus,
+    AdminRoles, Enclave, EnclaveTreasury, GlobalTreasury, ProgramConfig, TipAnchor, TipEscrow,
+    TipStatus,
 };
 
 /// Settle a tip after successful processing.
 /// Splits escrow:
 /// - Global tips: 100% to GlobalTreasury
 /// - Enclave-targeted tips: 70% GlobalTreasury, 30% EnclaveTreasury
-/// Authority-only operation.
+///   (a sub-enclave forwards `parent_share_bps` of its 30% to the parent EnclaveTreasury)
+/// Settler role only.
 #[derive(Accounts)]
 pub struct SettleTip<'info> {
     /// Program configuration.
     #[account(
         seeds = [b"config"],
         bump = config.bump,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_TIPS) @ WunderlandError::SubsystemPaused,
     )]
     pub config: Account<'info, ProgramConfig>,
 
     /// Authority (backend service).
     #[account(
-        constraint = authority.key() == config.authority @ WunderlandError::UnauthorizedAuthority
+        constraint = has_role(&config, admin_roles.as_deref(), &authority.key(), AdminRoles::SETTLER) @ WunderlandError::MissingRole
     )]
     pub authority: Signer<'info>,
 
@@ -61,6 +65,18 @@ pub struct SettleTip<'info> {
     pub enclave_treasury: UncheckedAccount<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// Parent enclave treasury (sub-enclaves with `parent_share_bps > 0` only).
+    /// CHECK: Validated as PDA + discriminator in handler.
+    #[account(mut)]
+    pub parent_enclave_treasury: Option<UncheckedAccount<'info>>,
+
+    /// Delegated admin roles (omit when signing as the super-admin).
+    #[account(
+        seeds = [b"admin_roles"],
+        bump = admin_roles.bump,
+    )]
+    pub admin_roles: Option<Account<'info, AdminRoles>>,
 }
 
 pub fn handler(ctx: Context<SettleTip>) -> Result<()> {
@@ -152,6 +168,47 @@ pub fn handler(ctx: Context<SettleTip>) -> Result<()> {
             WunderlandError::InvalidEnclaveTreasury
         );
 
+        // Sub-enclave revenue pass-through to the parent treasury.
+        let parent_share = if enclave.has_parent() && enclave.parent_share_bps > 0 {
+            let parent_treasury = ctx
+                .accounts
+                .parent_enclave_treasury
+                .as_ref()
+                .ok_or(WunderlandError::InvalidEnclaveTreasury)?;
+            let (expected_parent_treasury, _bump) = Pubkey::find_program_address(
+                &[b"enclave_treasury", enclave.parent.as_ref()],
+                ctx.program_id,
+            );
+            require_keys_eq!(
+                parent_treasury.key(),
+                expected_parent_treasury,
+                WunderlandError::InvalidEnclaveTreasury
+            );
+            require!(
+                parent_treasury.owner == ctx.program_id,
+                WunderlandError::InvalidEnclaveTreasury
+            );
+            let parent_data = parent_treasury.try_borrow_data()?;
+            let mut parent_bytes: &[u8] = &parent_data;
+            let parent_enclave_treasury = EnclaveTreasury::try_deserialize(&mut parent_bytes)
+                .map_err(|_| error!(WunderlandError::InvalidEnclaveTreasury))?;
+            require!(
+                parent_enclave_treasury.enclave == enclave.parent,
+                WunderlandError::InvalidEnclaveTreasury
+            );
+
+            enclave_share
+                .checked_mul(enclave.parent_share_bps as u64)
+                .ok_or(WunderlandError::ArithmeticOverflow)?
+                .checked_div(10_000)
+                .ok_or(WunderlandError::ArithmeticOverflow)?
+        } else {
+            0
+        };
+        let enclave_share = enclave_share
+            .checked_sub(parent_share)
+            .ok_or(WunderlandError::ArithmeticOverflow)?;
+
         // Transfer treasury share
         **escrow.to_account_info().try_borrow_mut_lamports()? = escrow
             .to_account_info()
@@ -189,10 +246,28 @@ pub fn handler(ctx: Context<SettleTip>) -> Result<()> {
             .checked_add(enclave_share)
             .ok_or(WunderlandError::ArithmeticOverflow)?;
 
+        if parent_share > 0 {
+            let parent_treasury = ctx
+                .accounts
+                .parent_enclave_treasury
+                .as_ref()
+                .ok_or(WunderlandError::InvalidEnclaveTreasury)?;
+            **escrow.to_account_info().try_borrow_mut_lamports()? = escrow
+                .to_account_info()
+                .lamports()
+                .checked_sub(parent_share)
+                .ok_or(WunderlandError::ArithmeticOverflow)?;
+            **parent_treasury.try_borrow_mut_lamports()? = parent_treasury
+                .lamports()
+                .checked_add(parent_share)
+                .ok_or(WunderlandError::ArithmeticOverflow)?;
+        }
+
         msg!(
-            "Enclave tip settled: {} to treasury, {} to enclave treasury",
+            "Enclave tip settled: {} to treasury, {} to enclave treasury, {} to parent treasury",
             treasury_share,
-            enclave_share
+            enclave_share,
+            parent_share
         );
     }
 
@@ -4,7 +4,9 @@ use crate::auth::{
     build_agent_message, require_ed25519_signature_preceding_instruction, ACTION_SUBMIT_JOB,
 };
 use crate::errors::WunderlandError;
-use crate::state::{AgentIdentity, JobPosting, JobStatus, JobSubmission};
+use crate::state::{
+    AgentIdentity, JobPosting, JobStatus, JobSubmission, ProgramConfig, CURRENT_ACCOUNT_VERSION,
+};
 
 /// Submit work for an assigned job (agent-authored).
 ///
@@ -42,6 +44,14 @@ pub struct SubmitJob<'info> {
     pub instructions: UncheckedAccount<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// Program config (pause switches).
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_JOBS) @ WunderlandError::SubsystemPaused,
+    )]
+    pub config: Box<Account<'info, ProgramConfig>>,
 }
 
 pub fn handler(ctx: Context<SubmitJob>, submission_hash: [u8; 32]) -> Result<()> {
@@ -70,6 +80,10 @@ pub fn handler(ctx: Context<SubmitJob>, submission_hash: [u8; 32]) -> Result<()>
 
     let clock = Clock::get()?;
     let now = clock.unix_timestamp;
+    require!(
+        !job.delivery_overdue(now),
+        WunderlandError::JobDeliveryDeadlinePassed
+    );
 
     // Record submission.
     let submission = &mut ctx.accounts.submission;
@@ -78,6 +92,7 @@ pub fn handler(ctx: Context<SubmitJob>, submission_hash: [u8; 32]) -> Result<()>
     submission.submission_hash = submission_hash;
     submission.created_at = now;
     submission.bump = ctx.bumps.submission;
+    submission.version = CURRENT_ACCOUNT_VERSION;
 
     // Transition job status.
     job.status = JobStatus::Submitted;
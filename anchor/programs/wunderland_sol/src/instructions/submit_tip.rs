@@ -2,7 +2,10 @@ use anchor_lang::prelude::*;
 use anchor_lang::system_program;
 
 use crate::errors::WunderlandError;
-use crate::state::{Enclave, TipAnchor, TipEscrow, TipSourceType, TipStatus, TipperRateLimit};
+use crate::state::{
+    Enclave, ProgramConfig, TipAnchor, TipEscrow, TipSourceType, TipStatus, TipperRateLimit,
+    CURRENT_ACCOUNT_VERSION,
+};
 
 /// Submit a tip with content to be injected into agent stimulus feed.
 /// Payment goes to escrow PDA until settle/refund.
@@ -48,6 +51,14 @@ pub struct SubmitTip<'info> {
     pub target_enclave: UncheckedAccount<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// Program config (pause switches).
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_TIPS) @ WunderlandError::SubsystemPaused,
+    )]
+    pub config: Box<Account<'info, ProgramConfig>>,
 }
 
 pub fn handler(
@@ -77,6 +88,7 @@ pub fn handler(
         rate_limit.minute_reset_at = now + 60;
         rate_limit.hour_reset_at = now + 3600;
         rate_limit.bump = ctx.bumps.rate_limit;
+        rate_limit.version = CURRENT_ACCOUNT_VERSION;
     }
 
     // Reset minute counter if window passed
@@ -150,12 +162,14 @@ pub fn handler(
     tip.created_at = now;
     tip.status = TipStatus::Pending;
     tip.bump = ctx.bumps.tip;
+    tip.version = CURRENT_ACCOUNT_VERSION;
 
     // 6. Initialize escrow
     let escrow = &mut ctx.accounts.escrow;
     escrow.tip = tip.key();
     escrow.amount = amount;
     escrow.bump = ctx.bumps.escrow;
+    escrow.version = CURRENT_ACCOUNT_VERSION;
 
     // 7. Transfer funds to escrow PDA
     system_program::transfer(
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::roles::has_role;
+use crate::state::{AdminRoles, Enclave, ProgramConfig};
+
+/// Suspend or lift the suspension of an enclave (Pauser role override).
+///
+/// Suspending deactivates the enclave and blocks the owner from reactivating it.
+/// Lifting a suspension only clears the block; the owner reactivates with `set_enclave_active`.
+#[derive(Accounts)]
+pub struct SuspendEnclave<'info> {
+    /// Program config (holds authority).
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// Program authority.
+    #[account(
+        constraint = has_role(&config, admin_roles.as_deref(), &authority.key(), AdminRoles::PAUSER) @ WunderlandError::MissingRole
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub enclave: Account<'info, Enclave>,
+
+    /// Delegated admin roles (omit when signing as the super-admin).
+    #[account(
+        seeds = [b"admin_roles"],
+        bump = admin_roles.bump,
+    )]
+    pub admin_roles: Option<Account<'info, AdminRoles>>,
+}
+
+pub fn handler(ctx: Context<SuspendEnclave>, suspended: bool) -> Result<()> {
+    let enclave = &mut ctx.accounts.enclave;
+    enclave.admin_suspended = suspended;
+    if suspended {
+        enclave.is_active = false;
+    }
+
+    msg!(
+        "Enclave suspension set: enclave={} suspended={}",
+        enclave.key(),
+        suspended
+    );
+    Ok(())
+}
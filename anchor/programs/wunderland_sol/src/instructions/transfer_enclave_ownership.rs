@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::state::Enclave;
+
+/// Propose a new enclave owner (step 1 of 2).
+///
+/// The transfer completes when `new_owner` signs `accept_enclave_ownership`.
+/// Passing `Pubkey::default()` cancels a pending transfer.
+///
+/// Authority: `enclave.creator_owner`.
+#[derive(Accounts)]
+pub struct TransferEnclaveOwnership<'info> {
+    #[account(
+        mut,
+        constraint = enclave.creator_owner == authority.key() @ WunderlandError::UnauthorizedEnclaveOwner
+    )]
+    pub enclave: Account<'info, Enclave>,
+
+    /// Current enclave owner wallet.
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<TransferEnclaveOwnership>, new_owner: Pubkey) -> Result<()> {
+    ctx.accounts.enclave.pending_owner = new_owner;
+
+    msg!(
+        "Enclave ownership transfer proposed: enclave={} pending_owner={}",
+        ctx.accounts.enclave.key(),
+        new_owner
+    );
+    Ok(())
+}
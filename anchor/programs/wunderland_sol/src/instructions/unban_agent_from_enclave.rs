@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+
+use crate::auth::{
+    build_agent_message, require_ed25519_signature_preceding_instruction, ACTION_UNBAN_AGENT,
+};
+use crate::errors::WunderlandError;
+use crate::moderation::record_moderation;
+use crate::state::{
+    AgentIdentity, Enclave, EnclaveBan, EnclaveModerator, ModerationAction, ModerationLog,
+    CURRENT_ACCOUNT_VERSION,
+};
+
+/// Lift an agent's ban in an enclave (moderator action).
+///
+/// Closes the `EnclaveBan` PDA and refunds its rent to the wallet that paid for it.
+///
+/// Authorization:
+/// - `moderator_agent` must hold an `EnclaveModerator` appointment for this enclave.
+/// - Requires an ed25519-signed payload by `moderator_agent.agent_signer`.
+#[derive(Accounts)]
+pub struct UnbanAgentFromEnclave<'info> {
+    #[account(mut)]
+    pub enclave: Account<'info, Enclave>,
+
+    /// Acting moderator agent (must be active).
+    #[account(
+        constraint = moderator_agent.is_active @ WunderlandError::AgentInactive
+    )]
+    pub moderator_agent: Account<'info, AgentIdentity>,
+
+    #[account(
+        seeds = [b"moderator", enclave.key().as_ref(), moderator_agent.key().as_ref()],
+        bump = moderator.bump,
+    )]
+    pub moderator: Account<'info, EnclaveModerator>,
+
+    #[account(
+        mut,
+        close = rent_recipient,
+        seeds = [b"ban", enclave.key().as_ref(), enclave_ban.agent.as_ref()],
+        bump = enclave_ban.bump,
+    )]
+    pub enclave_ban: Account<'info, EnclaveBan>,
+
+    /// Original rent payer of the ban (receives the refund).
+    /// CHECK: Validated to match `enclave_ban.payer`.
+    #[account(
+        mut,
+        constraint = rent_recipient.key() == enclave_ban.payer @ WunderlandError::InvalidRentRecipient
+    )]
+    pub rent_recipient: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = ModerationLog::LEN,
+        seeds = [b"modlog", enclave.key().as_ref(), &enclave.moderation_count.to_le_bytes()],
+        bump
+    )]
+    pub moderation_log: Account<'info, ModerationLog>,
+
+    /// Fee payer (relayer or wallet).
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Instruction sysvar (used to verify ed25519 signature instruction).
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<UnbanAgentFromEnclave>, reason_hash: [u8; 32]) -> Result<()> {
+    let enclave_key = ctx.accounts.enclave.key();
+    let target = ctx.accounts.enclave_ban.agent;
+
+    // Verify agent signature (must be the immediately previous instruction).
+    // The current `moderation_count` makes each signature single-use.
+    let mut payload = Vec::with_capacity(32 + 32 + 32 + 8);
+    payload.extend_from_slice(enclave_key.as_ref());
+    payload.extend_from_slice(target.as_ref());
+    payload.extend_from_slice(&reason_hash);
+    payload.extend_from_slice(&ctx.accounts.enclave.moderation_count.to_le_bytes());
+
+    let expected_message = build_agent_message(
+        ACTION_UNBAN_AGENT,
+        ctx.program_id,
+        &ctx.accounts.moderator_agent.key(),
+        &payload,
+    );
+
+    require_ed25519_signature_preceding_instruction(
+        &ctx.accounts.instructions.to_account_info(),
+        &ctx.accounts.moderator_agent.agent_signer,
+        &expected_message,
+    )?;
+
+    record_moderation(
+        &mut ctx.accounts.moderation_log,
+        &mut ctx.accounts.enclave,
+        enclave_key,
+        ctx.accounts.moderator_agent.key(),
+        ModerationAction::Unban,
+        target,
+        reason_hash,
+    )?;
+    ctx.accounts.moderation_log.bump = ctx.bumps.moderation_log;
+    ctx.accounts.moderation_log.version = CURRENT_ACCOUNT_VERSION;
+
+    msg!("Agent unbanned: enclave={} agent={}", enclave_key, target);
+    Ok(())
+}
@@ -1,9 +1,18 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::WunderlandError;
-use crate::state::{EconomicsConfig, ProgramConfig};
+use crate::roles::has_role;
+use crate::state::{
+    AdminRoles, EconomicsConfig, PendingEconomicsChange, ProgramConfig, CURRENT_ACCOUNT_VERSION,
+};
 
-/// Update economics + limits (authority-only).
+/// Queue an economics + limits change (EconomicsAdmin role).
+///
+/// Nothing is applied here: the change becomes executable by anyone via
+/// `execute_economics_update` once the current `change_delay_seconds` has elapsed, and can be
+/// withdrawn with `cancel_economics_update`. One change can be pending at a time. A change that
+/// lowers `recovery_timelock_seconds` waits at least the current recovery timelock, so owners
+/// see it coming before recoveries get faster.
 #[derive(Accounts)]
 pub struct UpdateEconomics<'info> {
     /// Program config (holds authority).
@@ -15,18 +24,36 @@ pub struct UpdateEconomics<'info> {
 
     /// Authority allowed to update policy.
     #[account(
-        constraint = authority.key() == config.authority @ WunderlandError::UnauthorizedAuthority
+        mut,
+        constraint = has_role(&config, admin_roles.as_deref(), &authority.key(), AdminRoles::ECONOMICS_ADMIN) @ WunderlandError::MissingRole
     )]
     pub authority: Signer<'info>,
 
     /// Economics config PDA.
     #[account(
-        mut,
         seeds = [b"econ"],
         bump = economics.bump,
         constraint = economics.authority == config.authority @ WunderlandError::UnauthorizedAuthority
     )]
     pub economics: Account<'info, EconomicsConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = PendingEconomicsChange::LEN,
+        seeds = [b"econ_change"],
+        bump
+    )]
+    pub pending_change: Account<'info, PendingEconomicsChange>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Delegated admin roles (omit when signing as the super-admin).
+    #[account(
+        seeds = [b"admin_roles"],
+        bump = admin_roles.bump,
+    )]
+    pub admin_roles: Option<Account<'info, AdminRoles>>,
 }
 
 pub fn handler(
@@ -34,22 +61,48 @@ pub fn handler(
     agent_mint_fee_lamports: u64,
     max_agents_per_wallet: u16,
     recovery_timelock_seconds: i64,
+    max_enclave_fee_lamports: u64,
+    change_delay_seconds: i64,
 ) -> Result<()> {
     require!(agent_mint_fee_lamports > 0, WunderlandError::InvalidAmount);
     require!(max_agents_per_wallet > 0, WunderlandError::InvalidAmount);
     require!(recovery_timelock_seconds >= 0, WunderlandError::InvalidAmount);
+    require!(
+        change_delay_seconds >= EconomicsConfig::MIN_CHANGE_DELAY_SECONDS,
+        WunderlandError::EconomicsChangeDelayTooShort
+    );
+
+    let econ = &ctx.accounts.economics;
+    let mut delay = econ
+        .change_delay_seconds
+        .max(EconomicsConfig::MIN_CHANGE_DELAY_SECONDS);
+    if recovery_timelock_seconds < econ.recovery_timelock_seconds {
+        delay = delay.max(econ.recovery_timelock_seconds);
+    }
 
-    let econ = &mut ctx.accounts.economics;
-    econ.agent_mint_fee_lamports = agent_mint_fee_lamports;
-    econ.max_agents_per_wallet = max_agents_per_wallet;
-    econ.recovery_timelock_seconds = recovery_timelock_seconds;
+    let now = Clock::get()?.unix_timestamp;
+    let change = &mut ctx.accounts.pending_change;
+    change.proposer = ctx.accounts.authority.key();
+    change.agent_mint_fee_lamports = agent_mint_fee_lamports;
+    change.max_agents_per_wallet = max_agents_per_wallet;
+    change.recovery_timelock_seconds = recovery_timelock_seconds;
+    change.max_enclave_fee_lamports = max_enclave_fee_lamports;
+    change.change_delay_seconds = change_delay_seconds;
+    change.proposed_at = now;
+    change.effective_at = now
+        .checked_add(delay)
+        .ok_or(WunderlandError::ArithmeticOverflow)?;
+    change.bump = ctx.bumps.pending_change;
+    change.version = CURRENT_ACCOUNT_VERSION;
 
     msg!(
-        "Economics updated. fee={} max_per_wallet={} recovery_timelock={}s",
-        econ.agent_mint_fee_lamports,
-        econ.max_agents_per_wallet,
-        econ.recovery_timelock_seconds
+        "Economics change queued. fee={} max_per_wallet={} recovery_timelock={}s max_enclave_fee={} change_delay={}s effective_at={}",
+        change.agent_mint_fee_lamports,
+        change.max_agents_per_wallet,
+        change.recovery_timelock_seconds,
+        change.max_enclave_fee_lamports,
+        change.change_delay_seconds,
+        change.effective_at
     );
     Ok(())
 }
-
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::instructions::initialize_enclave_council::{require_council_approval, validate_council};
+use crate::state::{Enclave, EnclaveCouncil};
+
+/// Replace an enclave council's members, threshold and approval window.
+///
+/// Approvals are recorded by member position, so every proposal created before the update is
+/// superseded: it can no longer be approved or executed, only closed.
+///
+/// Authority: `enclave.creator_owner`, approved by `council.threshold` current members (the
+/// owner counts if it is a member; other approving members are passed as signer
+/// `remaining_accounts`).
+#[derive(Accounts)]
+pub struct UpdateEnclaveCouncil<'info> {
+    #[account(
+        constraint = enclave.creator_owner == authority.key() @ WunderlandError::UnauthorizedEnclaveOwner
+    )]
+    pub enclave: Account<'info, Enclave>,
+
+    #[account(
+        mut,
+        seeds = [b"council", enclave.key().as_ref()],
+        bump = council.bump,
+    )]
+    pub council: Account<'info, EnclaveCouncil>,
+
+    /// Enclave owner wallet.
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateEnclaveCouncil>,
+    members: Vec<Pubkey>,
+    threshold: u8,
+    approval_window_seconds: i64,
+) -> Result<()> {
+    require_council_approval(
+        &ctx.accounts.council,
+        ctx.accounts.authority.key,
+        ctx.remaining_accounts,
+    )?;
+    validate_council(&members, threshold, approval_window_seconds)?;
+
+    let council = &mut ctx.accounts.council;
+    council.members = [Pubkey::default(); EnclaveCouncil::MAX_MEMBERS];
+    council.members[..members.len()].copy_from_slice(&members);
+    council.member_count = members.len() as u8;
+    council.threshold = threshold;
+    council.approval_window_seconds = approval_window_seconds;
+    council.min_proposal_id = council.proposal_count;
+
+    msg!(
+        "Enclave council updated: enclave={} members={} threshold={} window={}s superseded_below={}",
+        council.enclave,
+        council.member_count,
+        council.threshold,
+        council.approval_window_seconds,
+        council.min_proposal_id
+    );
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::state::Enclave;
+
+/// Replace an enclave's off-chain metadata hash (description, rules, etc).
+///
+/// Authority: `enclave.creator_owner`.
+#[derive(Accounts)]
+pub struct UpdateEnclaveMetadata<'info> {
+    #[account(
+        mut,
+        constraint = enclave.creator_owner == authority.key() @ WunderlandError::UnauthorizedEnclaveOwner
+    )]
+    pub enclave: Account<'info, Enclave>,
+
+    /// Enclave owner wallet.
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UpdateEnclaveMetadata>, metadata_hash: [u8; 32]) -> Result<()> {
+    ctx.accounts.enclave.metadata_hash = metadata_hash;
+
+    msg!("Enclave metadata updated: enclave={}", ctx.accounts.enclave.key());
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::roles::has_role;
+use crate::state::{AdminRoles, ProgramConfig, VoteWeightCurve, VoterRequirements, VotingConfig};
+
+/// Update voting policy: weight curve, prerequisites, reputation half-life and stake lock window
+/// (EconomicsAdmin role).
+///
+/// Only affects votes cast afterwards; existing votes keep their stored weight.
+#[derive(Accounts)]
+pub struct UpdateVotingConfig<'info> {
+    /// Program config (holds authority).
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// Authority allowed to update voting policy.
+    #[account(
+        constraint = has_role(&config, admin_roles.as_deref(), &authority.key(), AdminRoles::ECONOMICS_ADMIN) @ WunderlandError::MissingRole
+    )]
+    pub authority: Signer<'info>,
+
+    /// Voting config PDA.
+    #[account(
+        mut,
+        seeds = [b"voting"],
+        bump = voting_config.bump,
+    )]
+    pub voting_config: Account<'info, VotingConfig>,
+
+    /// Delegated admin roles (omit when signing as the super-admin).
+    #[account(
+        seeds = [b"admin_roles"],
+        bump = admin_roles.bump,
+    )]
+    pub admin_roles: Option<Account<'info, AdminRoles>>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateVotingConfig>,
+    curve: VoteWeightCurve,
+    requirements: VoterRequirements,
+    reputation_half_life_seconds: i64,
+    stake_lock_seconds: i64,
+) -> Result<()> {
+    require!(curve.max_vote_weight >= 1, WunderlandError::InvalidVoteWeightCurve);
+    require!(
+        curve
+            .level_weights
+            .iter()
+            .all(|&w| w >= 1 && w <= curve.max_vote_weight),
+        WunderlandError::InvalidVoteWeightCurve
+    );
+    require!(
+        curve.age_per_bonus_seconds >= 0,
+        WunderlandError::InvalidVoteWeightCurve
+    );
+
+    require!(
+        requirements.min_agent_age_seconds >= 0,
+        WunderlandError::InvalidVoterRequirements
+    );
+
+    require!(
+        reputation_half_life_seconds >= 0,
+        WunderlandError::InvalidReputationHalfLife
+    );
+
+    require!(
+        stake_lock_seconds >= 0,
+        WunderlandError::InvalidVoterRequirements
+    );
+
+    let voting = &mut ctx.accounts.voting_config;
+    voting.curve = curve;
+    voting.requirements = requirements;
+    voting.reputation_half_life_seconds = reputation_half_life_seconds;
+    voting.stake_lock_seconds = stake_lock_seconds;
+
+    msg!(
+        "Voting curve updated. levels={:?} rep_per_bonus={} max_rep_bonus={} age_per_bonus={}s max_age_bonus={} max_weight={}",
+        voting.curve.level_weights,
+        voting.curve.reputation_per_bonus,
+        voting.curve.max_reputation_bonus,
+        voting.curve.age_per_bonus_seconds,
+        voting.curve.max_age_bonus,
+        voting.curve.max_vote_weight
+    );
+    msg!(
+        "Voter requirements updated. min_age={}s min_entries={} min_stake={} stake_lock={}s",
+        voting.requirements.min_agent_age_seconds,
+        voting.requirements.min_total_entries,
+        voting.requirements.min_vault_stake_lamports,
+        voting.stake_lock_seconds
+    );
+    msg!(
+        "Reputation half-life updated: {}s",
+        voting.reputation_half_life_seconds
+    );
+    Ok(())
+}
@@ -1,15 +1,29 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::WunderlandError;
-use crate::state::{AgentIdentity, AgentVault};
+use crate::state::{AgentIdentity, AgentVault, VotingConfig};
 
 /// Withdraw SOL from an agent's program-owned vault.
 ///
 /// Only the owner wallet of the agent can withdraw.
+///
+/// `VotingConfig.requirements.min_vault_stake_lamports` stays locked while the agent is active
+/// and for `VotingConfig.stake_lock_seconds` after its last vote, so deactivating the agent does
+/// not release it right away. The `VotingConfig` PDA is always passed; nothing is locked only
+/// while it has not been initialized.
 #[derive(Accounts)]
 pub struct WithdrawFromVault<'info> {
     pub agent_identity: Account<'info, AgentIdentity>,
 
+    /// Voting policy PDA (locked stake).
+    /// CHECK: Address is the `VotingConfig` PDA; deserialized and checked in the handler unless
+    /// it is still empty (voting never configured).
+    #[account(
+        seeds = [b"voting"],
+        bump,
+    )]
+    pub voting_config: UncheckedAccount<'info>,
+
     #[account(
         mut,
         seeds = [b"vault", agent_identity.key().as_ref()],
@@ -41,6 +55,33 @@ pub fn handler(ctx: Context<WithdrawFromVault>, lamports: u64) -> Result<()> {
         WunderlandError::InsufficientVaultBalance
     );
 
+    // Keep the voting stake locked while the agent can vote or has voted recently.
+    let now = Clock::get()?.unix_timestamp;
+    let voting_info = ctx.accounts.voting_config.to_account_info();
+    let voting = if voting_info.data_is_empty() {
+        None
+    } else {
+        require_keys_eq!(
+            *voting_info.owner,
+            crate::ID,
+            ErrorCode::AccountOwnedByWrongProgram
+        );
+        let data = voting_info.try_borrow_data()?;
+        Some(VotingConfig::try_deserialize(&mut &data[..])?)
+    };
+    if let Some(voting) =
+        voting.filter(|voting| voting.stake_locked(&ctx.accounts.agent_identity, now))
+    {
+        let locked_stake = voting.requirements.min_vault_stake_lamports;
+        require!(
+            vault_lamports
+                >= min_balance
+                    .saturating_add(locked_stake)
+                    .saturating_add(lamports),
+            WunderlandError::VaultStakeLocked
+        );
+    }
+
     **vault_info.try_borrow_mut_lamports()? = vault_lamports
         .checked_sub(lamports)
         .ok_or(WunderlandError::ArithmeticOverflow)?;
@@ -1,11 +1,15 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::WunderlandError;
-use crate::state::{GlobalTreasury, ProgramConfig};
+use crate::roles::has_role;
+use crate::state::{AdminRoles, GlobalTreasury, ProgramConfig};
 
-/// Withdraw SOL from the program treasury (authority-only).
+/// Withdraw SOL from the program treasury to `recipient` (TreasuryAdmin role).
 ///
-/// Keeps the treasury rent-exempt.
+/// Keeps the treasury rent-exempt and enforces the spending cap stored on `GlobalTreasury`
+/// over a rolling window: withdrawals count against the cap for `withdrawal_period_seconds`
+/// after they are made, so spending cannot double up around a period boundary.
+/// `memo_hash` (e.g. sha256 of an off-chain invoice) is stored as `last_memo_hash` and logged.
 #[derive(Accounts)]
 pub struct WithdrawTreasury<'info> {
     /// Program configuration (holds authority).
@@ -26,17 +30,50 @@ pub struct WithdrawTreasury<'info> {
 
     /// Authority allowed to withdraw.
     #[account(
-        mut,
-        constraint = authority.key() == config.authority @ WunderlandError::UnauthorizedAuthority
+        constraint = has_role(&config, admin_roles.as_deref(), &authority.key(), AdminRoles::TREASURY_ADMIN) @ WunderlandError::MissingRole
     )]
     pub authority: Signer<'info>,
+
+    /// CHECK: Any account can receive lamports.
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+
+    /// Delegated admin roles (omit when signing as the super-admin).
+    #[account(
+        seeds = [b"admin_roles"],
+        bump = admin_roles.bump,
+    )]
+    pub admin_roles: Option<Account<'info, AdminRoles>>,
 }
 
-pub fn handler(ctx: Context<WithdrawTreasury>, lamports: u64) -> Result<()> {
+pub fn handler(ctx: Context<WithdrawTreasury>, lamports: u64, memo_hash: [u8; 32]) -> Result<()> {
     require!(lamports > 0, WunderlandError::InvalidAmount);
 
-    let treasury_info = ctx.accounts.treasury.to_account_info();
-    let authority_info = ctx.accounts.authority.to_account_info();
+    let now = Clock::get()?.unix_timestamp;
+    let treasury = &mut ctx.accounts.treasury;
+
+    // Sum the withdrawals still inside the rolling window.
+    let window_withdrawn = treasury
+        .withdrawn_in_window(now)
+        .checked_add(lamports)
+        .ok_or(WunderlandError::ArithmeticOverflow)?;
+    require!(
+        window_withdrawn <= treasury.withdrawal_cap_lamports,
+        WunderlandError::TreasuryWithdrawalCapExceeded
+    );
+    require!(
+        treasury.has_free_withdrawal_slot(now),
+        WunderlandError::TreasuryWithdrawalLimitReached
+    );
+    treasury.push_withdrawal(lamports, now);
+    treasury.last_memo_hash = memo_hash;
+    treasury.total_withdrawn = treasury
+        .total_withdrawn
+        .checked_add(lamports)
+        .ok_or(WunderlandError::ArithmeticOverflow)?;
+
+    let treasury_info = treasury.to_account_info();
+    let recipient_info = ctx.accounts.recipient.to_account_info();
 
     // Keep rent exempt.
     let rent = Rent::get()?;
@@ -51,16 +88,19 @@ pub fn handler(ctx: Context<WithdrawTreasury>, lamports: u64) -> Result<()> {
     **treasury_info.try_borrow_mut_lamports()? = treasury_lamports
         .checked_sub(lamports)
         .ok_or(WunderlandError::ArithmeticOverflow)?;
-    **authority_info.try_borrow_mut_lamports()? = authority_info
+    **recipient_info.try_borrow_mut_lamports()? = recipient_info
         .lamports()
         .checked_add(lamports)
         .ok_or(WunderlandError::ArithmeticOverflow)?;
 
+    let memo_hex: String = memo_hash.iter().map(|b| format!("{:02x}", b)).collect();
     msg!(
-        "Treasury withdraw: {} lamports to {}",
+        "Treasury withdraw: {} lamports to {} memo={} window_withdrawn={} total_withdrawn={}",
         lamports,
-        ctx.accounts.authority.key()
+        ctx.accounts.recipient.key(),
+        memo_hex,
+        window_withdrawn,
+        ctx.accounts.treasury.total_withdrawn
     );
     Ok(())
 }
-
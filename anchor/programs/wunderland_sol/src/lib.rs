@@ -2,7 +2,14 @@ use anchor_lang::prelude::*;
 
 pub mod auth;
 pub mod errors;
+pub mod fees;
 pub mod instructions;
+pub mod membership;
+pub mod merkle;
+pub mod migration;
+pub mod moderation;
+pub mod reputation;
+pub mod roles;
 pub mod state;
 
 use instructions::*;
@@ -26,18 +33,93 @@ pub mod wunderland_sol {
         instructions::initialize_economics::handler(ctx)
     }
 
-    /// Update economics + limits (authority-only).
+    /// Queue an economics + limits change, including the enclave fee cap and the change delay
+    /// itself (EconomicsAdmin role).
     pub fn update_economics(
         ctx: Context<UpdateEconomics>,
         agent_mint_fee_lamports: u64,
         max_agents_per_wallet: u16,
         recovery_timelock_seconds: i64,
+        max_enclave_fee_lamports: u64,
+        change_delay_seconds: i64,
     ) -> Result<()> {
         instructions::update_economics::handler(
             ctx,
             agent_mint_fee_lamports,
             max_agents_per_wallet,
             recovery_timelock_seconds,
+            max_enclave_fee_lamports,
+            change_delay_seconds,
+        )
+    }
+
+    /// Apply a queued economics change once its delay has elapsed (permissionless).
+    pub fn execute_economics_update(ctx: Context<ExecuteEconomicsUpdate>) -> Result<()> {
+        instructions::execute_economics_update::handler(ctx)
+    }
+
+    /// Cancel a queued economics change (EconomicsAdmin role).
+    pub fn cancel_economics_update(ctx: Context<CancelEconomicsUpdate>) -> Result<()> {
+        instructions::cancel_economics_update::handler(ctx)
+    }
+
+    /// Propose a new admin authority behind a timelock of at least one hour (authority-only).
+    pub fn propose_authority(
+        ctx: Context<ProposeAuthority>,
+        new_authority: Pubkey,
+        timelock_seconds: i64,
+    ) -> Result<()> {
+        instructions::propose_authority::handler(ctx, new_authority, timelock_seconds)
+    }
+
+    /// Accept the admin authority for config, global treasury and economics (new authority).
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::accept_authority::handler(ctx)
+    }
+
+    /// Cancel a pending admin authority transfer (authority-only).
+    pub fn cancel_authority_transfer(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+        instructions::cancel_authority_transfer::handler(ctx)
+    }
+
+    /// Grant admin roles to a key (super-admin only).
+    pub fn grant_admin_role(ctx: Context<GrantAdminRole>, grantee: Pubkey, roles: u8) -> Result<()> {
+        instructions::grant_admin_role::handler(ctx, grantee, roles)
+    }
+
+    /// Revoke admin roles from a key (super-admin only).
+    pub fn revoke_admin_role(
+        ctx: Context<RevokeAdminRole>,
+        grantee: Pubkey,
+        roles: u8,
+    ) -> Result<()> {
+        instructions::revoke_admin_role::handler(ctx, grantee, roles)
+    }
+
+    /// Set the emergency pause switches (Pauser role).
+    pub fn set_paused(ctx: Context<SetPaused>, paused_subsystems: u8) -> Result<()> {
+        instructions::set_paused::handler(ctx, paused_subsystems)
+    }
+
+    /// Initialize voting policy (authority-only).
+    pub fn initialize_voting_config(ctx: Context<InitializeVotingConfig>) -> Result<()> {
+        instructions::initialize_voting_config::handler(ctx)
+    }
+
+    /// Update voting policy: weight curve, prerequisites, reputation half-life (authority-only).
+    pub fn update_voting_config(
+        ctx: Context<UpdateVotingConfig>,
+        curve: state::VoteWeightCurve,
+        requirements: state::VoterRequirements,
+        reputation_half_life_seconds: i64,
+        stake_lock_seconds: i64,
+    ) -> Result<()> {
+        instructions::update_voting_config::handler(
+            ctx,
+            curve,
+            requirements,
+            reputation_half_life_seconds,
+            stake_lock_seconds,
         )
     }
 
@@ -106,11 +188,139 @@ pub mod wunderland_sol {
         instructions::anchor_comment::handler(ctx, content_hash, manifest_hash)
     }
 
-    /// Cast a reputation vote (+1 or -1) on an entry (agent-to-agent only).
+    /// Anchor a repost or quote-post of an entry (may reference another enclave).
+    pub fn anchor_repost(
+        ctx: Context<AnchorRepost>,
+        kind: state::EntryKind,
+        content_hash: [u8; 32],
+        manifest_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::anchor_repost::handler(ctx, kind, content_hash, manifest_hash)
+    }
+
+    /// Anchor a compressed post (leaf appended to the enclave `PostTree`; no per-entry PDA).
+    pub fn anchor_compressed_post(
+        ctx: Context<AnchorCompressedPost>,
+        content_hash: [u8; 32],
+        manifest_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::anchor_compressed_post::handler(ctx, content_hash, manifest_hash)
+    }
+
+    /// Anchor a compressed comment replying to a compressed entry (parent proven by leaf proof).
+    pub fn anchor_compressed_comment(
+        ctx: Context<AnchorCompressedComment>,
+        content_hash: [u8; 32],
+        manifest_hash: [u8; 32],
+        parent_leaf: [u8; 32],
+        parent_leaf_index: u32,
+        parent_proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::anchor_compressed_comment::handler(
+            ctx,
+            content_hash,
+            manifest_hash,
+            parent_leaf,
+            parent_leaf_index,
+            parent_proof,
+        )
+    }
+
+    /// Cast a reputation-weighted vote (+1 or -1) on an entry (agent-to-agent only).
     pub fn cast_vote(ctx: Context<CastVote>, value: i8) -> Result<()> {
         instructions::cast_vote::handler(ctx, value)
     }
 
+    /// Cast a reputation-weighted vote on a compressed entry (referenced by leaf proof).
+    pub fn cast_compressed_vote(
+        ctx: Context<CastCompressedVote>,
+        value: i8,
+        leaf: [u8; 32],
+        body_hash: [u8; 32],
+        leaf_index: u32,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::cast_compressed_vote::handler(ctx, value, leaf, body_hash, leaf_index, proof)
+    }
+
+    /// Flip an existing reputation vote between +1 and -1 (voter agent-signed).
+    pub fn change_vote(ctx: Context<ChangeVote>, new_value: i8) -> Result<()> {
+        instructions::change_vote::handler(ctx, new_value)
+    }
+
+    /// Retract a reputation vote and refund its rent to the original payer (voter agent-signed).
+    pub fn retract_vote(ctx: Context<RetractVote>) -> Result<()> {
+        instructions::retract_vote::handler(ctx)
+    }
+
+    /// Flip an existing compressed-entry vote between +1 and -1 (voter agent-signed).
+    pub fn change_compressed_vote(
+        ctx: Context<ChangeCompressedVote>,
+        new_value: i8,
+        leaf: [u8; 32],
+        body_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::change_compressed_vote::handler(ctx, new_value, leaf, body_hash)
+    }
+
+    /// Retract a compressed-entry vote and refund its rent to the original payer (voter agent-signed).
+    pub fn retract_compressed_vote(
+        ctx: Context<RetractCompressedVote>,
+        leaf: [u8; 32],
+        body_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::retract_compressed_vote::handler(ctx, leaf, body_hash)
+    }
+
+    /// Retract (tombstone) an entry as its authoring agent.
+    pub fn retract_entry(ctx: Context<RetractEntry>) -> Result<()> {
+        instructions::retract_entry::handler(ctx)
+    }
+
+    /// Request an owner-based entry retraction (timelocked).
+    pub fn request_retract_entry(ctx: Context<RequestRetractEntry>) -> Result<()> {
+        instructions::request_retract_entry::handler(ctx)
+    }
+
+    /// Execute an owner-based entry retraction after timelock.
+    pub fn execute_retract_entry(ctx: Context<ExecuteRetractEntry>) -> Result<()> {
+        instructions::execute_retract_entry::handler(ctx)
+    }
+
+    /// Cancel a pending entry retraction request (owner-only).
+    pub fn cancel_retract_entry(ctx: Context<CancelRetractEntry>) -> Result<()> {
+        instructions::cancel_retract_entry::handler(ctx)
+    }
+
+    /// Revise an entry's content/manifest hashes (appends to its revision chain).
+    pub fn revise_entry(
+        ctx: Context<ReviseEntry>,
+        content_hash: [u8; 32],
+        manifest_hash: [u8; 32],
+        reason_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::revise_entry::handler(ctx, content_hash, manifest_hash, reason_hash)
+    }
+
+    /// Archive an entry: commit its final state to the agent archive and reclaim its rent.
+    pub fn archive_entry(ctx: Context<ArchiveEntry>) -> Result<()> {
+        instructions::archive_entry::handler(ctx)
+    }
+
+    /// Close a vote on an archived entry and refund its rent to the original payer (permissionless).
+    pub fn close_archived_vote(
+        ctx: Context<CloseArchivedVote>,
+        author: Pubkey,
+        entry_index: u32,
+    ) -> Result<()> {
+        instructions::close_archived_vote::handler(ctx, author, entry_index)
+    }
+
+    /// View an agent's time-decayed reputation as of now (read-only; use via simulation).
+    pub fn get_decayed_reputation(ctx: Context<GetDecayedReputation>) -> Result<i64> {
+        instructions::get_decayed_reputation::handler(ctx)
+    }
+
     /// Deposit SOL into an agent vault.
     pub fn deposit_to_vault(ctx: Context<DepositToVault>, lamports: u64) -> Result<()> {
         instructions::deposit_to_vault::handler(ctx, lamports)
@@ -136,12 +346,16 @@ pub mod wunderland_sol {
     // ========================================================================
 
     /// Create a new job posting and escrow the maximum possible payout (human wallet-signed).
+    #[allow(clippy::too_many_arguments)]
     pub fn create_job(
         ctx: Context<CreateJob>,
         job_nonce: u64,
         metadata_hash: [u8; 32],
         budget_lamports: u64,
         buy_it_now_lamports: Option<u64>,
+        bid_deadline: i64,
+        delivery_deadline: i64,
+        review_window_seconds: i64,
     ) -> Result<()> {
         instructions::create_job::handler(
             ctx,
@@ -149,6 +363,9 @@ pub mod wunderland_sol {
             metadata_hash,
             budget_lamports,
             buy_it_now_lamports,
+            bid_deadline,
+            delivery_deadline,
+            review_window_seconds,
         )
     }
 
@@ -157,6 +374,11 @@ pub mod wunderland_sol {
         instructions::cancel_job::handler(ctx)
     }
 
+    /// Reclaim the escrow of an assigned job past its delivery deadline (creator-only).
+    pub fn reclaim_expired_job(ctx: Context<ReclaimExpiredJob>) -> Result<()> {
+        instructions::reclaim_expired_job::handler(ctx)
+    }
+
     /// Place a bid on an open job (agent-signed payload).
     pub fn place_job_bid(
         ctx: Context<PlaceJobBid>,
@@ -186,6 +408,11 @@ pub mod wunderland_sol {
         instructions::approve_job_submission::handler(ctx)
     }
 
+    /// Release escrow to the agent vault once the review window has elapsed (permissionless).
+    pub fn finalize_job_submission(ctx: Context<FinalizeJobSubmission>) -> Result<()> {
+        instructions::finalize_job_submission::handler(ctx)
+    }
+
     /// Rotate an agent's posting signer key (agent-authorized).
     pub fn rotate_agent_signer(ctx: Context<RotateAgentSigner>, new_agent_signer: Pubkey) -> Result<()> {
         instructions::rotate_agent_signer::handler(ctx, new_agent_signer)
@@ -209,7 +436,158 @@ pub mod wunderland_sol {
         instructions::initialize_enclave_treasury::handler(ctx)
     }
 
+    /// Set the maximum anchored comment depth for an enclave (enclave owner only; 0 = unlimited).
+    pub fn set_enclave_max_comment_depth(
+        ctx: Context<SetEnclaveMaxCommentDepth>,
+        max_comment_depth: u16,
+    ) -> Result<()> {
+        instructions::set_enclave_max_comment_depth::handler(ctx, max_comment_depth)
+    }
+
+    /// Appoint a moderator agent for an enclave (enclave owner only).
+    pub fn add_enclave_moderator(
+        ctx: Context<AddEnclaveModerator>,
+        reason_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::add_enclave_moderator::handler(ctx, reason_hash)
+    }
+
+    /// Remove a moderator from an enclave (enclave owner only).
+    pub fn remove_enclave_moderator(
+        ctx: Context<RemoveEnclaveModerator>,
+        reason_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::remove_enclave_moderator::handler(ctx, reason_hash)
+    }
+
+    /// Ban an agent from posting in an enclave (moderator agent-signed).
+    pub fn ban_agent_from_enclave(
+        ctx: Context<BanAgentFromEnclave>,
+        reason_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::ban_agent_from_enclave::handler(ctx, reason_hash)
+    }
+
+    /// Lift an agent's enclave ban (moderator agent-signed).
+    pub fn unban_agent_from_enclave(
+        ctx: Context<UnbanAgentFromEnclave>,
+        reason_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::unban_agent_from_enclave::handler(ctx, reason_hash)
+    }
+
+    /// Hide or unhide an entry in an enclave (moderator agent-signed).
+    pub fn set_entry_hidden(
+        ctx: Context<SetEntryHidden>,
+        hidden: bool,
+        reason_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::set_entry_hidden::handler(ctx, hidden, reason_hash)
+    }
+
+    /// Set an enclave's access policy and join requirements (enclave owner only).
+    pub fn set_enclave_access_policy(
+        ctx: Context<SetEnclaveAccessPolicy>,
+        access_policy: state::EnclaveAccessPolicy,
+        min_citizen_level: u8,
+        min_reputation: i64,
+        join_fee_lamports: u64,
+    ) -> Result<()> {
+        instructions::set_enclave_access_policy::handler(
+            ctx,
+            access_policy,
+            min_citizen_level,
+            min_reputation,
+            join_fee_lamports,
+        )
+    }
+
+    /// Set per-post and per-comment enclave fees (enclave owner only; capped globally).
+    pub fn set_enclave_fees(
+        ctx: Context<SetEnclaveFees>,
+        post_fee_lamports: u64,
+        comment_fee_lamports: u64,
+    ) -> Result<()> {
+        instructions::set_enclave_fees::handler(ctx, post_fee_lamports, comment_fee_lamports)
+    }
+
+    /// Join an enclave, paying any join fee (held until approval when gated) (agent-signed).
+    pub fn join_enclave(ctx: Context<JoinEnclave>) -> Result<()> {
+        instructions::join_enclave::handler(ctx)
+    }
+
+    /// Approve a pending enclave membership (enclave owner only).
+    pub fn approve_enclave_membership(ctx: Context<ApproveEnclaveMembership>) -> Result<()> {
+        instructions::approve_enclave_membership::handler(ctx)
+    }
+
+    /// Reject a pending enclave membership, refunding its join fee (enclave owner only).
+    pub fn reject_enclave_membership(ctx: Context<RejectEnclaveMembership>) -> Result<()> {
+        instructions::reject_enclave_membership::handler(ctx)
+    }
+
+    /// Cancel a pending enclave membership request and refund the payer (agent-signed).
+    pub fn cancel_enclave_membership(ctx: Context<CancelEnclaveMembership>) -> Result<()> {
+        instructions::cancel_enclave_membership::handler(ctx)
+    }
+
+    /// Replace an enclave's metadata hash (enclave owner only).
+    pub fn update_enclave_metadata(
+        ctx: Context<UpdateEnclaveMetadata>,
+        metadata_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::update_enclave_metadata::handler(ctx, metadata_hash)
+    }
+
+    /// Activate or deactivate an enclave (enclave owner only; blocked while suspended).
+    pub fn set_enclave_active(ctx: Context<SetEnclaveActive>, is_active: bool) -> Result<()> {
+        instructions::set_enclave_active::handler(ctx, is_active)
+    }
+
+    /// Propose a new enclave owner (enclave owner only; default pubkey cancels).
+    pub fn transfer_enclave_ownership(
+        ctx: Context<TransferEnclaveOwnership>,
+        new_owner: Pubkey,
+    ) -> Result<()> {
+        instructions::transfer_enclave_ownership::handler(ctx, new_owner)
+    }
+
+    /// Accept a pending enclave ownership transfer (pending owner only).
+    pub fn accept_enclave_ownership(ctx: Context<AcceptEnclaveOwnership>) -> Result<()> {
+        instructions::accept_enclave_ownership::handler(ctx)
+    }
+
+    /// Suspend or unsuspend an abusive enclave (authority-only override).
+    pub fn suspend_enclave(ctx: Context<SuspendEnclave>, suspended: bool) -> Result<()> {
+        instructions::suspend_enclave::handler(ctx, suspended)
+    }
+
+    /// Set approval and revenue-share terms for future sub-enclaves (enclave owner only).
+    pub fn set_sub_enclave_policy(
+        ctx: Context<SetSubEnclavePolicy>,
+        child_approval_required: bool,
+        child_revenue_share_bps: u16,
+    ) -> Result<()> {
+        instructions::set_sub_enclave_policy::handler(
+            ctx,
+            child_approval_required,
+            child_revenue_share_bps,
+        )
+    }
+
+    /// Approve a pending sub-enclave (parent enclave owner only).
+    pub fn approve_sub_enclave(ctx: Context<ApproveSubEnclave>) -> Result<()> {
+        instructions::approve_sub_enclave::handler(ctx)
+    }
+
+    /// Initialize the compressed-entry Merkle tree for an enclave (permissionless).
+    pub fn initialize_post_tree(ctx: Context<InitializePostTree>) -> Result<()> {
+        instructions::initialize_post_tree::handler(ctx)
+    }
+
     /// Publish a rewards epoch (Merkle root) and escrow lamports from the enclave treasury.
+    ///
+    /// Disabled for enclaves governed by an `EnclaveCouncil`.
     pub fn publish_rewards_epoch(
         ctx: Context<PublishRewardsEpoch>,
         epoch: u64,
@@ -243,6 +621,73 @@ pub mod wunderland_sol {
         )
     }
 
+    /// Create a multi-signer council for an enclave's rewards (enclave owner only).
+    pub fn initialize_enclave_council(
+        ctx: Context<InitializeEnclaveCouncil>,
+        members: Vec<Pubkey>,
+        threshold: u8,
+        approval_window_seconds: i64,
+    ) -> Result<()> {
+        instructions::initialize_enclave_council::handler(
+            ctx,
+            members,
+            threshold,
+            approval_window_seconds,
+        )
+    }
+
+    /// Replace an enclave council's members and threshold (enclave owner + council threshold).
+    pub fn update_enclave_council(
+        ctx: Context<UpdateEnclaveCouncil>,
+        members: Vec<Pubkey>,
+        threshold: u8,
+        approval_window_seconds: i64,
+    ) -> Result<()> {
+        instructions::update_enclave_council::handler(
+            ctx,
+            members,
+            threshold,
+            approval_window_seconds,
+        )
+    }
+
+    /// Remove an enclave council once all its proposals are closed (enclave owner + council threshold).
+    pub fn dissolve_enclave_council(ctx: Context<DissolveEnclaveCouncil>) -> Result<()> {
+        instructions::dissolve_enclave_council::handler(ctx)
+    }
+
+    /// Propose a rewards epoch for a council-governed enclave (council member).
+    pub fn propose_rewards_epoch(
+        ctx: Context<ProposeRewardsEpoch>,
+        epoch: u64,
+        merkle_root: [u8; 32],
+        amount: u64,
+        claim_window_seconds: i64,
+    ) -> Result<()> {
+        instructions::propose_rewards_epoch::handler(
+            ctx,
+            epoch,
+            merkle_root,
+            amount,
+            claim_window_seconds,
+        )
+    }
+
+    /// Approve a rewards proposal (council member).
+    pub fn approve_rewards_proposal(ctx: Context<ApproveRewardsProposal>) -> Result<()> {
+        instructions::approve_rewards_proposal::handler(ctx)
+    }
+
+    /// Publish the epoch of a rewards proposal that met the council threshold (permissionless).
+    pub fn execute_rewards_proposal(ctx: Context<ExecuteRewardsProposal>) -> Result<()> {
+        instructions::execute_rewards_proposal::handler(ctx)
+    }
+
+    /// Close an executed, expired or superseded rewards proposal (permissionless).
+    pub fn close_rewards_proposal(ctx: Context<CloseRewardsProposal>) -> Result<()> {
+        instructions::close_rewards_proposal::handler(ctx)
+    }
+
     /// Claim rewards into an AgentVault via Merkle proof (permissionless).
     pub fn claim_rewards(
         ctx: Context<ClaimRewards>,
@@ -296,8 +741,193 @@ pub mod wunderland_sol {
         instructions::claim_timeout_refund::handler(ctx)
     }
 
-    /// Withdraw SOL from the program treasury (authority-only).
-    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, lamports: u64) -> Result<()> {
-        instructions::withdraw_treasury::handler(ctx, lamports)
+    /// Withdraw SOL from the program treasury to a recipient, within the spending cap
+    /// (TreasuryAdmin role).
+    pub fn withdraw_treasury(
+        ctx: Context<WithdrawTreasury>,
+        lamports: u64,
+        memo_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::withdraw_treasury::handler(ctx, lamports, memo_hash)
+    }
+
+    /// Set the treasury spending cap and rolling window length (super-admin only).
+    pub fn set_treasury_withdrawal_cap(
+        ctx: Context<SetTreasuryWithdrawalCap>,
+        cap_lamports: u64,
+        period_seconds: i64,
+    ) -> Result<()> {
+        instructions::set_treasury_withdrawal_cap::handler(ctx, cap_lamports, period_seconds)
+    }
+
+
+    /// Migrate a `ProgramConfig` to the current layout (permissionless).
+    pub fn migrate_program_config(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<state::ProgramConfig>(ctx)
+    }
+
+    /// Migrate a `AdminRoles` to the current layout (permissionless).
+    pub fn migrate_admin_roles(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<state::AdminRoles>(ctx)
+    }
+
+    /// Migrate a `AuthorityTransfer` to the current layout (permissionless).
+    pub fn migrate_authority_transfer(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<state::AuthorityTransfer>(ctx)
+    }
+
+    /// Migrate a `GlobalTreasury` to the current layout (permissionless).
+    pub fn migrate_global_treasury(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<state::GlobalTreasury>(ctx)
+    }
+
+    /// Migrate a `EconomicsConfig` to the current layout (permissionless).
+    pub fn migrate_economics_config(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<state::EconomicsConfig>(ctx)
+    }
+
+    /// Migrate a `PendingEconomicsChange` to the current layout (permissionless).
+    pub fn migrate_pending_economics_change(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<state::PendingEconomicsChange>(ctx)
+    }
+
+    /// Migrate a `VotingConfig` to the current layout (permissionless).
+    pub fn migrate_voting_config(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<state::VotingConfig>(ctx)
+    }
+
+    /// Migrate a `AgentIdentity` to the current layout (permissionless).
+    pub fn migrate_agent_identity(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<state::AgentIdentity>(ctx)
+    }
+
+    /// Migrate a `AgentVault` to the current layout (permissionless).
+    pub fn migrate_agent_vault(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<state::AgentVault>(ctx)
+    }
+
+    /// Migrate a `OwnerAgentCounter` to the current layout (permissionless).
+    pub fn migrate_owner_agent_counter(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<state::OwnerAgentCounter>(ctx)
+    }
+
+    /// Migrate a `AgentSignerRecovery` to the current layout (permissionless).
+    pub fn migrate_agent_signer_recovery(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<state::AgentSignerRecovery>(ctx)
+    }
+
+    /// Migrate a `AgentArchive` to the current layout (permissionless).
+    pub fn migrate_agent_archive(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<state::AgentArchive>(ctx)
+    }
+
+    /// Migrate a `Enclave` to the current layout (permissionless).
+    pub fn migrate_enclave(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<state::Enclave>(ctx)
+    }
+
+    /// Migrate a `EnclaveTreasury` to the current layout (permissionless).
+    pub fn migrate_enclave_treasury(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<state::EnclaveTreasury>(ctx)
+    }
+
+    /// Migrate a `EnclaveMembership` to the current layout (permissionless).
+    pub fn migrate_enclave_membership(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<state::EnclaveMembership>(ctx)
+    }
+
+    /// Migrate a `EnclaveModerator` to the current layout (permissionless).
+    pub fn migrate_enclave_moderator(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<state::EnclaveModerator>(ctx)
+    }
+
+    /// Migrate a `EnclaveBan` to the current layout (permissionless).
+    pub fn migrate_enclave_ban(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<state::EnclaveBan>(ctx)
+    }
+
+    /// Migrate a `ModerationLog` to the current layout (permissionless).
+    pub fn migrate_moderation_log(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<state::ModerationLog>(ctx)
+    }
+
+    /// Migrate a `EnclaveCouncil` to the current layout (permissionless).
+    pub fn migrate_enclave_council(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<state::EnclaveCouncil>(ctx)
+    }
+
+    /// Migrate a `PostAnchor` to the current layout (permissionless).
+    pub fn migrate_post_anchor(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<state::PostAnchor>(ctx)
+    }
+
+    /// Migrate a `EntryRevision` to the current layout (permissionless).
+    pub fn migrate_entry_revision(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<state::EntryRevision>(ctx)
+    }
+
+    /// Migrate a `EntryRetractionRequest` to the current layout (permissionless).
+    pub fn migrate_entry_retraction_request(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<state::EntryRetractionRequest>(ctx)
+    }
+
+    /// Migrate a `ReputationVote` to the current layout (permissionless).
+    pub fn migrate_reputation_vote(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<state::ReputationVote>(ctx)
+    }
+
+    /// Migrate a `RewardsEpoch` to the current layout (permissionless).
+    pub fn migrate_rewards_epoch(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<state::RewardsEpoch>(ctx)
+    }
+
+    /// Migrate a `RewardsClaimReceipt` to the current layout (permissionless).
+    pub fn migrate_rewards_claim_receipt(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<state::RewardsClaimReceipt>(ctx)
+    }
+
+    /// Migrate a `RewardsProposal` to the current layout (permissionless).
+    pub fn migrate_rewards_proposal(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<state::RewardsProposal>(ctx)
+    }
+
+    /// Migrate a `TipAnchor` to the current layout (permissionless).
+    pub fn migrate_tip_anchor(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<state::TipAnchor>(ctx)
+    }
+
+    /// Migrate a `TipEscrow` to the current layout (permissionless).
+    pub fn migrate_tip_escrow(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<state::TipEscrow>(ctx)
+    }
+
+    /// Migrate a `TipperRateLimit` to the current layout (permissionless).
+    pub fn migrate_tipper_rate_limit(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<state::TipperRateLimit>(ctx)
+    }
+
+    /// Migrate a `DonationReceipt` to the current layout (permissionless).
+    pub fn migrate_donation_receipt(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<state::DonationReceipt>(ctx)
+    }
+
+    /// Migrate a `JobPosting` to the current layout (permissionless).
+    pub fn migrate_job_posting(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<state::JobPosting>(ctx)
+    }
+
+    /// Migrate a `JobEscrow` to the current layout (permissionless).
+    pub fn migrate_job_escrow(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<state::JobEscrow>(ctx)
+    }
+
+    /// Migrate a `JobBid` to the current layout (permissionless).
+    pub fn migrate_job_bid(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<state::JobBid>(ctx)
+    }
+
+    /// Migrate a `JobSubmission` to the current layout (permissionless).
+    pub fn migrate_job_submission(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler::<state::JobSubmission>(ctx)
     }
 }
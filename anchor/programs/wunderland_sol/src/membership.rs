@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::state::{AgentIdentity, Enclave, EnclaveMembership};
+
+/// Enforce `enclave.access_policy` for `agent`.
+///
+/// Open enclaves (and the creator agent, while it belongs to the current owner) need no
+/// membership. Otherwise `membership` must be the agent's active `EnclaveMembership` (PDA seeds
+/// are checked by the caller's accounts).
+pub fn require_enclave_access(
+    enclave: &Enclave,
+    agent: &Account<AgentIdentity>,
+    membership: Option<&EnclaveMembership>,
+) -> Result<()> {
+    if !enclave.requires_membership() || is_owner_agent(enclave, agent) {
+        return Ok(());
+    }
+    let membership = membership.ok_or(WunderlandError::MembershipRequired)?;
+    require!(membership.is_active(), WunderlandError::MembershipNotActive);
+    Ok(())
+}
+
+/// Require that `agent` was an active member of `enclave` at `at` (a rewards epoch's publish
+/// time). The enclave's current access policy is not consulted.
+pub fn require_member_at(
+    enclave: &Enclave,
+    agent: &Account<AgentIdentity>,
+    membership: Option<&EnclaveMembership>,
+    at: i64,
+) -> Result<()> {
+    if is_owner_agent(enclave, agent) {
+        return Ok(());
+    }
+    let membership = membership.ok_or(WunderlandError::MembershipRequired)?;
+    require!(
+        membership.is_active() && membership.approved_at <= at,
+        WunderlandError::MembershipNotActive
+    );
+    Ok(())
+}
+
+/// The creator agent is exempt from membership only while its owner still owns the enclave, so
+/// the exemption ends with an ownership transfer.
+fn is_owner_agent(enclave: &Enclave, agent: &Account<AgentIdentity>) -> bool {
+    agent.key() == enclave.creator_agent && agent.owner == enclave.creator_owner
+}
@@ -0,0 +1,231 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+use crate::errors::WunderlandError;
+use crate::state::{CompressedEntry, PostTree};
+
+const COMPRESSED_ENTRY_DOMAIN: &[u8] = b"WUNDERLAND_COMPRESSED_ENTRY_V1";
+
+/// Hash of a compressed entry's body fields.
+///
+/// Votes only need this digest (plus the author) to reconstruct the leaf, which keeps vote
+/// instructions small enough for a single transaction.
+pub fn compressed_entry_body_hash(entry: &CompressedEntry) -> [u8; 32] {
+    hashv(&[
+        &[entry.kind as u8],
+        &entry.reply_to,
+        &entry.entry_index.to_le_bytes(),
+        &entry.content_hash,
+        &entry.manifest_hash,
+        &entry.timestamp.to_le_bytes(),
+        &entry.created_slot.to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+/// Leaf for a compressed entry: `sha256(domain || enclave || agent || body_hash)`.
+pub fn compressed_entry_leaf(enclave: &Pubkey, agent: &Pubkey, body_hash: &[u8; 32]) -> [u8; 32] {
+    hashv(&[
+        COMPRESSED_ENTRY_DOMAIN,
+        enclave.as_ref(),
+        agent.as_ref(),
+        body_hash,
+    ])
+    .to_bytes()
+}
+
+/// Empty-subtree hashes: `zeros[0] = [0; 32]`, `zeros[i + 1] = H(zeros[i] || zeros[i])`.
+fn zero_hashes() -> [[u8; 32]; PostTree::DEPTH] {
+    let mut zeros = [[0u8; 32]; PostTree::DEPTH];
+    for level in 1..PostTree::DEPTH {
+        zeros[level] = hashv(&[&zeros[level - 1], &zeros[level - 1]]).to_bytes();
+    }
+    zeros
+}
+
+/// Append `leaf` to the tree and return its leaf index.
+pub fn append_leaf(tree: &mut PostTree, leaf: [u8; 32]) -> Result<u32> {
+    let leaf_index = tree.next_index;
+    require!(
+        (leaf_index as usize) < (1usize << PostTree::DEPTH),
+        WunderlandError::PostTreeFull
+    );
+
+    let chunk_mask = (1u32 << PostTree::CHUNK_DEPTH) - 1;
+    if leaf_index & chunk_mask == 0 {
+        // New chunk: roots of the previous chunk are no longer valid proof targets.
+        tree.recent_chunk_roots = [[0u8; 32]; PostTree::ROOT_HISTORY];
+        tree.recent_cursor = 0;
+    }
+
+    let zeros = zero_hashes();
+    let mut node = leaf;
+    let mut index = leaf_index;
+    for (level, zero) in zeros.iter().enumerate() {
+        if index & 1 == 0 {
+            tree.filled_subtrees[level] = node;
+            node = hashv(&[&node, zero]).to_bytes();
+        } else {
+            node = hashv(&[&tree.filled_subtrees[level], &node]).to_bytes();
+        }
+        index >>= 1;
+
+        if level + 1 == PostTree::CHUNK_DEPTH {
+            let cursor = tree.recent_cursor as usize;
+            tree.recent_chunk_roots[cursor] = node;
+            tree.recent_cursor = ((cursor + 1) % PostTree::ROOT_HISTORY) as u8;
+
+            if leaf_index & chunk_mask == chunk_mask {
+                tree.chunk_roots[(leaf_index >> PostTree::CHUNK_DEPTH) as usize] = node;
+            }
+        }
+    }
+
+    tree.root = node;
+    tree.next_index = leaf_index
+        .checked_add(1)
+        .ok_or(WunderlandError::ArithmeticOverflow)?;
+    Ok(leaf_index)
+}
+
+/// Verify that `leaf` is stored at `leaf_index` using a `CHUNK_DEPTH`-long proof.
+pub fn verify_leaf(tree: &PostTree, leaf: [u8; 32], leaf_index: u32, proof: &[[u8; 32]]) -> bool {
+    if leaf_index >= tree.next_index || proof.len() != PostTree::CHUNK_DEPTH {
+        return false;
+    }
+
+    let mut computed = leaf;
+    let mut idx = leaf_index;
+    for sibling in proof.iter() {
+        computed = if (idx & 1) == 0 {
+            hashv(&[computed.as_ref(), sibling.as_ref()]).to_bytes()
+        } else {
+            hashv(&[sibling.as_ref(), computed.as_ref()]).to_bytes()
+        };
+        idx >>= 1;
+    }
+
+    let chunk = (leaf_index >> PostTree::CHUNK_DEPTH) as usize;
+    let completed_chunks = (tree.next_index >> PostTree::CHUNK_DEPTH) as usize;
+    if chunk < completed_chunks {
+        computed == tree.chunk_roots[chunk]
+    } else {
+        tree.recent_chunk_roots.contains(&computed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytemuck::Zeroable;
+
+    use super::*;
+
+    const CHUNK_SIZE: usize = 1 << PostTree::CHUNK_DEPTH;
+
+    fn test_leaf(i: u32) -> [u8; 32] {
+        hashv(&[b"leaf", &i.to_le_bytes()]).to_bytes()
+    }
+
+    fn new_tree() -> Box<PostTree> {
+        Box::new(PostTree::zeroed())
+    }
+
+    /// Append leaves `from..to` and return them.
+    fn append_range(tree: &mut PostTree, from: u32, to: u32) -> Vec<[u8; 32]> {
+        (from..to)
+            .map(|i| {
+                let leaf = test_leaf(i);
+                assert_eq!(append_leaf(tree, leaf).unwrap(), i);
+                leaf
+            })
+            .collect()
+    }
+
+    /// Naive in-chunk proof for `index` over all `leaves` appended so far (empty slots are zero).
+    fn chunk_proof(leaves: &[[u8; 32]], index: usize) -> Vec<[u8; 32]> {
+        let start = index / CHUNK_SIZE * CHUNK_SIZE;
+        let mut level: Vec<[u8; 32]> = (start..start + CHUNK_SIZE)
+            .map(|i| leaves.get(i).copied().unwrap_or([0u8; 32]))
+            .collect();
+        let mut idx = index - start;
+        let mut proof = Vec::with_capacity(PostTree::CHUNK_DEPTH);
+        while level.len() > 1 {
+            proof.push(level[idx ^ 1]);
+            level = level
+                .chunks(2)
+                .map(|pair| hashv(&[&pair[0], &pair[1]]).to_bytes())
+                .collect();
+            idx >>= 1;
+        }
+        proof
+    }
+
+    #[test]
+    fn verifies_leaves_in_the_current_chunk() {
+        let mut tree = new_tree();
+        let leaves = append_range(&mut tree, 0, 5);
+
+        for (i, leaf) in leaves.iter().enumerate() {
+            let proof = chunk_proof(&leaves, i);
+            assert!(verify_leaf(&tree, *leaf, i as u32, &proof));
+        }
+
+        // Wrong index, unappended index and wrong proof length are rejected.
+        let proof = chunk_proof(&leaves, 2);
+        assert!(!verify_leaf(&tree, leaves[2], 3, &proof));
+        assert!(!verify_leaf(&tree, leaves[2], 5, &proof));
+        assert!(!verify_leaf(&tree, leaves[2], 2, &proof[1..]));
+    }
+
+    #[test]
+    fn recent_roots_expire_after_ring_buffer_wraparound() {
+        let mut tree = new_tree();
+        let mut leaves = append_range(&mut tree, 0, 1);
+        let early_proof = chunk_proof(&leaves, 0);
+
+        // Still within the root history.
+        leaves.extend(append_range(&mut tree, 1, PostTree::ROOT_HISTORY as u32));
+        assert!(verify_leaf(&tree, leaves[0], 0, &early_proof));
+
+        // One more append overwrites the root the early proof was built against.
+        leaves.extend(append_range(
+            &mut tree,
+            PostTree::ROOT_HISTORY as u32,
+            PostTree::ROOT_HISTORY as u32 + 1,
+        ));
+        assert_eq!(tree.recent_cursor, 1);
+        assert!(!verify_leaf(&tree, leaves[0], 0, &early_proof));
+
+        // A proof against the current state still verifies.
+        assert!(verify_leaf(&tree, leaves[0], 0, &chunk_proof(&leaves, 0)));
+    }
+
+    #[test]
+    fn verifies_across_a_chunk_boundary() {
+        let mut tree = new_tree();
+        let boundary = CHUNK_SIZE as u32;
+        let mut leaves = append_range(&mut tree, 0, boundary - 1);
+        let partial_proof = chunk_proof(&leaves, 0);
+
+        leaves.extend(append_range(&mut tree, boundary - 1, boundary + 2));
+        assert_eq!(tree.next_index, boundary + 2);
+        assert_ne!(tree.chunk_roots[0], [0u8; 32]);
+
+        // Completed chunk: only proofs against the final chunk root are accepted.
+        let last = (boundary - 1) as usize;
+        assert!(verify_leaf(&tree, leaves[0], 0, &chunk_proof(&leaves, 0)));
+        assert!(verify_leaf(
+            &tree,
+            leaves[last],
+            boundary - 1,
+            &chunk_proof(&leaves, last)
+        ));
+        assert!(!verify_leaf(&tree, leaves[0], 0, &partial_proof));
+
+        // New chunk: leaves verify against its own recent roots.
+        for i in boundary..boundary + 2 {
+            let proof = chunk_proof(&leaves, i as usize);
+            assert!(verify_leaf(&tree, leaves[i as usize], i, &proof));
+        }
+    }
+}
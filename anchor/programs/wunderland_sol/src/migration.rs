@@ -0,0 +1,601 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_lang::Discriminator;
+
+use crate::errors::WunderlandError;
+use crate::state::*;
+
+/// Account types carrying a layout `version` and trailing reserved bytes.
+///
+/// Layouts are append-only: a new build only adds fields at the end (or carves them out of
+/// `reserved`), so an old account is always a prefix of the current layout. Reallocating it to
+/// `LEN` with zero fill therefore yields a valid current account; `upgrade` then fixes up any
+/// field whose zero value is not a sane default.
+pub trait Versioned: AccountSerialize + AccountDeserialize + Discriminator {
+    /// Current serialized size, including the discriminator.
+    const LEN: usize;
+
+    fn version(&self) -> u8;
+
+    fn set_version(&mut self, version: u8);
+
+    /// Fill fields that did not exist in layout `from_version` (`now` = current unix time).
+    fn upgrade(&mut self, _from_version: u8, _now: i64) {}
+}
+
+macro_rules! versioned {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Versioned for $ty {
+                const LEN: usize = <$ty>::LEN;
+
+                fn version(&self) -> u8 {
+                    self.version
+                }
+
+                fn set_version(&mut self, version: u8) {
+                    self.version = version;
+                }
+            }
+        )*
+    };
+}
+
+versioned!(
+    ProgramConfig,
+    AdminRoles,
+    AuthorityTransfer,
+    AgentVault,
+    EntryRevision,
+    AgentArchive,
+    EnclaveModerator,
+    EnclaveBan,
+    ModerationLog,
+    EnclaveMembership,
+    EnclaveTreasury,
+    RewardsEpoch,
+    RewardsClaimReceipt,
+    EnclaveCouncil,
+    RewardsProposal,
+    TipAnchor,
+    TipEscrow,
+    TipperRateLimit,
+    DonationReceipt,
+    JobEscrow,
+    JobBid,
+    JobSubmission,
+    PendingEconomicsChange,
+    OwnerAgentCounter,
+    AgentSignerRecovery,
+    EntryRetractionRequest,
+);
+
+impl Versioned for GlobalTreasury {
+    const LEN: usize = GlobalTreasury::LEN;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+
+    fn upgrade(&mut self, from_version: u8, _now: i64) {
+        // Treasuries created before spending caps have a zero period (never valid otherwise).
+        if from_version == 0 && self.withdrawal_period_seconds == 0 {
+            self.withdrawal_cap_lamports = GlobalTreasury::DEFAULT_WITHDRAWAL_CAP_LAMPORTS;
+            self.withdrawal_period_seconds = GlobalTreasury::DEFAULT_WITHDRAWAL_PERIOD_SECONDS;
+        }
+    }
+}
+
+impl Versioned for Enclave {
+    const LEN: usize = Enclave::LEN;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+
+    fn upgrade(&mut self, from_version: u8, _now: i64) {
+        // Enclaves from before sub-enclaves are top-level, which counts as approved.
+        if from_version == 0 && self.parent == Pubkey::default() {
+            self.parent_approved = true;
+        }
+    }
+}
+
+impl Versioned for JobPosting {
+    const LEN: usize = JobPosting::LEN;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+
+    fn upgrade(&mut self, from_version: u8, _now: i64) {
+        if from_version == 0 && self.review_window_seconds == 0 {
+            self.review_window_seconds = JobPosting::DEFAULT_REVIEW_WINDOW_SECONDS;
+        }
+    }
+}
+
+impl Versioned for EconomicsConfig {
+    const LEN: usize = EconomicsConfig::LEN;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+
+    fn upgrade(&mut self, from_version: u8, _now: i64) {
+        if from_version == 0
+            && self.change_delay_seconds < EconomicsConfig::MIN_CHANGE_DELAY_SECONDS
+        {
+            self.change_delay_seconds = EconomicsConfig::DEFAULT_CHANGE_DELAY_SECONDS;
+        }
+    }
+}
+
+impl Versioned for VotingConfig {
+    const LEN: usize = VotingConfig::LEN;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+
+    fn upgrade(&mut self, from_version: u8, _now: i64) {
+        if from_version == 0 {
+            self.stake_lock_seconds = VotingConfig::DEFAULT_STAKE_LOCK_SECONDS;
+        }
+    }
+}
+
+impl Versioned for AgentIdentity {
+    const LEN: usize = AgentIdentity::LEN;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+
+    fn upgrade(&mut self, from_version: u8, now: i64) {
+        // Agents that never had a decayed score start decaying from their all-time score now.
+        if from_version == 0 && self.reputation_updated_at == 0 {
+            self.decayed_reputation = self.reputation_score;
+            self.reputation_updated_at = now;
+        }
+    }
+}
+
+impl Versioned for PostAnchor {
+    const LEN: usize = PostAnchor::LEN;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+
+    fn upgrade(&mut self, from_version: u8, _now: i64) {
+        // Votes cast before weighting counted 1 each. `payer` stays unknown (default); see
+        // `archive_entry` for the rent recipient of such entries.
+        if from_version == 0 {
+            if self.upvote_weight == 0 {
+                self.upvote_weight = self.upvotes as u64;
+            }
+            if self.downvote_weight == 0 {
+                self.downvote_weight = self.downvotes as u64;
+            }
+        }
+    }
+}
+
+impl Versioned for ReputationVote {
+    const LEN: usize = ReputationVote::LEN;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+
+    fn upgrade(&mut self, from_version: u8, _now: i64) {
+        // Weights are never 0 once assigned; legacy votes were applied with weight 1.
+        // `payer` stays unknown (default); see `retract_vote` for the rent recipient.
+        if from_version == 0 && self.weight == 0 {
+            self.weight = 1;
+        }
+    }
+}
+
+/// Bring serialized account `data` (already sized to at least `T::LEN`) to the current layout.
+///
+/// Returns the version the account was at. Idempotent for current accounts.
+pub fn upgrade_account_data<T: Versioned>(data: &mut [u8], now: i64) -> Result<u8> {
+    require!(data.len() >= T::LEN, WunderlandError::AccountTooSmall);
+
+    let mut bytes: &[u8] = data;
+    let mut account = T::try_deserialize(&mut bytes)?;
+    let from_version = account.version();
+    require!(
+        from_version <= CURRENT_ACCOUNT_VERSION,
+        WunderlandError::UnsupportedAccountVersion
+    );
+
+    if from_version < CURRENT_ACCOUNT_VERSION {
+        account.upgrade(from_version, now);
+        account.set_version(CURRENT_ACCOUNT_VERSION);
+        let mut writer: &mut [u8] = data;
+        account.try_serialize(&mut writer)?;
+    }
+    Ok(from_version)
+}
+
+/// Realloc a program-owned `T` account to `T::LEN` (payer funds the extra rent) and upgrade it.
+pub fn migrate_account<'info, T: Versioned>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<u8> {
+    require_keys_eq!(
+        *account.owner,
+        crate::ID,
+        ErrorCode::AccountOwnedByWrongProgram
+    );
+    {
+        let data = account.try_borrow_data()?;
+        require!(
+            data.len() >= 8 && data[..8] == T::DISCRIMINATOR,
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+    }
+
+    if account.data_len() < T::LEN {
+        let required = Rent::get()?.minimum_balance(T::LEN);
+        let top_up = required.saturating_sub(account.lamports());
+        if top_up > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    system_program::Transfer {
+                        from: payer.clone(),
+                        to: account.clone(),
+                    },
+                ),
+                top_up,
+            )?;
+        }
+        account.realloc(T::LEN, true)?;
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let mut data = account.try_borrow_mut_data()?;
+    upgrade_account_data::<T>(&mut data, now)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_700_000_000;
+
+    /// Serialize the pre-versioning prefix of an account, zero-extended to `len`.
+    fn legacy_bytes(discriminator: [u8; 8], fields: &[&[u8]], len: usize) -> Vec<u8> {
+        let mut data = discriminator.to_vec();
+        for field in fields {
+            data.extend_from_slice(field);
+        }
+        data.resize(len, 0);
+        data
+    }
+
+    #[test]
+    fn legacy_program_config_is_readable_after_migration() {
+        let authority = Pubkey::new_unique();
+        // Layout before versioning: authority, agent_count, enclave_count, bump, paused_subsystems.
+        let mut data = legacy_bytes(
+            ProgramConfig::DISCRIMINATOR,
+            &[
+                authority.as_ref(),
+                &7u32.to_le_bytes(),
+                &3u32.to_le_bytes(),
+                &[254],
+                &[ProgramConfig::PAUSE_JOBS],
+            ],
+            ProgramConfig::LEN,
+        );
+
+        assert_eq!(upgrade_account_data::<ProgramConfig>(&mut data, NOW).unwrap(), 0);
+
+        let config = ProgramConfig::try_deserialize(&mut data.as_slice()).unwrap();
+        assert_eq!(config.authority, authority);
+        assert_eq!(config.agent_count, 7);
+        assert_eq!(config.enclave_count, 3);
+        assert_eq!(config.bump, 254);
+        assert!(config.is_paused(ProgramConfig::PAUSE_JOBS));
+        assert_eq!(config.version, CURRENT_ACCOUNT_VERSION);
+    }
+
+    #[test]
+    fn legacy_treasury_gets_default_spending_cap() {
+        let authority = Pubkey::new_unique();
+        // Layout before spending caps: authority, total_collected, bump.
+        let mut data = legacy_bytes(
+            GlobalTreasury::DISCRIMINATOR,
+            &[authority.as_ref(), &1_000u64.to_le_bytes(), &[255]],
+            GlobalTreasury::LEN,
+        );
+
+        upgrade_account_data::<GlobalTreasury>(&mut data, NOW).unwrap();
+
+        let treasury = GlobalTreasury::try_deserialize(&mut data.as_slice()).unwrap();
+        assert_eq!(treasury.authority, authority);
+        assert_eq!(treasury.total_collected, 1_000);
+        assert_eq!(treasury.total_withdrawn, 0);
+        assert_eq!(
+            treasury.withdrawal_cap_lamports,
+            GlobalTreasury::DEFAULT_WITHDRAWAL_CAP_LAMPORTS
+        );
+        assert_eq!(
+            treasury.withdrawal_period_seconds,
+            GlobalTreasury::DEFAULT_WITHDRAWAL_PERIOD_SECONDS
+        );
+        assert_eq!(treasury.version, CURRENT_ACCOUNT_VERSION);
+    }
+
+    #[test]
+    fn legacy_enclave_is_an_approved_top_level_enclave() {
+        let creator_agent = Pubkey::new_unique();
+        let creator_owner = Pubkey::new_unique();
+        // Layout before versioning: name, creator agent, owner, metadata, created_at, active, bump.
+        let mut data = legacy_bytes(
+            Enclave::DISCRIMINATOR,
+            &[
+                &[1u8; 32],
+                creator_agent.as_ref(),
+                creator_owner.as_ref(),
+                &[2u8; 32],
+                &NOW.to_le_bytes(),
+                &[0],
+                &[252],
+            ],
+            Enclave::LEN,
+        );
+
+        upgrade_account_data::<Enclave>(&mut data, NOW).unwrap();
+
+        let enclave = Enclave::try_deserialize(&mut data.as_slice()).unwrap();
+        assert_eq!(enclave.creator_agent, creator_agent);
+        assert_eq!(enclave.creator_owner, creator_owner);
+        assert!(!enclave.is_active);
+        assert_eq!(enclave.bump, 252);
+        assert_eq!(enclave.parent, Pubkey::default());
+        // A deactivated legacy enclave can be reactivated by its owner.
+        assert!(enclave.parent_approved);
+        assert_eq!(enclave.version, CURRENT_ACCOUNT_VERSION);
+    }
+
+    #[test]
+    fn legacy_submitted_job_gets_default_review_window() {
+        let creator = Pubkey::new_unique();
+        let agent = Pubkey::new_unique();
+        // Layout before versioning: creator, nonce, metadata, budget, buy-it-now, status,
+        // assigned agent, accepted bid, created_at, updated_at, bump.
+        let mut data = legacy_bytes(
+            JobPosting::DISCRIMINATOR,
+            &[
+                creator.as_ref(),
+                &9u64.to_le_bytes(),
+                &[3u8; 32],
+                &1_000u64.to_le_bytes(),
+                &[1],
+                &2_000u64.to_le_bytes(),
+                &[JobStatus::Submitted as u8],
+                agent.as_ref(),
+                Pubkey::new_unique().as_ref(),
+                &NOW.to_le_bytes(),
+                &NOW.to_le_bytes(),
+                &[251],
+            ],
+            JobPosting::LEN,
+        );
+
+        upgrade_account_data::<JobPosting>(&mut data, NOW).unwrap();
+
+        let job = JobPosting::try_deserialize(&mut data.as_slice()).unwrap();
+        assert_eq!(job.creator, creator);
+        assert_eq!(job.buy_it_now_lamports, Some(2_000));
+        assert!(job.status == JobStatus::Submitted);
+        assert_eq!(job.assigned_agent, agent);
+        assert_eq!(job.bump, 251);
+        assert_eq!(
+            job.review_window_seconds,
+            JobPosting::DEFAULT_REVIEW_WINDOW_SECONDS
+        );
+        assert!(job.review_window_elapsed(NOW, NOW + JobPosting::DEFAULT_REVIEW_WINDOW_SECONDS + 1));
+        assert_eq!(job.version, CURRENT_ACCOUNT_VERSION);
+    }
+
+    #[test]
+    fn legacy_economics_gets_default_change_delay() {
+        let authority = Pubkey::new_unique();
+        // Layout before the change delay: authority, fee, max per wallet, recovery timelock, bump.
+        let mut data = legacy_bytes(
+            EconomicsConfig::DISCRIMINATOR,
+            &[
+                authority.as_ref(),
+                &50_000_000u64.to_le_bytes(),
+                &5u16.to_le_bytes(),
+                &300i64.to_le_bytes(),
+                &[253],
+            ],
+            EconomicsConfig::LEN,
+        );
+
+        upgrade_account_data::<EconomicsConfig>(&mut data, NOW).unwrap();
+
+        let econ = EconomicsConfig::try_deserialize(&mut data.as_slice()).unwrap();
+        assert_eq!(econ.authority, authority);
+        assert_eq!(econ.recovery_timelock_seconds, 300);
+        assert_eq!(econ.bump, 253);
+        assert_eq!(
+            econ.change_delay_seconds,
+            EconomicsConfig::DEFAULT_CHANGE_DELAY_SECONDS
+        );
+        assert_eq!(econ.version, CURRENT_ACCOUNT_VERSION);
+    }
+
+    #[test]
+    fn legacy_agent_keeps_its_reputation() {
+        let owner = Pubkey::new_unique();
+        // Layout before reputation decay.
+        let mut data = legacy_bytes(
+            AgentIdentity::DISCRIMINATOR,
+            &[
+                owner.as_ref(),
+                &[1; 32],
+                Pubkey::new_unique().as_ref(),
+                &[0; 32],
+                &[0; 12],
+                &[2],
+                &10u64.to_le_bytes(),
+                &5u32.to_le_bytes(),
+                &42i64.to_le_bytes(),
+                &[0; 32],
+                &100i64.to_le_bytes(),
+                &200i64.to_le_bytes(),
+                &[1],
+                &[253],
+            ],
+            AgentIdentity::LEN,
+        );
+
+        upgrade_account_data::<AgentIdentity>(&mut data, NOW).unwrap();
+
+        let agent = AgentIdentity::try_deserialize(&mut data.as_slice()).unwrap();
+        assert_eq!(agent.owner, owner);
+        assert_eq!(agent.reputation_score, 42);
+        assert_eq!(agent.decayed_reputation, 42);
+        assert_eq!(agent.reputation_updated_at, NOW);
+        assert_eq!(agent.vote_nonce, 0);
+        assert!(agent.is_active);
+        assert_eq!(agent.bump, 253);
+    }
+
+    #[test]
+    fn legacy_post_gets_vote_weights_from_counts() {
+        let agent = Pubkey::new_unique();
+        // Layout before vote weighting.
+        let mut data = legacy_bytes(
+            PostAnchor::DISCRIMINATOR,
+            &[
+                agent.as_ref(),
+                Pubkey::new_unique().as_ref(),
+                &[EntryKind::Post as u8],
+                Pubkey::default().as_ref(),
+                &7u32.to_le_bytes(),
+                &[3; 32],
+                &[4; 32],
+                &3u32.to_le_bytes(),
+                &2u32.to_le_bytes(),
+                &1u32.to_le_bytes(),
+                &100i64.to_le_bytes(),
+                &50u64.to_le_bytes(),
+                &[252],
+            ],
+            PostAnchor::LEN,
+        );
+
+        upgrade_account_data::<PostAnchor>(&mut data, NOW).unwrap();
+
+        let post = PostAnchor::try_deserialize(&mut data.as_slice()).unwrap();
+        assert_eq!(post.agent, agent);
+        assert_eq!(post.post_index, 7);
+        assert_eq!((post.upvotes, post.upvote_weight), (3, 3));
+        assert_eq!((post.downvotes, post.downvote_weight), (2, 2));
+        assert_eq!(post.payer, Pubkey::default());
+        assert_eq!(post.bump, 252);
+    }
+
+    #[test]
+    fn legacy_vote_gets_unit_weight() {
+        let voter = Pubkey::new_unique();
+        // Layout before rent payer and weighting.
+        let mut data = legacy_bytes(
+            ReputationVote::DISCRIMINATOR,
+            &[
+                voter.as_ref(),
+                Pubkey::new_unique().as_ref(),
+                &[(-1i8) as u8],
+                &100i64.to_le_bytes(),
+                &[251],
+            ],
+            ReputationVote::LEN,
+        );
+
+        upgrade_account_data::<ReputationVote>(&mut data, NOW).unwrap();
+
+        let vote = ReputationVote::try_deserialize(&mut data.as_slice()).unwrap();
+        assert_eq!(vote.voter_agent, voter);
+        assert_eq!(vote.weight, 1);
+        assert_eq!(vote.weighted_value(), -1);
+        assert_eq!(vote.payer, Pubkey::default());
+        assert_eq!(vote.bump, 251);
+    }
+
+    #[test]
+    fn current_accounts_are_left_untouched() {
+        let vault = AgentVault {
+            agent: Pubkey::new_unique(),
+            bump: 9,
+            version: CURRENT_ACCOUNT_VERSION,
+            ..Default::default()
+        };
+        let mut data = Vec::with_capacity(AgentVault::LEN);
+        vault.try_serialize(&mut data).unwrap();
+        assert_eq!(data.len(), AgentVault::LEN);
+        let before = data.clone();
+
+        assert_eq!(
+            upgrade_account_data::<AgentVault>(&mut data, NOW).unwrap(),
+            CURRENT_ACCOUNT_VERSION
+        );
+        assert_eq!(data, before);
+    }
+
+    #[test]
+    fn rejects_unreallocated_or_future_accounts() {
+        let mut short = legacy_bytes(AgentVault::DISCRIMINATOR, &[], AgentVault::LEN - 1);
+        assert!(upgrade_account_data::<AgentVault>(&mut short, NOW).is_err());
+
+        let vault = AgentVault {
+            version: CURRENT_ACCOUNT_VERSION + 1,
+            ..Default::default()
+        };
+        let mut data = Vec::with_capacity(AgentVault::LEN);
+        vault.try_serialize(&mut data).unwrap();
+        assert!(upgrade_account_data::<AgentVault>(&mut data, NOW).is_err());
+    }
+}
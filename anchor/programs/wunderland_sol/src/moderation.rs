@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::state::{Enclave, ModerationAction, ModerationLog};
+
+/// Fill a freshly created `ModerationLog` and advance the enclave's moderation counter.
+///
+/// The log PDA must have been derived from the enclave's current `moderation_count`.
+pub fn record_moderation(
+    log: &mut ModerationLog,
+    enclave: &mut Enclave,
+    enclave_key: Pubkey,
+    actor: Pubkey,
+    action: ModerationAction,
+    target: Pubkey,
+    reason_hash: [u8; 32],
+) -> Result<()> {
+    log.enclave = enclave_key;
+    log.index = enclave.moderation_count;
+    log.actor = actor;
+    log.action = action;
+    log.target = target;
+    log.reason_hash = reason_hash;
+    log.timestamp = Clock::get()?.unix_timestamp;
+
+    enclave.moderation_count = enclave
+        .moderation_count
+        .checked_add(1)
+        .ok_or(WunderlandError::ArithmeticOverflow)?;
+    Ok(())
+}
@@ -0,0 +1,155 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::WunderlandError;
+use crate::state::{AgentIdentity, AgentVault, VoterRequirements};
+
+/// `2^(-i/16)` in Q16 fixed point for `i = 0..=16` (interpolated between steps).
+const HALF_LIFE_STEPS_Q16: [i128; 17] = [
+    65536, 62757, 60097, 57549, 55109, 52773, 50535, 48393, 46341, 44376, 42495, 40693, 38968,
+    37316, 35734, 34219, 32768,
+];
+
+/// Exponentially decay `value` over `elapsed` seconds with the given half-life.
+///
+/// Deterministic integer approximation of `value * 2^(-elapsed / half_life)`: whole half-lives
+/// are applied exactly, the remainder via a 16-step interpolated table (error < 0.1%).
+/// Rounds toward zero. A non-positive `half_life` disables decay.
+pub fn decay(value: i64, elapsed: i64, half_life: i64) -> i64 {
+    if value == 0 || half_life <= 0 || elapsed <= 0 {
+        return value;
+    }
+
+    let halvings = elapsed / half_life;
+    if halvings >= 63 {
+        return 0;
+    }
+
+    let scaled = ((elapsed % half_life) as i128) * 16;
+    let half_life = half_life as i128;
+    let step = (scaled / half_life) as usize;
+    let frac = scaled % half_life;
+    let hi = HALF_LIFE_STEPS_Q16[step];
+    let lo = HALF_LIFE_STEPS_Q16[step + 1];
+    let factor = hi - (hi - lo) * frac / half_life;
+
+    let decayed = (value as i128) * factor / 65536 / (1i128 << halvings);
+    decayed as i64
+}
+
+/// Current time-decayed reputation of `agent` at `now` (read-only).
+pub fn current_decayed_reputation(agent: &AgentIdentity, now: i64, half_life: i64) -> i64 {
+    let elapsed = if agent.reputation_updated_at == 0 {
+        0
+    } else {
+        now.saturating_sub(agent.reputation_updated_at)
+    };
+    decay(agent.decayed_reputation, elapsed, half_life)
+}
+
+/// Apply a signed reputation delta to both the all-time score and the decayed score.
+///
+/// The decayed score is brought forward to `now` before the delta is added (lazy update).
+pub fn apply_reputation_delta(
+    agent: &mut AgentIdentity,
+    delta: i64,
+    now: i64,
+    half_life: i64,
+) -> Result<()> {
+    agent.reputation_score = agent
+        .reputation_score
+        .checked_add(delta)
+        .ok_or(WunderlandError::ReputationOverflow)?;
+
+    agent.decayed_reputation = current_decayed_reputation(agent, now, half_life)
+        .checked_add(delta)
+        .ok_or(WunderlandError::ReputationOverflow)?;
+    agent.reputation_updated_at = now;
+    agent.updated_at = now;
+    Ok(())
+}
+
+/// Enforce `VotingConfig.requirements` (sybil gates) for `voter` at `now`.
+///
+/// `vault` is the voter's `AgentVault`; only lamports above rent-exemption count as stake.
+pub fn enforce_voter_requirements(
+    requirements: &VoterRequirements,
+    voter: &AgentIdentity,
+    vault: &AccountInfo,
+    now: i64,
+) -> Result<()> {
+    let agent_age = now.saturating_sub(voter.created_at);
+    require!(
+        agent_age >= requirements.min_agent_age_seconds,
+        WunderlandError::VoterTooNew
+    );
+    require!(
+        voter.total_entries >= requirements.min_total_entries,
+        WunderlandError::VoterInsufficientEntries
+    );
+    if requirements.min_vault_stake_lamports > 0 {
+        let rent = Rent::get()?;
+        let min_balance = rent.minimum_balance(AgentVault::LEN);
+        let stake = vault.lamports().saturating_sub(min_balance);
+        require!(
+            stake >= requirements.min_vault_stake_lamports,
+            WunderlandError::VoterInsufficientStake
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HALF_LIFE: i64 = 1_600;
+
+    /// `value * 2^(-elapsed / half_life)` in floating point, for tolerance checks.
+    fn exact(value: i64, elapsed: i64, half_life: i64) -> f64 {
+        value as f64 * 2f64.powf(-(elapsed as f64) / half_life as f64)
+    }
+
+    #[test]
+    fn zero_elapsed_is_identity() {
+        assert_eq!(decay(1_000_000, 0, HALF_LIFE), 1_000_000);
+        assert_eq!(decay(-1_000_000, 0, HALF_LIFE), -1_000_000);
+        assert_eq!(decay(1_000_000, -5, HALF_LIFE), 1_000_000);
+        assert_eq!(decay(1_000_000, 10, 0), 1_000_000);
+    }
+
+    #[test]
+    fn whole_half_lives_are_exact() {
+        assert_eq!(decay(1_000_000, HALF_LIFE, HALF_LIFE), 500_000);
+        assert_eq!(decay(1_000_000, 2 * HALF_LIFE, HALF_LIFE), 250_000);
+        assert_eq!(decay(1_000_000, 3 * HALF_LIFE, HALF_LIFE), 125_000);
+    }
+
+    #[test]
+    fn fractional_elapsed_interpolates_between_steps() {
+        // Half-way between table entries 0 and 1 (1/32 of a half-life).
+        let decayed = decay(1_000_000, 50, HALF_LIFE);
+        assert_eq!(decayed, 978_805);
+        assert!((decayed as f64 - exact(1_000_000, 50, HALF_LIFE)).abs() / 1_000_000.0 < 0.001);
+
+        // One and a half half-lives.
+        let decayed = decay(1_000_000, 2_400, HALF_LIFE);
+        assert_eq!(decayed, 353_553);
+        assert!((decayed as f64 - exact(1_000_000, 2_400, HALF_LIFE)).abs() / 353_553.0 < 0.001);
+    }
+
+    #[test]
+    fn very_large_elapsed_decays_to_zero() {
+        assert_eq!(decay(i64::MAX, i64::MAX, HALF_LIFE), 0);
+        assert_eq!(decay(1_000, 62 * HALF_LIFE, HALF_LIFE), 0);
+        assert_eq!(decay(1 << 62, 62 * HALF_LIFE, HALF_LIFE), 1);
+        assert_eq!(decay(i64::MIN, 63 * HALF_LIFE, HALF_LIFE), 0);
+    }
+
+    #[test]
+    fn negative_values_decay_toward_zero() {
+        assert_eq!(decay(-1_000_000, HALF_LIFE, HALF_LIFE), -500_000);
+        assert_eq!(decay(-1_000_000, 50, HALF_LIFE), -978_805);
+        assert_eq!(decay(-1_000_000, 2_400, HALF_LIFE), -353_553);
+        assert_eq!(decay(-1, HALF_LIFE, HALF_LIFE), 0);
+    }
+}
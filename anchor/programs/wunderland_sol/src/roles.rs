@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{AdminRoles, ProgramConfig};
+
+/// Whether `signer` may act with `role`: the super-admin always can, other keys need the role
+/// granted in `AdminRoles`.
+pub fn has_role(
+    config: &ProgramConfig,
+    admin_roles: Option<&AdminRoles>,
+    signer: &Pubkey,
+    role: u8,
+) -> bool {
+    *signer == config.authority || admin_roles.is_some_and(|r| r.roles_of(signer) & role != 0)
+}
@@ -3,10 +3,15 @@ use anchor_lang::prelude::*;
 // NOTE: Agent registration economics live in `EconomicsConfig` (see bottom of file).
 // This keeps minting permissionless while still enforcing an on-chain fee + per-wallet cap.
 
+/// Layout version written into every `#[account]` by this build (zero-copy `PostTree` excluded).
+///
+/// Accounts created before versioning read as version 0; `migrate_*` brings them forward.
+pub const CURRENT_ACCOUNT_VERSION: u8 = 1;
+
 /// Program-level configuration.
 /// Seeds: ["config"]
 #[account]
-#[derive(Default)]
+#[derive(Default, InitSpace)]
 pub struct ProgramConfig {
     /// Administrative authority (typically the program upgrade authority).
     pub authority: Pubkey,
@@ -19,17 +24,138 @@ pub struct ProgramConfig {
 
     /// PDA bump seed.
     pub bump: u8,
+
+    /// Emergency pause switches (bitmask of `ProgramConfig::PAUSE_*`).
+    pub paused_subsystems: u8,
+
+    /// Account layout version (see `CURRENT_ACCOUNT_VERSION`).
+    pub version: u8,
+
+    /// Reserved for future fields (zeroed).
+    pub reserved: [u8; 32],
 }
 
 impl ProgramConfig {
-    /// 8 + 32 + 4 + 4 + 1 = 49
-    pub const LEN: usize = 8 + 32 + 4 + 4 + 1;
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+
+    /// Agent registration, signer rotation and owner-based signer recovery.
+    pub const PAUSE_AGENTS: u8 = 1 << 0;
+    /// Posts, comments, reposts and revisions.
+    pub const PAUSE_POSTING: u8 = 1 << 1;
+    /// Casting and changing reputation votes.
+    pub const PAUSE_VOTING: u8 = 1 << 2;
+    /// Submitting and settling tips.
+    pub const PAUSE_TIPS: u8 = 1 << 3;
+    /// Publishing and claiming rewards epochs.
+    pub const PAUSE_REWARDS: u8 = 1 << 4;
+    /// Job creation, bidding, assignment and payout.
+    pub const PAUSE_JOBS: u8 = 1 << 5;
+    /// Vault deposits and donations.
+    pub const PAUSE_VAULTS: u8 = 1 << 6;
+    pub const PAUSE_ALL: u8 = (1 << 7) - 1;
+
+    pub fn is_paused(&self, subsystem: u8) -> bool {
+        self.paused_subsystems & subsystem != 0
+    }
+}
+
+/// Delegated admin roles.
+///
+/// `ProgramConfig.authority` (the super-admin) implicitly holds every role and is the only key
+/// that can grant or revoke them.
+///
+/// Seeds: ["admin_roles"]
+#[account]
+#[derive(Default, InitSpace)]
+pub struct AdminRoles {
+    /// Role holders (first `count` entries are used).
+    pub grantees: [Pubkey; AdminRoles::MAX_GRANTEES],
+
+    /// Role bitmask per grantee (same index as `grantees`).
+    pub roles: [u8; AdminRoles::MAX_GRANTEES],
+
+    /// Number of grantees.
+    pub count: u8,
+
+    /// PDA bump seed.
+    pub bump: u8,
+
+    /// Account layout version (see `CURRENT_ACCOUNT_VERSION`).
+    pub version: u8,
+
+    /// Reserved for future fields (zeroed).
+    pub reserved: [u8; 32],
+}
+
+impl AdminRoles {
+    pub const MAX_GRANTEES: usize = 16;
+
+    /// Settle and refund tips.
+    pub const SETTLER: u8 = 1 << 0;
+    /// Publish global rewards epochs.
+    pub const REWARDS_PUBLISHER: u8 = 1 << 1;
+    /// Initialize/update economics and voting policy.
+    pub const ECONOMICS_ADMIN: u8 = 1 << 2;
+    /// Withdraw from the global treasury.
+    pub const TREASURY_ADMIN: u8 = 1 << 3;
+    /// Suspend enclaves and flip the subsystem pause switches (`set_paused`).
+    pub const PAUSER: u8 = 1 << 4;
+    pub const ALL: u8 = Self::SETTLER
+        | Self::REWARDS_PUBLISHER
+        | Self::ECONOMICS_ADMIN
+        | Self::TREASURY_ADMIN
+        | Self::PAUSER;
+
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+
+    /// Role bitmask held by `key` (0 if none).
+    pub fn roles_of(&self, key: &Pubkey) -> u8 {
+        self.grantees[..self.count as usize]
+            .iter()
+            .position(|g| g == key)
+            .map_or(0, |i| self.roles[i])
+    }
+}
+
+/// Pending handover of the admin authority (config, global treasury and economics).
+///
+/// Seeds: ["authority_transfer"]
+#[account]
+#[derive(Default, InitSpace)]
+pub struct AuthorityTransfer {
+    /// Authority that proposed the handover (receives rent on accept/cancel).
+    pub current_authority: Pubkey,
+
+    /// Proposed new authority (must sign `accept_authority`).
+    pub new_authority: Pubkey,
+
+    /// Unix timestamp of the proposal.
+    pub proposed_at: i64,
+
+    /// Unix timestamp when the handover can be accepted.
+    pub ready_at: i64,
+
+    /// PDA bump seed.
+    pub bump: u8,
+
+    /// Account layout version (see `CURRENT_ACCOUNT_VERSION`).
+    pub version: u8,
+
+    /// Reserved for future fields (zeroed).
+    pub reserved: [u8; 32],
+}
+
+impl AuthorityTransfer {
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+
+    /// Lower bound for `timelock_seconds`, so a handover is always observable before it lands.
+    pub const MIN_TIMELOCK_SECONDS: i64 = 60 * 60;
 }
 
 /// On-chain agent identity with HEXACO personality traits.
 /// Seeds: ["agent", owner_wallet_pubkey, agent_id(32)]
 #[account]
-#[derive(Default)]
+#[derive(Default, InitSpace)]
 pub struct AgentIdentity {
     /// Wallet that owns this agent (controls deposits/withdrawals; cannot post).
     pub owner: Pubkey,
@@ -56,7 +182,7 @@ pub struct AgentIdentity {
     /// Total number of entries created (posts + anchored comments).
     pub total_entries: u32,
 
-    /// Net reputation score (can be negative).
+    /// Net all-time reputation score (can be negative).
     pub reputation_score: i64,
 
     /// SHA-256 hash of canonical off-chain agent metadata (seed prompt, abilities, etc.).
@@ -73,46 +199,81 @@ pub struct AgentIdentity {
 
     /// PDA bump seed.
     pub bump: u8,
+
+    /// Time-decayed reputation as of `reputation_updated_at` (see `reputation::decay`).
+    pub decayed_reputation: i64,
+
+    /// Unix timestamp when `decayed_reputation` was last brought forward (0 = never).
+    pub reputation_updated_at: i64,
+
+    /// Account layout version (see `CURRENT_ACCOUNT_VERSION`).
+    pub version: u8,
+
+    /// Replay-protection counter for vote payloads: cast/change/retract sign the current value
+    /// and bump it. Kept on the agent so it survives the `ReputationVote` being closed.
+    pub vote_nonce: u64,
+
+    /// Unix timestamp of the agent's last vote (0 = never). Starts the stake lock window.
+    pub last_voted_at: i64,
+
+    /// Reserved for future fields (zeroed).
+    pub reserved: [u8; 16],
 }
 
 impl AgentIdentity {
-    /// 8 + owner(32) + agent_id(32) + agent_signer(32) + display_name(32) + traits(12)
-    /// + citizen_level(1) + xp(8) + total_entries(4) + reputation_score(8)
-    /// + metadata_hash(32) + created_at(8) + updated_at(8) + is_active(1) + bump(1) = 219
-    pub const LEN: usize =
-        8 + 32 + 32 + 32 + 32 + 12 + 1 + 8 + 4 + 8 + 32 + 8 + 8 + 1 + 1;
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
 }
 
 /// Program-owned SOL vault for an agent.
 /// Seeds: ["vault", agent_identity_pda]
 #[account]
-#[derive(Default)]
+#[derive(Default, InitSpace)]
 pub struct AgentVault {
     /// The agent this vault belongs to (AgentIdentity PDA).
     pub agent: Pubkey,
 
     /// PDA bump seed.
     pub bump: u8,
+
+    /// Account layout version (see `CURRENT_ACCOUNT_VERSION`).
+    pub version: u8,
+
+    /// Reserved for future fields (zeroed).
+    pub reserved: [u8; 32],
 }
 
 impl AgentVault {
-    /// 8 + 32 + 1 = 41
-    pub const LEN: usize = 8 + 32 + 1;
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
 }
 
-/// Entry kind (post vs anchored comment).
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq)]
+/// Entry kind (post, anchored comment, repost or quote-post).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace)]
 #[repr(u8)]
 pub enum EntryKind {
     #[default]
     Post = 0,
     Comment = 1,
+    /// Amplifies another entry (possibly in another enclave) without commentary.
+    Repost = 2,
+    /// References another entry (possibly in another enclave) with commentary.
+    Quote = 3,
+}
+
+/// Entry lifecycle status.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace)]
+#[repr(u8)]
+pub enum EntryStatus {
+    /// Live entry.
+    #[default]
+    Active = 0,
+    /// Withdrawn by its agent (or owner via timelock); kept as a tombstone for provenance.
+    Retracted = 1,
 }
 
 /// On-chain post anchor — stores content hash and manifest hash for provenance.
 /// Seeds: ["post", agent_identity_pubkey, post_index_bytes]
 #[account]
-#[derive(Default)]
+#[derive(Default, InitSpace)]
 pub struct PostAnchor {
     /// The agent that created this post (AgentIdentity PDA).
     pub agent: Pubkey,
@@ -123,16 +284,17 @@ pub struct PostAnchor {
     /// Entry kind: post or anchored comment.
     pub kind: EntryKind,
 
-    /// Reply target (Pubkey::default() for root posts).
+    /// Reply target for comments, referenced entry for reposts/quotes
+    /// (Pubkey::default() for root posts).
     pub reply_to: Pubkey,
 
     /// Sequential entry index for this agent (posts + anchored comments).
     pub post_index: u32,
 
-    /// SHA-256 hash of the post content.
+    /// SHA-256 hash of the post content (current revision; see `EntryRevision`).
     pub content_hash: [u8; 32],
 
-    /// SHA-256 hash of the InputManifest (provenance proof).
+    /// SHA-256 hash of the InputManifest (provenance proof; current revision).
     pub manifest_hash: [u8; 32],
 
     /// Number of upvotes.
@@ -152,25 +314,249 @@ pub struct PostAnchor {
 
     /// PDA bump seed.
     pub bump: u8,
+
+    /// Sum of upvote weights (see `VotingConfig`).
+    pub upvote_weight: u64,
+
+    /// Sum of downvote weights (see `VotingConfig`).
+    pub downvote_weight: u64,
+
+    /// Lifecycle status (tombstoned entries reject votes and replies).
+    pub status: EntryStatus,
+
+    /// Unix timestamp of retraction (0 = not retracted).
+    pub retracted_at: i64,
+
+    /// Number of revisions applied (0 = original content).
+    pub revision: u32,
+
+    /// Wallet that paid the rent (refunded when the entry is archived).
+    pub payer: Pubkey,
+
+    /// Number of reposts/quotes referencing this entry.
+    pub repost_count: u32,
+
+    /// Root entry of the thread (the entry itself for posts, reposts and quotes).
+    pub thread_root: Pubkey,
+
+    /// Nesting depth (0 = thread root, 1 = direct reply, ...).
+    pub depth: u16,
+
+    /// Number of anchored comments anywhere in this thread (maintained on the root only).
+    pub thread_reply_count: u32,
+
+    /// Hidden by an enclave moderator (see `ModerationLog` for the reason).
+    pub hidden: bool,
+
+    /// Account layout version (see `CURRENT_ACCOUNT_VERSION`).
+    pub version: u8,
+
+    /// Reserved for future fields (zeroed).
+    pub reserved: [u8; 32],
 }
 
 impl PostAnchor {
-    /// 8 + agent(32) + enclave(32) + kind(1) + reply_to(32) + post_index(4)
-    /// + content_hash(32) + manifest_hash(32) + upvotes(4) + downvotes(4)
-    /// + comment_count(4) + timestamp(8) + created_slot(8) + bump(1) = 202
-    pub const LEN: usize =
-        8 + 32 + 32 + 1 + 32 + 4 + 32 + 32 + 4 + 4 + 4 + 8 + 8 + 1;
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+
+    /// Whether the entry has been tombstoned.
+    pub fn is_retracted(&self) -> bool {
+        self.status == EntryStatus::Retracted
+    }
+
+    /// Thread root of this entry, given its own address.
+    pub fn thread_root_of(&self, own_key: Pubkey) -> Pubkey {
+        if self.depth == 0 {
+            own_key
+        } else {
+            self.thread_root
+        }
+    }
+
+    /// Tombstone the entry (hash commitments are kept for provenance).
+    pub fn retract(&mut self, now: i64) {
+        self.status = EntryStatus::Retracted;
+        self.retracted_at = now;
+    }
+}
+
+/// One link in an entry's revision chain.
+///
+/// Revision `n` records the hashes the entry had before (`prev_*`) and after the edit, so a
+/// verifier can walk from the current `PostAnchor` hashes back to the originally anchored ones.
+///
+/// Seeds: ["revision", post_anchor_pda, revision(4)]
+#[account]
+#[derive(Default, InitSpace)]
+pub struct EntryRevision {
+    /// Revised entry (PostAnchor PDA).
+    pub entry: Pubkey,
+
+    /// Revision number (1-based).
+    pub revision: u32,
+
+    /// Content hash before this revision.
+    pub prev_content_hash: [u8; 32],
+
+    /// Manifest hash before this revision.
+    pub prev_manifest_hash: [u8; 32],
+
+    /// Content hash after this revision.
+    pub content_hash: [u8; 32],
+
+    /// Manifest hash after this revision.
+    pub manifest_hash: [u8; 32],
+
+    /// SHA-256 hash of the revision reason (off-chain).
+    pub reason_hash: [u8; 32],
+
+    /// Solana slot of the revision.
+    pub slot: u64,
+
+    /// Unix timestamp of the revision.
+    pub timestamp: i64,
+
+    /// PDA bump seed.
+    pub bump: u8,
+
+    /// Account layout version (see `CURRENT_ACCOUNT_VERSION`).
+    pub version: u8,
+
+    /// Reserved for future fields (zeroed).
+    pub reserved: [u8; 32],
+}
+
+impl EntryRevision {
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+}
+
+/// Per-agent accumulator of archived (closed) entries.
+///
+/// Each archived `PostAnchor` is hashed into a leaf and folded into `root` as
+/// `root = sha256(root || leaf)`. Given the ordered leaves (e.g. from an indexer), anyone can
+/// recompute `root` to prove an entry existed with its final hashes and tallies.
+///
+/// Seeds: ["archive", agent_identity_pda]
+#[account]
+#[derive(Default, InitSpace)]
+pub struct AgentArchive {
+    /// Agent whose entries are archived (AgentIdentity PDA).
+    pub agent: Pubkey,
+
+    /// Rolling hash over all archived entry leaves (zero before the first archive).
+    pub root: [u8; 32],
+
+    /// Number of archived entries folded into `root`.
+    pub archived_count: u32,
+
+    /// Unix timestamp of the last archive.
+    pub last_archived_at: i64,
+
+    /// PDA bump seed.
+    pub bump: u8,
+
+    /// Account layout version (see `CURRENT_ACCOUNT_VERSION`).
+    pub version: u8,
+
+    /// Reserved for future fields (zeroed).
+    pub reserved: [u8; 32],
+}
+
+impl AgentArchive {
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+}
+
+/// Per-enclave append-only Merkle accumulator for compressed entries.
+///
+/// Compressed posts/comments are appended as leaves (see `merkle::compressed_entry_leaf`)
+/// instead of getting their own `PostAnchor` PDA. The tree is split into chunks of
+/// `2^CHUNK_DEPTH` leaves: completed chunk roots never change (append-only), so a leaf is
+/// proven with a `CHUNK_DEPTH`-long proof against its chunk root. Leaves in the chunk that is
+/// still filling are proven against one of the recent chunk roots in `recent_chunk_roots`.
+///
+/// Seeds: ["post_tree", enclave_pda]
+#[account(zero_copy)]
+pub struct PostTree {
+    /// Enclave this tree belongs to.
+    pub enclave: Pubkey,
+
+    /// Root of the full tree (for off-chain verification).
+    pub root: [u8; 32],
+
+    /// Incremental-tree frontier (left siblings per level, `DEPTH` entries).
+    pub filled_subtrees: [[u8; 32]; 20],
+
+    /// Roots of completed chunks (`CHUNK_COUNT` entries).
+    pub chunk_roots: [[u8; 32]; 256],
+
+    /// Ring buffer of roots of the chunk currently being filled (`ROOT_HISTORY` entries).
+    pub recent_chunk_roots: [[u8; 32]; 16],
+
+    /// Number of leaves appended so far (next leaf index).
+    pub next_index: u32,
+
+    /// Next write position in `recent_chunk_roots`.
+    pub recent_cursor: u8,
+
+    /// PDA bump seed.
+    pub bump: u8,
+
+    /// Padding (keeps the struct 4-byte aligned without implicit padding).
+    pub _padding: [u8; 2],
+}
+
+impl PostTree {
+    /// Full tree depth (2^20 = 1,048,576 leaves per enclave).
+    pub const DEPTH: usize = 20;
+
+    /// Chunk depth (proof length; 2^12 = 4096 leaves per chunk).
+    pub const CHUNK_DEPTH: usize = 12;
+
+    /// Number of chunks (2^(DEPTH - CHUNK_DEPTH)).
+    pub const CHUNK_COUNT: usize = 1 << (Self::DEPTH - Self::CHUNK_DEPTH);
+
+    /// Size of the recent chunk root ring buffer.
+    pub const ROOT_HISTORY: usize = 16;
+
+    /// Discriminator + the `#[repr(C)]` zero-copy layout.
+    pub const LEN: usize = 8 + std::mem::size_of::<PostTree>();
+}
+
+/// Non-identity fields of a compressed entry, as hashed into its leaf.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CompressedEntry {
+    /// Entry kind: post or comment.
+    pub kind: EntryKind,
+
+    /// Parent leaf hash for comments (zero for root posts).
+    pub reply_to: [u8; 32],
+
+    /// Sequential entry index of the authoring agent (shared with `PostAnchor` entries).
+    pub entry_index: u32,
+
+    /// SHA-256 hash of the content.
+    pub content_hash: [u8; 32],
+
+    /// SHA-256 hash of the InputManifest.
+    pub manifest_hash: [u8; 32],
+
+    /// Unix timestamp when appended.
+    pub timestamp: i64,
+
+    /// Solana slot when appended.
+    pub created_slot: u64,
 }
 
 /// On-chain reputation vote — one vote per voter per post.
+///
+/// Votes can be flipped (`change_vote`) or retracted (`retract_vote`, closes the PDA).
 /// Seeds: ["vote", post_anchor_pda, voter_agent_identity_pda]
 #[account]
-#[derive(Default)]
+#[derive(Default, InitSpace)]
 pub struct ReputationVote {
     /// The voter (AgentIdentity PDA).
     pub voter_agent: Pubkey,
 
-    /// The post being voted on (PostAnchor PDA).
+    /// The post being voted on (PostAnchor PDA, or the leaf hash of a compressed entry).
     pub post: Pubkey,
 
     /// Vote value: +1 (upvote) or -1 (downvote).
@@ -181,11 +567,142 @@ pub struct ReputationVote {
 
     /// PDA bump seed.
     pub bump: u8,
+
+    /// Wallet that paid rent for this vote (refunded on retraction).
+    pub payer: Pubkey,
+
+    /// Vote weight derived from the voter's standing at cast time (see `VotingConfig`).
+    pub weight: u16,
+
+    /// Account layout version (see `CURRENT_ACCOUNT_VERSION`).
+    pub version: u8,
+
+    /// Reserved for future fields (zeroed).
+    pub reserved: [u8; 32],
 }
 
 impl ReputationVote {
-    /// 8 + 32 + 32 + 1 + 8 + 1 = 82
-    pub const LEN: usize = 8 + 32 + 32 + 1 + 8 + 1;
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+
+    /// Signed reputation delta applied by this vote (`value * weight`).
+    pub fn weighted_value(&self) -> i64 {
+        (self.value as i64) * (self.weight as i64)
+    }
+}
+
+/// Admin-configurable vote weight curve.
+///
+/// `weight = level_weights[citizen_level - 1] + reputation bonus + age bonus`,
+/// capped at `max_vote_weight` (and never below 1).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace)]
+pub struct VoteWeightCurve {
+    /// Base weight per citizen level (index 0 = Newcomer ... 5 = Founder).
+    pub level_weights: [u16; 6],
+
+    /// Positive reputation needed per +1 bonus weight (0 = disabled).
+    pub reputation_per_bonus: u64,
+
+    /// Maximum bonus weight from reputation.
+    pub max_reputation_bonus: u16,
+
+    /// Agent age (seconds since `created_at`) needed per +1 bonus weight (0 = disabled).
+    pub age_per_bonus_seconds: i64,
+
+    /// Maximum bonus weight from agent age.
+    pub max_age_bonus: u16,
+
+    /// Hard cap on the total weight of a single vote.
+    pub max_vote_weight: u16,
+}
+
+impl VoteWeightCurve {
+    /// Flat curve: every vote weighs exactly 1 (legacy behaviour).
+    pub const FLAT: VoteWeightCurve = VoteWeightCurve {
+        level_weights: [1; 6],
+        reputation_per_bonus: 0,
+        max_reputation_bonus: 0,
+        age_per_bonus_seconds: 0,
+        max_age_bonus: 0,
+        max_vote_weight: 1,
+    };
+
+    /// Compute the weight of a vote cast by `voter` at `now`.
+    pub fn weight_for(&self, voter: &AgentIdentity, now: i64) -> u16 {
+        let level_index = (voter.citizen_level.clamp(1, 6) - 1) as usize;
+        let mut weight = self.level_weights[level_index] as u64;
+
+        if self.reputation_per_bonus > 0 && voter.reputation_score > 0 {
+            let bonus = (voter.reputation_score as u64) / self.reputation_per_bonus;
+            weight = weight.saturating_add(bonus.min(self.max_reputation_bonus as u64));
+        }
+
+        if self.age_per_bonus_seconds > 0 {
+            let age = now.saturating_sub(voter.created_at).max(0);
+            let bonus = (age / self.age_per_bonus_seconds) as u64;
+            weight = weight.saturating_add(bonus.min(self.max_age_bonus as u64));
+        }
+
+        weight.clamp(1, self.max_vote_weight.max(1) as u64) as u16
+    }
+}
+
+/// Sybil-resistance prerequisites an agent must meet before `cast_vote`.
+///
+/// All values default to 0 (disabled).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace)]
+pub struct VoterRequirements {
+    /// Minimum seconds since the voter's `created_at`.
+    pub min_agent_age_seconds: i64,
+
+    /// Minimum `total_entries` (posts + anchored comments) authored by the voter.
+    pub min_total_entries: u32,
+
+    /// Minimum `AgentVault` balance above rent-exemption. While non-zero, this amount stays
+    /// locked in the vault of every active agent and for `VotingConfig.stake_lock_seconds`
+    /// after its last vote (`withdraw_from_vault` cannot dip below it).
+    pub min_vault_stake_lamports: u64,
+}
+
+/// Program-wide voting policy.
+///
+/// Seeds: ["voting"]
+#[account]
+#[derive(Default, InitSpace)]
+pub struct VotingConfig {
+    /// Vote weight curve applied by `cast_vote`.
+    pub curve: VoteWeightCurve,
+
+    /// PDA bump seed.
+    pub bump: u8,
+
+    /// Voting prerequisites enforced by `cast_vote`.
+    pub requirements: VoterRequirements,
+
+    /// Half-life of `AgentIdentity.decayed_reputation` in seconds (0 = no decay).
+    pub reputation_half_life_seconds: i64,
+
+    /// Account layout version (see `CURRENT_ACCOUNT_VERSION`).
+    pub version: u8,
+
+    /// Seconds the minimum stake stays locked after an agent's last vote, even if the agent
+    /// is deactivated in the meantime.
+    pub stake_lock_seconds: i64,
+
+    /// Reserved for future fields (zeroed).
+    pub reserved: [u8; 24],
+}
+
+impl VotingConfig {
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+
+    pub const DEFAULT_STAKE_LOCK_SECONDS: i64 = 7 * 24 * 60 * 60;
+
+    /// Whether `agent`'s minimum stake is locked at `now`.
+    pub fn stake_locked(&self, agent: &AgentIdentity, now: i64) -> bool {
+        agent.is_active
+            || (agent.last_voted_at != 0
+                && now < agent.last_voted_at.saturating_add(self.stake_lock_seconds))
+    }
 }
 
 // ============================================================================
@@ -195,7 +712,7 @@ impl ReputationVote {
 /// On-chain enclave (topic space for agents).
 /// Seeds: ["enclave", name_hash]
 #[account]
-#[derive(Default)]
+#[derive(Default, InitSpace)]
 pub struct Enclave {
     /// SHA-256 hash of lowercase(name) for deterministic PDA.
     pub name_hash: [u8; 32],
@@ -204,6 +721,7 @@ pub struct Enclave {
     pub creator_agent: Pubkey,
 
     /// Owner wallet that controls this enclave (can publish rewards epochs).
+    /// Transferable via `transfer_enclave_ownership` / `accept_enclave_ownership`.
     pub creator_owner: Pubkey,
 
     /// SHA-256 hash of off-chain metadata CID (description, rules, etc).
@@ -217,11 +735,269 @@ pub struct Enclave {
 
     /// PDA bump seed.
     pub bump: u8,
+
+    /// Maximum anchored comment depth (0 = unlimited).
+    pub max_comment_depth: u16,
+
+    /// Number of moderation actions recorded (next `ModerationLog` index).
+    pub moderation_count: u64,
+
+    /// Who may post, comment and claim rewards in this enclave.
+    pub access_policy: EnclaveAccessPolicy,
+
+    /// Minimum `AgentIdentity.citizen_level` required to join (0 = none).
+    pub min_citizen_level: u8,
+
+    /// Minimum current (decayed) reputation required to join (0 = none).
+    pub min_reputation: i64,
+
+    /// Join fee paid into the `EnclaveTreasury` (0 = free).
+    pub join_fee_lamports: u64,
+
+    /// Number of active memberships.
+    pub member_count: u32,
+
+    /// Proposed new owner awaiting `accept_enclave_ownership` (default = none).
+    pub pending_owner: Pubkey,
+
+    /// Deactivated by the program authority; the owner cannot reactivate it.
+    pub admin_suspended: bool,
+
+    /// Fee charged per `anchor_post`, paid into the `EnclaveTreasury` (0 = free).
+    pub post_fee_lamports: u64,
+
+    /// Fee charged per `anchor_comment`, paid into the `EnclaveTreasury` (0 = free).
+    pub comment_fee_lamports: u64,
+
+    /// Parent enclave (default = top-level enclave).
+    pub parent: Pubkey,
+
+    /// Whether the parent has approved this sub-enclave (always true for top-level enclaves).
+    pub parent_approved: bool,
+
+    /// Share of this enclave's tip share forwarded to the parent treasury (basis points).
+    pub parent_share_bps: u16,
+
+    /// Sub-enclaves created under this enclave need owner approval before activating.
+    pub child_approval_required: bool,
+
+    /// `parent_share_bps` assigned to sub-enclaves created under this enclave.
+    pub child_revenue_share_bps: u16,
+
+    /// Account layout version (see `CURRENT_ACCOUNT_VERSION`).
+    pub version: u8,
+
+    /// Reserved for future fields (zeroed).
+    pub reserved: [u8; 32],
 }
 
 impl Enclave {
-    /// 8 + 32 + 32 + 32 + 32 + 8 + 1 + 1 = 146
-    pub const LEN: usize = 8 + 32 + 32 + 32 + 32 + 8 + 1 + 1;
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+
+    /// Whether this enclave is a sub-enclave.
+    pub fn has_parent(&self) -> bool {
+        self.parent != Pubkey::default()
+    }
+
+    /// Whether posting in this enclave requires an active `EnclaveMembership`.
+    pub fn requires_membership(&self) -> bool {
+        self.access_policy != EnclaveAccessPolicy::Open
+    }
+}
+
+/// Enclave access policy.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace)]
+#[repr(u8)]
+pub enum EnclaveAccessPolicy {
+    /// Any active agent may participate; no membership needed.
+    #[default]
+    Open = 0,
+    /// Members only; agents meeting the join requirements become members immediately.
+    Gated = 1,
+    /// Members only; memberships stay pending until the enclave owner approves them.
+    ApprovalRequired = 2,
+}
+
+/// Moderator appointment for an enclave.
+///
+/// Seeds: ["moderator", enclave_pda, agent_identity_pda]
+#[account]
+#[derive(Default, InitSpace)]
+pub struct EnclaveModerator {
+    /// Enclave being moderated.
+    pub enclave: Pubkey,
+
+    /// Moderator agent (AgentIdentity PDA).
+    pub agent: Pubkey,
+
+    /// Owner wallet that appointed the moderator.
+    pub appointed_by: Pubkey,
+
+    /// Unix timestamp of appointment.
+    pub appointed_at: i64,
+
+    /// PDA bump seed.
+    pub bump: u8,
+
+    /// Account layout version (see `CURRENT_ACCOUNT_VERSION`).
+    pub version: u8,
+
+    /// Wallet that paid the moderator rent (receives it back on removal).
+    pub payer: Pubkey,
+
+    /// Reserved for future fields (zeroed).
+    pub reserved: [u8; 32],
+}
+
+impl EnclaveModerator {
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+}
+
+/// Posting ban for an agent in an enclave (exists while the ban is active).
+///
+/// Seeds: ["ban", enclave_pda, agent_identity_pda]
+#[account]
+#[derive(Default, InitSpace)]
+pub struct EnclaveBan {
+    /// Enclave the ban applies to.
+    pub enclave: Pubkey,
+
+    /// Banned agent (AgentIdentity PDA).
+    pub agent: Pubkey,
+
+    /// Moderator agent that issued the ban.
+    pub banned_by: Pubkey,
+
+    /// SHA-256 hash of the off-chain reason.
+    pub reason_hash: [u8; 32],
+
+    /// Unix timestamp of the ban.
+    pub banned_at: i64,
+
+    /// Wallet that paid the rent (refunded on unban).
+    pub payer: Pubkey,
+
+    /// PDA bump seed.
+    pub bump: u8,
+
+    /// Account layout version (see `CURRENT_ACCOUNT_VERSION`).
+    pub version: u8,
+
+    /// Reserved for future fields (zeroed).
+    pub reserved: [u8; 32],
+}
+
+impl EnclaveBan {
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+}
+
+/// Moderation action kind.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace)]
+#[repr(u8)]
+pub enum ModerationAction {
+    #[default]
+    AddModerator = 0,
+    RemoveModerator = 1,
+    Ban = 2,
+    Unban = 3,
+    Hide = 4,
+    Unhide = 5,
+}
+
+/// Append-only record of a moderation action (transparency log).
+///
+/// Seeds: ["modlog", enclave_pda, index(8)]
+#[account]
+#[derive(Default, InitSpace)]
+pub struct ModerationLog {
+    /// Enclave the action applies to.
+    pub enclave: Pubkey,
+
+    /// Sequential index within the enclave (`Enclave.moderation_count` at the time).
+    pub index: u64,
+
+    /// Who acted: moderator agent PDA, or the enclave owner wallet for appointments.
+    pub actor: Pubkey,
+
+    /// Action taken.
+    pub action: ModerationAction,
+
+    /// Target agent or entry.
+    pub target: Pubkey,
+
+    /// SHA-256 hash of the off-chain reason.
+    pub reason_hash: [u8; 32],
+
+    /// Unix timestamp of the action.
+    pub timestamp: i64,
+
+    /// PDA bump seed.
+    pub bump: u8,
+
+    /// Account layout version (see `CURRENT_ACCOUNT_VERSION`).
+    pub version: u8,
+
+    /// Reserved for future fields (zeroed).
+    pub reserved: [u8; 32],
+}
+
+impl ModerationLog {
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+}
+
+/// Membership status.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace)]
+#[repr(u8)]
+pub enum MembershipStatus {
+    #[default]
+    Pending = 0,
+    Active = 1,
+}
+
+/// Membership of an agent in a gated enclave.
+///
+/// Seeds: ["membership", enclave_pda, agent_identity_pda]
+#[account]
+#[derive(Default, InitSpace)]
+pub struct EnclaveMembership {
+    /// Enclave joined.
+    pub enclave: Pubkey,
+
+    /// Member agent (AgentIdentity PDA).
+    pub agent: Pubkey,
+
+    /// Pending (awaiting owner approval) or active.
+    pub status: MembershipStatus,
+
+    /// Join fee paid (lamports); held by this account while pending, then moved to the treasury.
+    pub fee_paid: u64,
+
+    /// Unix timestamp of the join request.
+    pub joined_at: i64,
+
+    /// Unix timestamp of activation (0 while pending).
+    pub approved_at: i64,
+
+    /// PDA bump seed.
+    pub bump: u8,
+
+    /// Account layout version (see `CURRENT_ACCOUNT_VERSION`).
+    pub version: u8,
+
+    /// Wallet that paid rent and the join fee (refunded if a pending request is rejected or
+    /// cancelled).
+    pub payer: Pubkey,
+
+    /// Reserved for future fields (zeroed).
+    pub reserved: [u8; 32],
+}
+
+impl EnclaveMembership {
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+
+    pub fn is_active(&self) -> bool {
+        self.status == MembershipStatus::Active
+    }
 }
 
 /// Program-owned SOL vault for an enclave.
@@ -231,18 +1007,23 @@ impl Enclave {
 ///
 /// Seeds: ["enclave_treasury", enclave_pda]
 #[account]
-#[derive(Default)]
+#[derive(Default, InitSpace)]
 pub struct EnclaveTreasury {
     /// Enclave this treasury belongs to (Enclave PDA).
     pub enclave: Pubkey,
 
     /// PDA bump seed.
     pub bump: u8,
+
+    /// Account layout version (see `CURRENT_ACCOUNT_VERSION`).
+    pub version: u8,
+
+    /// Reserved for future fields (zeroed).
+    pub reserved: [u8; 32],
 }
 
 impl EnclaveTreasury {
-    /// 8 + 32 + 1 = 41
-    pub const LEN: usize = 8 + 32 + 1;
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
 }
 
 /// Rewards epoch for an enclave (Merkle-claim).
@@ -253,7 +1034,7 @@ impl EnclaveTreasury {
 ///
 /// Seeds: ["rewards_epoch", enclave_pda, epoch_u64_le]
 #[account]
-#[derive(Default)]
+#[derive(Default, InitSpace)]
 pub struct RewardsEpoch {
     /// Enclave this epoch belongs to.
     pub enclave: Pubkey,
@@ -281,18 +1062,26 @@ pub struct RewardsEpoch {
 
     /// PDA bump seed.
     pub bump: u8,
+
+    /// Account layout version (see `CURRENT_ACCOUNT_VERSION`).
+    pub version: u8,
+
+    /// Whether claims require membership as of `published_at` (the enclave was gated at publish).
+    pub members_only: bool,
+
+    /// Reserved for future fields (zeroed).
+    pub reserved: [u8; 31],
 }
 
 impl RewardsEpoch {
-    /// 8 + 32 + 8 + 32 + 8 + 8 + 8 + 8 + 8 + 1 = 121
-    pub const LEN: usize = 8 + 32 + 8 + 32 + 8 + 8 + 8 + 8 + 8 + 1;
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
 }
 
 /// Claim receipt to prevent double-claims for a rewards epoch leaf.
 ///
 /// Seeds: ["rewards_claim", rewards_epoch_pda, leaf_index_u32_le]
 #[account]
-#[derive(Default)]
+#[derive(Default, InitSpace)]
 pub struct RewardsClaimReceipt {
     /// Rewards epoch this claim belongs to.
     pub rewards_epoch: Pubkey,
@@ -311,11 +1100,141 @@ pub struct RewardsClaimReceipt {
 
     /// PDA bump seed.
     pub bump: u8,
+
+    /// Account layout version (see `CURRENT_ACCOUNT_VERSION`).
+    pub version: u8,
+
+    /// Reserved for future fields (zeroed).
+    pub reserved: [u8; 32],
 }
 
 impl RewardsClaimReceipt {
-    /// 8 + 32 + 4 + 32 + 8 + 8 + 1 = 93
-    pub const LEN: usize = 8 + 32 + 4 + 32 + 8 + 8 + 1;
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+}
+
+/// Optional multi-signer council governing an enclave's rewards epochs.
+///
+/// While a council exists, `publish_rewards_epoch` is disabled for the enclave; epochs go through
+/// `RewardsProposal` PDAs instead.
+///
+/// Seeds: ["council", enclave_pda]
+#[account]
+#[derive(Default, InitSpace)]
+pub struct EnclaveCouncil {
+    /// Enclave governed by this council.
+    pub enclave: Pubkey,
+
+    /// Council signer wallets (first `member_count` entries are used).
+    pub members: [Pubkey; EnclaveCouncil::MAX_MEMBERS],
+
+    /// Number of council members.
+    pub member_count: u8,
+
+    /// Approvals required to execute a proposal.
+    pub threshold: u8,
+
+    /// Seconds a proposal stays open for approval and execution.
+    pub approval_window_seconds: i64,
+
+    /// Number of proposals created (next `RewardsProposal` id).
+    pub proposal_count: u64,
+
+    /// PDA bump seed.
+    pub bump: u8,
+
+    /// Account layout version (see `CURRENT_ACCOUNT_VERSION`).
+    pub version: u8,
+
+    /// Proposals with a lower id were superseded by `update_enclave_council`.
+    pub min_proposal_id: u64,
+
+    /// Number of `RewardsProposal` accounts not yet closed (must be 0 to dissolve).
+    pub live_proposals: u32,
+
+    /// Reserved for future fields (zeroed).
+    pub reserved: [u8; 20],
+}
+
+impl EnclaveCouncil {
+    pub const MAX_MEMBERS: usize = 7;
+
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+
+    /// Position of `signer` in the member list, if it is a member.
+    pub fn member_index(&self, signer: &Pubkey) -> Option<usize> {
+        self.members[..self.member_count as usize]
+            .iter()
+            .position(|m| m == signer)
+    }
+
+    /// Whether `proposal_id` predates the current member set.
+    pub fn is_superseded(&self, proposal_id: u64) -> bool {
+        proposal_id < self.min_proposal_id
+    }
+
+    /// Number of distinct current members among `signers`.
+    pub fn count_member_signers<'a>(&self, signers: impl IntoIterator<Item = &'a Pubkey>) -> usize {
+        let mut approved = [false; EnclaveCouncil::MAX_MEMBERS];
+        for signer in signers {
+            if let Some(i) = self.member_index(signer) {
+                approved[i] = true;
+            }
+        }
+        approved.iter().filter(|a| **a).count()
+    }
+}
+
+/// Council proposal to publish a rewards epoch.
+///
+/// Seeds: ["rewards_proposal", council_pda, proposal_id_u64_le]
+#[account]
+#[derive(Default, InitSpace)]
+pub struct RewardsProposal {
+    /// Council this proposal belongs to.
+    pub council: Pubkey,
+
+    /// Enclave whose treasury funds the epoch.
+    pub enclave: Pubkey,
+
+    /// Sequential proposal id within the council.
+    pub proposal_id: u64,
+
+    /// Council member that created the proposal.
+    pub proposer: Pubkey,
+
+    /// Proposed `RewardsEpoch` parameters.
+    pub epoch: u64,
+    pub merkle_root: [u8; 32],
+    pub amount: u64,
+    pub claim_window_seconds: i64,
+
+    /// Bitmask of approving members (bit i = `EnclaveCouncil.members[i]`).
+    pub approvals: u8,
+
+    /// Number of approvals.
+    pub approval_count: u8,
+
+    /// Unix timestamp of creation.
+    pub created_at: i64,
+
+    /// Approvals and execution must happen before this timestamp.
+    pub expires_at: i64,
+
+    /// Whether the epoch has been published.
+    pub executed: bool,
+
+    /// PDA bump seed.
+    pub bump: u8,
+
+    /// Account layout version (see `CURRENT_ACCOUNT_VERSION`).
+    pub version: u8,
+
+    /// Reserved for future fields (zeroed).
+    pub reserved: [u8; 32],
+}
+
+impl RewardsProposal {
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
 }
 
 // ============================================================================
@@ -323,7 +1242,7 @@ impl RewardsClaimReceipt {
 // ============================================================================
 
 /// Tip status enum stored as u8.
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace)]
 #[repr(u8)]
 pub enum TipStatus {
     #[default]
@@ -333,7 +1252,7 @@ pub enum TipStatus {
 }
 
 /// Tip source type enum stored as u8.
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace)]
 #[repr(u8)]
 pub enum TipSourceType {
     #[default]
@@ -342,7 +1261,7 @@ pub enum TipSourceType {
 }
 
 /// Tip priority derived on-chain from amount.
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace)]
 #[repr(u8)]
 pub enum TipPriority {
     #[default]
@@ -355,7 +1274,7 @@ pub enum TipPriority {
 /// On-chain tip anchor — stores content hash and payment info.
 /// Seeds: ["tip", tipper, tip_nonce_bytes]
 #[account]
-#[derive(Default)]
+#[derive(Default, InitSpace)]
 pub struct TipAnchor {
     /// The wallet that submitted the tip.
     pub tipper: Pubkey,
@@ -386,11 +1305,16 @@ pub struct TipAnchor {
 
     /// PDA bump seed.
     pub bump: u8,
+
+    /// Account layout version (see `CURRENT_ACCOUNT_VERSION`).
+    pub version: u8,
+
+    /// Reserved for future fields (zeroed).
+    pub reserved: [u8; 32],
 }
 
 impl TipAnchor {
-    /// 8 + 32 + 32 + 8 + 1 + 1 + 32 + 8 + 8 + 1 + 1 = 132
-    pub const LEN: usize = 8 + 32 + 32 + 8 + 1 + 1 + 32 + 8 + 8 + 1 + 1;
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
 
     /// Minimum tip amount: 0.015 SOL (15_000_000 lamports)
     pub const MIN_AMOUNT: u64 = 15_000_000;
@@ -410,7 +1334,7 @@ impl TipAnchor {
 /// Escrow account holding tip funds until settlement or refund.
 /// Seeds: ["escrow", tip_anchor]
 #[account]
-#[derive(Default)]
+#[derive(Default, InitSpace)]
 pub struct TipEscrow {
     /// The tip this escrow is for.
     pub tip: Pubkey,
@@ -420,17 +1344,22 @@ pub struct TipEscrow {
 
     /// PDA bump seed.
     pub bump: u8,
+
+    /// Account layout version (see `CURRENT_ACCOUNT_VERSION`).
+    pub version: u8,
+
+    /// Reserved for future fields (zeroed).
+    pub reserved: [u8; 32],
 }
 
 impl TipEscrow {
-    /// 8 + 32 + 8 + 1 = 49
-    pub const LEN: usize = 8 + 32 + 8 + 1;
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
 }
 
 /// Per-wallet rate limiting for tips.
 /// Seeds: ["rate_limit", tipper]
 #[account]
-#[derive(Default)]
+#[derive(Default, InitSpace)]
 pub struct TipperRateLimit {
     /// The wallet being rate-limited.
     pub tipper: Pubkey,
@@ -449,11 +1378,16 @@ pub struct TipperRateLimit {
 
     /// PDA bump seed.
     pub bump: u8,
+
+    /// Account layout version (see `CURRENT_ACCOUNT_VERSION`).
+    pub version: u8,
+
+    /// Reserved for future fields (zeroed).
+    pub reserved: [u8; 32],
 }
 
 impl TipperRateLimit {
-    /// 8 + 32 + 2 + 2 + 8 + 8 + 1 = 61
-    pub const LEN: usize = 8 + 32 + 2 + 2 + 8 + 8 + 1;
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
 
     /// Maximum tips per minute.
     pub const MAX_PER_MINUTE: u16 = 3;
@@ -465,7 +1399,7 @@ impl TipperRateLimit {
 /// Global treasury for collecting tip fees.
 /// Seeds: ["treasury"]
 #[account]
-#[derive(Default)]
+#[derive(Default, InitSpace)]
 pub struct GlobalTreasury {
     /// Authority that can withdraw from treasury.
     pub authority: Pubkey,
@@ -475,11 +1409,85 @@ pub struct GlobalTreasury {
 
     /// PDA bump seed.
     pub bump: u8,
+
+    /// Total lamports withdrawn (all time).
+    pub total_withdrawn: u64,
+
+    /// Maximum lamports withdrawable within any rolling window of `withdrawal_period_seconds`.
+    pub withdrawal_cap_lamports: u64,
+
+    /// Length of the rolling spending window (seconds).
+    pub withdrawal_period_seconds: i64,
+
+    /// Most recent withdrawals (ring buffer; `withdrawal_cursor` points at the oldest).
+    pub recent_withdrawals: [TreasuryWithdrawal; GlobalTreasury::MAX_RECENT_WITHDRAWALS],
+
+    /// Next slot of `recent_withdrawals` to overwrite.
+    pub withdrawal_cursor: u8,
+
+    /// `memo_hash` of the most recent withdrawal.
+    pub last_memo_hash: [u8; 32],
+
+    /// Account layout version (see `CURRENT_ACCOUNT_VERSION`).
+    pub version: u8,
+
+    /// Reserved for future fields (zeroed).
+    pub reserved: [u8; 32],
 }
 
 impl GlobalTreasury {
-    /// 8 + 32 + 8 + 1 = 49
-    pub const LEN: usize = 8 + 32 + 8 + 1;
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+
+    /// Default spending cap set on `initialize_config` (10 SOL per day).
+    pub const DEFAULT_WITHDRAWAL_CAP_LAMPORTS: u64 = 10_000_000_000;
+    pub const DEFAULT_WITHDRAWAL_PERIOD_SECONDS: i64 = 24 * 60 * 60;
+
+    /// Withdrawals tracked for the rolling window (also the max withdrawals per window).
+    pub const MAX_RECENT_WITHDRAWALS: usize = 8;
+
+    /// Whether `withdrawal` still counts against the cap at `now`.
+    /// A withdrawal stops counting exactly `withdrawal_period_seconds` after it was made.
+    pub fn is_in_window(&self, withdrawal: &TreasuryWithdrawal, now: i64) -> bool {
+        withdrawal.lamports > 0
+            && now
+                < withdrawal
+                    .withdrawn_at
+                    .saturating_add(self.withdrawal_period_seconds)
+    }
+
+    /// Lamports withdrawn within the rolling window ending at `now`.
+    pub fn withdrawn_in_window(&self, now: i64) -> u64 {
+        self.recent_withdrawals
+            .iter()
+            .filter(|w| self.is_in_window(w, now))
+            .fold(0u64, |sum, w| sum.saturating_add(w.lamports))
+    }
+
+    /// Whether the oldest tracked slot is free to reuse at `now`.
+    pub fn has_free_withdrawal_slot(&self, now: i64) -> bool {
+        let oldest = &self.recent_withdrawals[self.withdrawal_cursor as usize];
+        !self.is_in_window(oldest, now)
+    }
+
+    /// Record a withdrawal in the oldest slot. Callers check the cap and the slot first.
+    pub fn push_withdrawal(&mut self, lamports: u64, now: i64) {
+        let slot = self.withdrawal_cursor as usize;
+        self.recent_withdrawals[slot] = TreasuryWithdrawal {
+            withdrawn_at: now,
+            lamports,
+        };
+        self.withdrawal_cursor = ((slot + 1) % Self::MAX_RECENT_WITHDRAWALS) as u8;
+    }
+}
+
+/// A single treasury withdrawal tracked for the rolling spending cap.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace)]
+pub struct TreasuryWithdrawal {
+    /// Unix timestamp of the withdrawal.
+    pub withdrawn_at: i64,
+
+    /// Lamports withdrawn.
+    pub lamports: u64,
 }
 
 // ============================================================================
@@ -493,7 +1501,7 @@ impl GlobalTreasury {
 ///
 /// Seeds: ["donation", donor_wallet, agent_identity_pda, donation_nonce_u64_le]
 #[account]
-#[derive(Default)]
+#[derive(Default, InitSpace)]
 pub struct DonationReceipt {
     /// Wallet that paid the donation.
     pub donor: Pubkey,
@@ -515,11 +1523,16 @@ pub struct DonationReceipt {
 
     /// PDA bump seed.
     pub bump: u8,
+
+    /// Account layout version (see `CURRENT_ACCOUNT_VERSION`).
+    pub version: u8,
+
+    /// Reserved for future fields (zeroed).
+    pub reserved: [u8; 32],
 }
 
 impl DonationReceipt {
-    /// 8 + 32 + 32 + 32 + 32 + 8 + 8 + 1 = 153
-    pub const LEN: usize = 8 + 32 + 32 + 32 + 32 + 8 + 8 + 1;
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
 }
 
 // ============================================================================
@@ -527,7 +1540,7 @@ impl DonationReceipt {
 // ============================================================================
 
 /// Job lifecycle status.
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace)]
 #[repr(u8)]
 pub enum JobStatus {
     /// Open for bids.
@@ -541,10 +1554,12 @@ pub enum JobStatus {
     Completed = 3,
     /// Cancelled by the creator (refund).
     Cancelled = 4,
+    /// Delivery deadline passed without a submission (escrow reclaimed by the creator).
+    Expired = 5,
 }
 
 /// Bid lifecycle status.
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace)]
 #[repr(u8)]
 pub enum JobBidStatus {
     /// Active bid (may be accepted).
@@ -556,6 +1571,8 @@ pub enum JobBidStatus {
     Accepted = 2,
     /// Bid rejected (explicit).
     Rejected = 3,
+    /// Accepted bid whose job expired before delivery.
+    Expired = 4,
 }
 
 /// On-chain job posting (human-created).
@@ -564,7 +1581,7 @@ pub enum JobBidStatus {
 ///
 /// Seeds: ["job", creator_wallet, job_nonce_u64_le]
 #[account]
-#[derive(Default)]
+#[derive(Default, InitSpace)]
 pub struct JobPosting {
     /// Wallet that created the job posting (human).
     pub creator: Pubkey,
@@ -599,12 +1616,48 @@ pub struct JobPosting {
 
     /// PDA bump seed.
     pub bump: u8,
+
+    /// Account layout version (see `CURRENT_ACCOUNT_VERSION`).
+    pub version: u8,
+
+    /// Last unix timestamp at which bids are accepted (0 = no deadline).
+    pub bid_deadline: i64,
+
+    /// Unix timestamp by which the assigned agent must submit (0 = no deadline).
+    pub delivery_deadline: i64,
+
+    /// Seconds the creator has to review a submission before anyone can finalize it
+    /// (migrated jobs get `DEFAULT_REVIEW_WINDOW_SECONDS`).
+    pub review_window_seconds: i64,
+
+    /// Reserved for future fields (zeroed).
+    pub reserved: [u8; 8],
 }
 
 impl JobPosting {
-    /// 8 + 32 + 8 + 32 + 8 + (1+8) + 1 + 32 + 32 + 8 + 8 + 1 = 179
-    /// Option<u64> = 1 (discriminator) + 8 (value) = 9 bytes
-    pub const LEN: usize = 8 + 32 + 8 + 32 + 8 + 9 + 1 + 32 + 32 + 8 + 8 + 1;
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+
+    /// Longest review window a creator may set (30 days).
+    pub const MAX_REVIEW_WINDOW_SECONDS: i64 = 30 * 24 * 60 * 60;
+
+    /// Review window given to jobs migrated from before review windows existed (7 days).
+    pub const DEFAULT_REVIEW_WINDOW_SECONDS: i64 = 7 * 24 * 60 * 60;
+
+    /// Whether the bidding window has closed at `now`.
+    pub fn bidding_closed(&self, now: i64) -> bool {
+        self.bid_deadline != 0 && now > self.bid_deadline
+    }
+
+    /// Whether the delivery deadline has passed at `now`.
+    pub fn delivery_overdue(&self, now: i64) -> bool {
+        self.delivery_deadline != 0 && now > self.delivery_deadline
+    }
+
+    /// Whether the review window for a submission made at `submitted_at` has elapsed at `now`.
+    pub fn review_window_elapsed(&self, submitted_at: i64, now: i64) -> bool {
+        self.review_window_seconds != 0
+            && now > submitted_at.saturating_add(self.review_window_seconds)
+    }
 }
 
 /// Program-owned escrow account for a job.
@@ -612,7 +1665,7 @@ impl JobPosting {
 /// Holds the job budget until completion or cancellation.
 /// Seeds: ["job_escrow", job_posting_pda]
 #[account]
-#[derive(Default)]
+#[derive(Default, InitSpace)]
 pub struct JobEscrow {
     /// Job this escrow belongs to.
     pub job: Pubkey,
@@ -622,11 +1675,16 @@ pub struct JobEscrow {
 
     /// PDA bump seed.
     pub bump: u8,
+
+    /// Account layout version (see `CURRENT_ACCOUNT_VERSION`).
+    pub version: u8,
+
+    /// Reserved for future fields (zeroed).
+    pub reserved: [u8; 32],
 }
 
 impl JobEscrow {
-    /// 8 + 32 + 8 + 1 = 49
-    pub const LEN: usize = 8 + 32 + 8 + 1;
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
 }
 
 /// On-chain bid for a job (agent-authored).
@@ -634,7 +1692,7 @@ impl JobEscrow {
 /// Stores only a hash commitment to the off-chain bid message/details.
 /// Seeds: ["job_bid", job_posting_pda, bidder_agent_identity_pda]
 #[account]
-#[derive(Default)]
+#[derive(Default, InitSpace)]
 pub struct JobBid {
     /// Job being bid on.
     pub job: Pubkey,
@@ -656,11 +1714,16 @@ pub struct JobBid {
 
     /// PDA bump seed.
     pub bump: u8,
+
+    /// Account layout version (see `CURRENT_ACCOUNT_VERSION`).
+    pub version: u8,
+
+    /// Reserved for future fields (zeroed).
+    pub reserved: [u8; 32],
 }
 
 impl JobBid {
-    /// 8 + 32 + 32 + 8 + 32 + 1 + 8 + 1 = 122
-    pub const LEN: usize = 8 + 32 + 32 + 8 + 32 + 1 + 8 + 1;
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
 }
 
 /// Job submission (agent-authored).
@@ -668,7 +1731,7 @@ impl JobBid {
 /// Stores a hash commitment to off-chain deliverable metadata (links, proofs, etc).
 /// Seeds: ["job_submission", job_posting_pda]
 #[account]
-#[derive(Default)]
+#[derive(Default, InitSpace)]
 pub struct JobSubmission {
     /// Job being submitted.
     pub job: Pubkey,
@@ -684,11 +1747,16 @@ pub struct JobSubmission {
 
     /// PDA bump seed.
     pub bump: u8,
+
+    /// Account layout version (see `CURRENT_ACCOUNT_VERSION`).
+    pub version: u8,
+
+    /// Reserved for future fields (zeroed).
+    pub reserved: [u8; 32],
 }
 
 impl JobSubmission {
-    /// 8 + 32 + 32 + 32 + 8 + 1 = 113
-    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 1;
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
 }
 
 // ============================================================================
@@ -699,7 +1767,7 @@ impl JobSubmission {
 ///
 /// Seeds: ["econ"]
 #[account]
-#[derive(Default)]
+#[derive(Default, InitSpace)]
 pub struct EconomicsConfig {
     /// Authority allowed to update policy values.
     pub authority: Pubkey,
@@ -715,18 +1783,78 @@ pub struct EconomicsConfig {
 
     /// PDA bump seed.
     pub bump: u8,
+
+    /// Upper bound for per-enclave post/comment fees (lamports).
+    pub max_enclave_fee_lamports: u64,
+
+    /// Minimum delay between queueing an economics change and executing it (seconds).
+    pub change_delay_seconds: i64,
+
+    /// Account layout version (see `CURRENT_ACCOUNT_VERSION`).
+    pub version: u8,
+
+    /// Reserved for future fields (zeroed).
+    pub reserved: [u8; 32],
 }
 
 impl EconomicsConfig {
-    /// 8 + 32 + 8 + 2 + 8 + 1 = 59
-    pub const LEN: usize = 8 + 32 + 8 + 2 + 8 + 1;
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+
+    pub const DEFAULT_CHANGE_DELAY_SECONDS: i64 = 24 * 60 * 60;
+
+    /// Lower bound for `change_delay_seconds`, so a queued change is always observable.
+    pub const MIN_CHANGE_DELAY_SECONDS: i64 = 60 * 60;
+}
+
+/// Queued economics change, executable once `effective_at` has passed.
+///
+/// Seeds: ["econ_change"]
+#[account]
+#[derive(Default, InitSpace)]
+pub struct PendingEconomicsChange {
+    /// Wallet that queued the change (receives rent on execute/cancel).
+    pub proposer: Pubkey,
+
+    /// New `EconomicsConfig.agent_mint_fee_lamports`.
+    pub agent_mint_fee_lamports: u64,
+
+    /// New `EconomicsConfig.max_agents_per_wallet`.
+    pub max_agents_per_wallet: u16,
+
+    /// New `EconomicsConfig.recovery_timelock_seconds`.
+    pub recovery_timelock_seconds: i64,
+
+    /// New `EconomicsConfig.max_enclave_fee_lamports`.
+    pub max_enclave_fee_lamports: u64,
+
+    /// New `EconomicsConfig.change_delay_seconds`.
+    pub change_delay_seconds: i64,
+
+    /// Unix timestamp when the change was queued.
+    pub proposed_at: i64,
+
+    /// Unix timestamp from which the change can be executed.
+    pub effective_at: i64,
+
+    /// PDA bump seed.
+    pub bump: u8,
+
+    /// Account layout version (see `CURRENT_ACCOUNT_VERSION`).
+    pub version: u8,
+
+    /// Reserved for future fields (zeroed).
+    pub reserved: [u8; 32],
+}
+
+impl PendingEconomicsChange {
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
 }
 
 /// Per-wallet agent counter to enforce a lifetime cap.
 ///
 /// Seeds: ["owner_counter", owner_wallet]
 #[account]
-#[derive(Default)]
+#[derive(Default, InitSpace)]
 pub struct OwnerAgentCounter {
     /// Owner wallet this counter belongs to.
     pub owner: Pubkey,
@@ -736,18 +1864,23 @@ pub struct OwnerAgentCounter {
 
     /// PDA bump seed.
     pub bump: u8,
+
+    /// Account layout version (see `CURRENT_ACCOUNT_VERSION`).
+    pub version: u8,
+
+    /// Reserved for future fields (zeroed).
+    pub reserved: [u8; 32],
 }
 
 impl OwnerAgentCounter {
-    /// 8 + 32 + 2 + 1 = 43
-    pub const LEN: usize = 8 + 32 + 2 + 1;
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
 }
 
 /// Owner-based signer recovery request (timelocked).
 ///
 /// Seeds: ["recovery", agent_identity_pda]
 #[account]
-#[derive(Default)]
+#[derive(Default, InitSpace)]
 pub struct AgentSignerRecovery {
     /// Agent being recovered.
     pub agent: Pubkey,
@@ -766,9 +1899,198 @@ pub struct AgentSignerRecovery {
 
     /// PDA bump seed.
     pub bump: u8,
+
+    /// Account layout version (see `CURRENT_ACCOUNT_VERSION`).
+    pub version: u8,
+
+    /// Reserved for future fields (zeroed).
+    pub reserved: [u8; 32],
 }
 
 impl AgentSignerRecovery {
-    /// 8 + 32 + 32 + 32 + 8 + 8 + 1 = 121
-    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 8 + 1;
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+}
+
+/// Owner-initiated entry retraction request (timelocked).
+///
+/// Lets the owner wallet tombstone an entry without the agent signer, after the
+/// `EconomicsConfig.recovery_timelock_seconds` delay (same delay as signer recovery).
+///
+/// Seeds: ["retraction", post_anchor_pda]
+#[account]
+#[derive(Default, InitSpace)]
+pub struct EntryRetractionRequest {
+    /// Entry being retracted (PostAnchor PDA).
+    pub entry: Pubkey,
+
+    /// Agent that authored the entry.
+    pub agent: Pubkey,
+
+    /// Owner wallet that can execute the retraction.
+    pub owner: Pubkey,
+
+    /// Unix timestamp when the request was created.
+    pub requested_at: i64,
+
+    /// Unix timestamp when the retraction becomes executable.
+    pub ready_at: i64,
+
+    /// PDA bump seed.
+    pub bump: u8,
+
+    /// Account layout version (see `CURRENT_ACCOUNT_VERSION`).
+    pub version: u8,
+
+    /// Reserved for future fields (zeroed).
+    pub reserved: [u8; 32],
+}
+
+impl EntryRetractionRequest {
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn serialized_len<T: AccountSerialize>(account: &T) -> usize {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        data.len()
+    }
+
+    macro_rules! assert_default_len {
+        ($($ty:ty),* $(,)?) => {
+            $(
+                assert_eq!(
+                    serialized_len(&<$ty>::default()),
+                    <$ty>::LEN,
+                    concat!(stringify!($ty), "::LEN does not match its serialized size")
+                );
+            )*
+        };
+    }
+
+    #[test]
+    fn len_matches_serialized_default_for_every_account() {
+        assert_default_len!(
+            ProgramConfig,
+            AdminRoles,
+            AuthorityTransfer,
+            AgentIdentity,
+            AgentVault,
+            PostAnchor,
+            EntryRevision,
+            AgentArchive,
+            ReputationVote,
+            VotingConfig,
+            Enclave,
+            EnclaveModerator,
+            EnclaveBan,
+            ModerationLog,
+            EnclaveMembership,
+            EnclaveTreasury,
+            RewardsEpoch,
+            RewardsClaimReceipt,
+            EnclaveCouncil,
+            RewardsProposal,
+            TipAnchor,
+            TipEscrow,
+            TipperRateLimit,
+            GlobalTreasury,
+            DonationReceipt,
+            JobEscrow,
+            JobBid,
+            JobSubmission,
+            EconomicsConfig,
+            PendingEconomicsChange,
+            OwnerAgentCounter,
+            AgentSignerRecovery,
+            EntryRetractionRequest,
+        );
+    }
+
+    #[test]
+    fn len_covers_optional_fields_when_set() {
+        // `Option` fields serialize to 1 byte when `None`; `LEN` must cover `Some`.
+        let job = JobPosting {
+            buy_it_now_lamports: Some(u64::MAX),
+            ..Default::default()
+        };
+        assert_eq!(serialized_len(&job), JobPosting::LEN);
+        assert_eq!(serialized_len(&JobPosting::default()), JobPosting::LEN - 8);
+    }
+
+    #[test]
+    fn post_tree_len_matches_zero_copy_layout() {
+        // discriminator + enclave + root + filled_subtrees + chunk_roots + recent_chunk_roots
+        // + next_index + recent_cursor + bump + padding (no implicit padding allowed).
+        let fields = 32 + 32 + 32 * PostTree::DEPTH + 32 * PostTree::CHUNK_COUNT;
+        let tail = 32 * PostTree::ROOT_HISTORY + 4 + 1 + 1 + 2;
+        assert_eq!(PostTree::LEN, 8 + fields + tail);
+    }
+
+    #[test]
+    fn council_counts_distinct_member_signers() {
+        let members = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        let outsider = Pubkey::new_unique();
+        let mut council = EnclaveCouncil {
+            member_count: 2,
+            ..Default::default()
+        };
+        council.members[..3].copy_from_slice(&members);
+
+        assert_eq!(council.count_member_signers(&[]), 0);
+        assert_eq!(council.count_member_signers(&[members[0], members[0]]), 1);
+        assert_eq!(
+            council.count_member_signers(&[members[1], outsider, members[0]]),
+            2
+        );
+        // Entries past `member_count` are not members.
+        assert_eq!(council.count_member_signers(&[members[2]]), 0);
+    }
+
+    #[test]
+    fn treasury_window_rolls_instead_of_resetting() {
+        let period = GlobalTreasury::DEFAULT_WITHDRAWAL_PERIOD_SECONDS;
+        let mut treasury = GlobalTreasury {
+            withdrawal_cap_lamports: 100,
+            withdrawal_period_seconds: period,
+            ..Default::default()
+        };
+
+        // Spend the whole cap just before a fixed period would have rolled over.
+        treasury.push_withdrawal(100, period - 1);
+        // Right after that boundary the earlier withdrawal still counts.
+        assert_eq!(treasury.withdrawn_in_window(period), 100);
+        assert_eq!(treasury.withdrawn_in_window(2 * period - 2), 100);
+        // It stops counting exactly one period after it was made.
+        assert_eq!(treasury.withdrawn_in_window(2 * period - 1), 0);
+    }
+
+    #[test]
+    fn treasury_window_limits_withdrawal_count() {
+        let mut treasury = GlobalTreasury {
+            withdrawal_cap_lamports: u64::MAX,
+            withdrawal_period_seconds: 60,
+            ..Default::default()
+        };
+
+        for i in 0..GlobalTreasury::MAX_RECENT_WITHDRAWALS as i64 {
+            assert!(treasury.has_free_withdrawal_slot(i));
+            treasury.push_withdrawal(1, i);
+        }
+        // Every slot is still inside the window, so the oldest cannot be dropped.
+        assert!(!treasury.has_free_withdrawal_slot(59));
+        assert_eq!(treasury.withdrawn_in_window(59), 8);
+        // Once the oldest expires its slot is reused.
+        assert!(treasury.has_free_withdrawal_slot(60));
+        treasury.push_withdrawal(5, 60);
+        assert_eq!(treasury.withdrawal_cursor, 1);
+        assert_eq!(treasury.withdrawn_in_window(60), 7 + 5);
+    }
 }
@@ -16,10 +16,29 @@ import BN from "bn.js";
 const SIGN_DOMAIN = Buffer.from("WUNDERLAND_SOL_V2");
 const ACTION_CREATE_ENCLAVE = 1;
 const ACTION_ANCHOR_POST = 2;
+const ACTION_ANCHOR_COMMENT = 3;
 const ACTION_CAST_VOTE = 4;
 const ACTION_PLACE_JOB_BID = 6;
 const ACTION_WITHDRAW_JOB_BID = 7;
 const ACTION_SUBMIT_JOB = 8;
+const ACTION_CHANGE_VOTE = 9;
+const ACTION_RETRACT_VOTE = 10;
+const ACTION_RETRACT_ENTRY = 11;
+const ACTION_REVISE_ENTRY = 12;
+const ACTION_ARCHIVE_ENTRY = 13;
+const ACTION_ANCHOR_COMPRESSED_POST = 14;
+const ACTION_ANCHOR_COMPRESSED_COMMENT = 15;
+const ACTION_CAST_COMPRESSED_VOTE = 16;
+const ACTION_ANCHOR_REPOST = 17;
+const ACTION_BAN_AGENT = 18;
+const ACTION_UNBAN_AGENT = 19;
+const ACTION_SET_ENTRY_HIDDEN = 20;
+const ACTION_JOIN_ENCLAVE = 21;
+const ACTION_CANCEL_ENCLAVE_MEMBERSHIP = 22;
+const ACTION_CHANGE_COMPRESSED_VOTE = 23;
+const ACTION_RETRACT_COMPRESSED_VOTE = 24;
+const POST_TREE_CHUNK_DEPTH = 12;
+const STAKE_LOCK_SECONDS = 7 * 24 * 60 * 60;
 const MERKLE_DOMAIN = Buffer.from("WUNDERLAND_REWARDS_V1");
 
 describe("wunderland-sol", () => {
@@ -86,6 +105,13 @@ describe("wunderland-sol", () => {
     );
   }
 
+  function deriveVotingConfigPDA() {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("voting")],
+      program.programId
+    );
+  }
+
   function deriveProgramDataPDA() {
     return PublicKey.findProgramAddressSync(
       [program.programId.toBuffer()],
@@ -234,6 +260,195 @@ describe("wunderland-sol", () => {
     );
   }
 
+  function deriveRetractionPDA(postPda: PublicKey) {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("retraction"), postPda.toBuffer()],
+      program.programId
+    );
+  }
+
+  function deriveRevisionPDA(postPda: PublicKey, revision: number) {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("revision"), postPda.toBuffer(), u32LE(revision)],
+      program.programId
+    );
+  }
+
+  function deriveArchivePDA(agentPda: PublicKey) {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("archive"), agentPda.toBuffer()],
+      program.programId
+    );
+  }
+
+  function i64LE(value: number): Buffer {
+    const buf = Buffer.alloc(8);
+    buf.writeBigInt64LE(BigInt(value));
+    return buf;
+  }
+
+  function entryKindByte(kind: any): number {
+    if (kind.post) return 0;
+    if (kind.comment) return 1;
+    if (kind.repost) return 2;
+    return 3; // quote
+  }
+
+  function archiveLeafHash(entryPda: PublicKey, post: any): Buffer {
+    return createHash("sha256")
+      .update(
+        Buffer.concat([
+          Buffer.from("WUNDERLAND_ARCHIVE_V1"),
+          entryPda.toBuffer(),
+          post.agent.toBuffer(),
+          post.enclave.toBuffer(),
+          Buffer.from([entryKindByte(post.kind)]),
+          post.replyTo.toBuffer(),
+          u32LE(post.postIndex),
+          Buffer.from(post.contentHash),
+          Buffer.from(post.manifestHash),
+          u32LE(post.upvotes),
+          u32LE(post.downvotes),
+          u64LE(post.upvoteWeight.toNumber()),
+          u64LE(post.downvoteWeight.toNumber()),
+          u32LE(post.commentCount),
+          i64LE(post.timestamp.toNumber()),
+          u64LE(post.createdSlot.toNumber()),
+          Buffer.from([post.status.active ? 0 : 1]),
+          i64LE(post.retractedAt.toNumber()),
+          u32LE(post.revision),
+          Buffer.from([post.hidden ? 1 : 0]),
+          u32LE(post.repostCount),
+          u16LE(post.depth),
+          post.threadRoot.toBuffer(),
+        ])
+      )
+      .digest();
+  }
+
+  function derivePostTreePDA(enclave: PublicKey) {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("post_tree"), enclave.toBuffer()],
+      program.programId
+    );
+  }
+
+  function compressedBodyHash(entry: {
+    kind: number;
+    replyTo: Buffer;
+    entryIndex: number;
+    contentHash: number[];
+    manifestHash: number[];
+    timestamp: number;
+    slot: number;
+  }): Buffer {
+    return createHash("sha256")
+      .update(
+        Buffer.concat([
+          Buffer.from([entry.kind]),
+          entry.replyTo,
+          u32LE(entry.entryIndex),
+          Buffer.from(entry.contentHash),
+          Buffer.from(entry.manifestHash),
+          i64LE(entry.timestamp),
+          u64LE(entry.slot),
+        ])
+      )
+      .digest();
+  }
+
+  function compressedLeaf(enclave: PublicKey, agent: PublicKey, bodyHash: Buffer): Buffer {
+    return createHash("sha256")
+      .update(
+        Buffer.concat([
+          Buffer.from("WUNDERLAND_COMPRESSED_ENTRY_V1"),
+          enclave.toBuffer(),
+          agent.toBuffer(),
+          bodyHash,
+        ])
+      )
+      .digest();
+  }
+
+  /** Proof of `index` within its chunk (2^POST_TREE_CHUNK_DEPTH leaves, zero-padded). */
+  function chunkProof(leaves: Buffer[], index: number): number[][] {
+    const proof: number[][] = [];
+    let level = leaves;
+    let zero = Buffer.alloc(32);
+    let idx = index;
+    for (let d = 0; d < POST_TREE_CHUNK_DEPTH; d++) {
+      const sibling = level[idx ^ 1] ?? zero;
+      proof.push(Array.from(sibling));
+      const next: Buffer[] = [];
+      for (let i = 0; i < level.length; i += 2) {
+        next.push(merkleParentOrdered(level[i], level[i + 1] ?? zero));
+      }
+      level = next;
+      zero = merkleParentOrdered(zero, zero);
+      idx >>= 1;
+    }
+    return proof;
+  }
+
+  function merkleParentOrdered(left: Buffer, right: Buffer): Buffer {
+    return createHash("sha256").update(Buffer.concat([left, right])).digest();
+  }
+
+  /** Parse `timestamp=` / `slot=` from a compressed-entry log line. */
+  async function compressedEntryTimes(signature: string) {
+    const tx = await provider.connection.getTransaction(signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    const line = (tx?.meta?.logMessages ?? []).find((l) => l.includes("leaf_index="))!;
+    return {
+      timestamp: Number(/timestamp=(\d+)/.exec(line)![1]),
+      slot: Number(/slot=(\d+)/.exec(line)![1]),
+    };
+  }
+
+  function deriveBanPDA(enclave: PublicKey, agentPda: PublicKey) {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("ban"), enclave.toBuffer(), agentPda.toBuffer()],
+      program.programId
+    );
+  }
+
+  function deriveModeratorPDA(enclave: PublicKey, agentPda: PublicKey) {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("moderator"), enclave.toBuffer(), agentPda.toBuffer()],
+      program.programId
+    );
+  }
+
+  function deriveMembershipPDA(enclave: PublicKey, agentPda: PublicKey) {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("membership"), enclave.toBuffer(), agentPda.toBuffer()],
+      program.programId
+    );
+  }
+
+  function deriveCouncilPDA(enclave: PublicKey) {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("council"), enclave.toBuffer()],
+      program.programId
+    );
+  }
+
+  function deriveRewardsProposalPDA(council: PublicKey, proposalId: number) {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("rewards_proposal"), council.toBuffer(), u64LE(proposalId)],
+      program.programId
+    );
+  }
+
+  function deriveModerationLogPDA(enclave: PublicKey, index: number) {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("modlog"), enclave.toBuffer(), u64LE(index)],
+      program.programId
+    );
+  }
+
   function buildAgentMessage(
     action: number,
     agentPda: PublicKey,
@@ -261,6 +476,17 @@ describe("wunderland-sol", () => {
     return buf;
   }
 
+  async function voteNonceLE(agentPda: PublicKey): Promise<Buffer> {
+    const agent = await program.account.agentIdentity.fetch(agentPda);
+    return u64LE(agent.voteNonce.toNumber());
+  }
+
+  function u16LE(value: number): Buffer {
+    const buf = Buffer.alloc(2);
+    buf.writeUInt16LE(value);
+    return buf;
+  }
+
   function u32LE(value: number): Buffer {
     const buf = Buffer.alloc(4);
     buf.writeUInt32LE(value);
@@ -296,6 +522,7 @@ describe("wunderland-sol", () => {
   let configPda: PublicKey;
   let treasuryPda: PublicKey;
   let economicsPda: PublicKey;
+  let votingConfigPda: PublicKey;
   let ownerCounterPda: PublicKey;
   let programDataPda: PublicKey;
   let agent1Pda: PublicKey;
@@ -310,6 +537,7 @@ describe("wunderland-sol", () => {
     [configPda] = deriveConfigPDA();
     [treasuryPda] = deriveTreasuryPDA();
     [economicsPda] = deriveEconomicsPDA();
+    [votingConfigPda] = deriveVotingConfigPDA();
     [ownerCounterPda] = deriveOwnerCounterPDA(authority.publicKey);
     [programDataPda] = deriveProgramDataPDA();
     [agent1Pda] = deriveAgentPDA(authority.publicKey, agentId1);
@@ -358,7 +586,25 @@ describe("wunderland-sol", () => {
     expect(econ.authority.toBase58()).to.equal(authority.publicKey.toBase58());
     expect(econ.agentMintFeeLamports.toNumber()).to.equal(50_000_000);
     expect(econ.maxAgentsPerWallet).to.equal(5);
+    expect(econ.maxEnclaveFeeLamports.toNumber()).to.equal(10_000_000);
     expect(econ.recoveryTimelockSeconds.toNumber()).to.equal(5 * 60);
+    expect(econ.changeDelaySeconds.toNumber()).to.equal(24 * 60 * 60);
+  });
+
+  it("initializes voting config (flat curve)", async () => {
+    await program.methods
+      .initializeVotingConfig()
+      .accounts({
+        config: configPda,
+        authority: authority.publicKey,
+        votingConfig: votingConfigPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const voting = await (program.account as any).votingConfig.fetch(votingConfigPda);
+    expect(voting.curve.levelWeights).to.deep.equal([1, 1, 1, 1, 1, 1]);
+    expect(voting.curve.maxVoteWeight).to.equal(1);
   });
 
   it("initializes agent 1 (Athena)", async () => {
@@ -502,9 +748,11 @@ describe("wunderland-sol", () => {
     await program.methods
       .anchorPost(contentHash, manifestHash)
       .accounts({
+        config: configPda,
         postAnchor: post0Pda,
         agentIdentity: agent1Pda,
         enclave: enclavePda,
+        enclaveBan: deriveBanPDA(enclavePda, agent1Pda)[0],
         payer: authority.publicKey,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
         systemProgram: SystemProgram.programId,
@@ -530,6 +778,7 @@ describe("wunderland-sol", () => {
     const payload = Buffer.concat([
       post0Pda.toBuffer(),
       Buffer.from([value]),
+      await voteNonceLE(agent2Pda),
     ]);
     const message = buildAgentMessage(ACTION_CAST_VOTE, agent2Pda, payload);
     const ed25519Ix = createEd25519Ix(agentSigner2, message);
@@ -537,10 +786,13 @@ describe("wunderland-sol", () => {
     await program.methods
       .castVote(value)
       .accounts({
+        config: configPda,
         reputationVote: votePda,
         postAnchor: post0Pda,
         postAgent: agent1Pda,
+        votingConfig: votingConfigPda,
         voterAgent: agent2Pda,
+        voterVault: vault2Pda,
         payer: authority.publicKey,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
         systemProgram: SystemProgram.programId,
@@ -550,129 +802,1562 @@ describe("wunderland-sol", () => {
 
     const vote = await program.account.reputationVote.fetch(votePda);
     expect(vote.value).to.equal(1);
+    expect(vote.weight).to.equal(1);
     expect(vote.voterAgent.toBase58()).to.equal(agent2Pda.toBase58());
 
     const post = await program.account.postAnchor.fetch(post0Pda);
     expect(post.upvotes).to.equal(1);
+    expect(post.upvoteWeight.toNumber()).to.equal(1);
 
     const agent = await program.account.agentIdentity.fetch(agent1Pda);
     expect(agent.reputationScore.toNumber()).to.equal(1);
   });
 
-  it("deposits to vault", async () => {
-    const depositAmount = new BN(LAMPORTS_PER_SOL);
-
+  it("changes and retracts a vote (rent refunded to payer)", async () => {
+    const [votePda] = deriveVotePDA(post0Pda, agent2Pda);
+    // Nonce signed by the original cast_vote (bumped once since).
+    const castNonce = (await program.account.agentIdentity.fetch(agent2Pda)).voteNonce.toNumber() - 1;
+
+    // Flip +1 → -1.
+    const changeMessage = buildAgentMessage(
+      ACTION_CHANGE_VOTE,
+      agent2Pda,
+      Buffer.concat([post0Pda.toBuffer(), Buffer.from([0xff]), await voteNonceLE(agent2Pda)])
+    );
     await program.methods
-      .depositToVault(depositAmount)
+      .changeVote(-1)
       .accounts({
-        agentIdentity: agent1Pda,
-        vault: vault1Pda,
-        depositor: authority.publicKey,
-        systemProgram: SystemProgram.programId,
+        config: configPda,
+        reputationVote: votePda,
+        postAnchor: post0Pda,
+        postAgent: agent1Pda,
+        votingConfig: votingConfigPda,
+        voterAgent: agent2Pda,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
       })
+      .preInstructions([createEd25519Ix(agentSigner2, changeMessage)])
       .rpc();
 
-    const vaultBalance = await provider.connection.getBalance(vault1Pda);
-    expect(vaultBalance).to.be.greaterThan(anchor.web3.LAMPORTS_PER_SOL);
-  });
-
-  it("withdraws from vault", async () => {
-    const withdrawAmount = new BN(500_000_000);
-    const balanceBefore = await provider.connection.getBalance(
-      authority.publicKey
+    let post = await program.account.postAnchor.fetch(post0Pda);
+    expect(post.upvotes).to.equal(0);
+    expect(post.downvotes).to.equal(1);
+    let agent = await program.account.agentIdentity.fetch(agent1Pda);
+    expect(agent.reputationScore.toNumber()).to.equal(-1);
+
+    // Retract (closes the vote PDA).
+    const retractMessage = buildAgentMessage(
+      ACTION_RETRACT_VOTE,
+      agent2Pda,
+      Buffer.concat([post0Pda.toBuffer(), await voteNonceLE(agent2Pda)])
     );
-
     await program.methods
-      .withdrawFromVault(withdrawAmount)
+      .retractVote()
       .accounts({
-        agentIdentity: agent1Pda,
-        vault: vault1Pda,
-        owner: authority.publicKey,
+        reputationVote: votePda,
+        postAnchor: post0Pda,
+        postAgent: agent1Pda,
+        votingConfig: votingConfigPda,
+        voterAgent: agent2Pda,
+        payer: authority.publicKey,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
       })
+      .preInstructions([createEd25519Ix(agentSigner2, retractMessage)])
       .rpc();
 
-    const balanceAfter = await provider.connection.getBalance(
-      authority.publicKey
+    post = await program.account.postAnchor.fetch(post0Pda);
+    expect(post.upvotes).to.equal(0);
+    expect(post.downvotes).to.equal(0);
+    agent = await program.account.agentIdentity.fetch(agent1Pda);
+    expect(agent.reputationScore.toNumber()).to.equal(0);
+    expect(await provider.connection.getAccountInfo(votePda)).to.equal(null);
+
+    // Replaying the original cast_vote signature cannot resurrect the vote.
+    const replayMessage = buildAgentMessage(
+      ACTION_CAST_VOTE,
+      agent2Pda,
+      Buffer.concat([post0Pda.toBuffer(), Buffer.from([1]), u64LE(castNonce)])
     );
-    expect(balanceAfter).to.be.greaterThan(balanceBefore);
+    try {
+      await program.methods
+        .castVote(1)
+        .accounts({
+          config: configPda,
+          reputationVote: votePda,
+          postAnchor: post0Pda,
+          postAgent: agent1Pda,
+          votingConfig: votingConfigPda,
+          voterAgent: agent2Pda,
+          voterVault: vault2Pda,
+          payer: authority.publicKey,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        })
+        .preInstructions([createEd25519Ix(agentSigner2, replayMessage)])
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal("SignatureMessageMismatch");
+    }
   });
 
-  it("donates to agent (receipt + vault)", async () => {
-    const donor = Keypair.generate();
-    const airdropSig = await provider.connection.requestAirdrop(
-      donor.publicKey,
-      2 * LAMPORTS_PER_SOL
-    );
-    await provider.connection.confirmTransaction(airdropSig, "confirmed");
-
-    const donationNonce = 1;
-    const [receiptPda] = deriveDonationReceiptPDA(
-      donor.publicKey,
-      agent1Pda,
-      donationNonce
-    );
+  it("reports time-decayed reputation via view", async () => {
+    const decayed: BN = await program.methods
+      .getDecayedReputation()
+      .accounts({
+        agentIdentity: agent1Pda,
+        votingConfig: votingConfigPda,
+      })
+      .view();
 
-    const donateAmount = new BN(50_000_000); // 0.05 SOL
-    const contextHash = hashContent(`post:${post0Pda.toBase58()}`);
+    // No half-life configured: the decayed score tracks the all-time score.
+    const agent = await program.account.agentIdentity.fetch(agent1Pda);
+    expect(decayed.toNumber()).to.equal(agent.decayedReputation.toNumber());
+    expect(decayed.toNumber()).to.equal(agent.reputationScore.toNumber());
+  });
 
-    const vaultBefore = await provider.connection.getBalance(vault1Pda);
+  it("revises an entry (revision chain keeps previous hashes)", async () => {
+    const before = await program.account.postAnchor.fetch(post0Pda);
+    const contentHash = hashContent("Hello, Wunderland! (typo fixed)");
+    const manifestHash = hashContent("manifest-proof-v2");
+    const reasonHash = hashContent("fix typo");
+    const [revisionPda] = deriveRevisionPDA(post0Pda, 1);
 
+    const payload = Buffer.concat([
+      post0Pda.toBuffer(),
+      u32LE(1),
+      Buffer.from(contentHash),
+      Buffer.from(manifestHash),
+      Buffer.from(reasonHash),
+    ]);
     await program.methods
-      .donateToAgent(donateAmount, contextHash, new BN(donationNonce))
+      .reviseEntry(contentHash, manifestHash, reasonHash)
       .accounts({
-        donor: donor.publicKey,
+        config: configPda,
+        postAnchor: post0Pda,
+        entryRevision: revisionPda,
         agentIdentity: agent1Pda,
-        vault: vault1Pda,
-        receipt: receiptPda,
+        payer: authority.publicKey,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
         systemProgram: SystemProgram.programId,
       })
-      .signers([donor])
+      .preInstructions([
+        createEd25519Ix(agentSigner1, buildAgentMessage(ACTION_REVISE_ENTRY, agent1Pda, payload)),
+      ])
       .rpc();
 
-    const vaultAfter = await provider.connection.getBalance(vault1Pda);
-    expect(vaultAfter - vaultBefore).to.equal(donateAmount.toNumber());
-
-    const receipt = await program.account.donationReceipt.fetch(receiptPda);
-    expect(receipt.donor.toBase58()).to.equal(donor.publicKey.toBase58());
-    expect(receipt.agent.toBase58()).to.equal(agent1Pda.toBase58());
-    expect(receipt.vault.toBase58()).to.equal(vault1Pda.toBase58());
-    expect(receipt.amount.toNumber()).to.equal(donateAmount.toNumber());
-    expect(Buffer.from(receipt.contextHash)).to.deep.equal(Buffer.from(contextHash));
-    expect(receipt.donatedAt.toNumber()).to.be.greaterThan(0);
+    const post = await program.account.postAnchor.fetch(post0Pda);
+    expect(post.revision).to.equal(1);
+    expect(Buffer.from(post.contentHash).equals(Buffer.from(contentHash))).to.equal(true);
+
+    const revision = await (program.account as any).entryRevision.fetch(revisionPda);
+    expect(revision.revision).to.equal(1);
+    expect(Buffer.from(revision.prevContentHash).equals(Buffer.from(before.contentHash))).to.equal(true);
+    expect(Buffer.from(revision.prevManifestHash).equals(Buffer.from(before.manifestHash))).to.equal(true);
+    expect(Buffer.from(revision.reasonHash).equals(Buffer.from(reasonHash))).to.equal(true);
   });
 
-  it("submits a tip (global)", async () => {
-    const tipNonce = 0;
-    const [tipPda] = deriveTipPDA(authority.publicKey, tipNonce);
-    const [escrowPda] = deriveEscrowPDA(tipPda);
-    const [rateLimitPda] = deriveRateLimitPDA(authority.publicKey);
-
-    const tipContentHash = hashContent(
-      "Check out this amazing article about AI agents!"
-    );
-    const tipAmount = new BN(15_000_000); // 0.015 SOL minimum
+  it("retracts an entry (tombstone rejects votes; owner path is timelocked)", async () => {
+    const [post1Pda] = derivePostPDA(agent1Pda, 1);
+    const contentHash = hashContent("A post that will be withdrawn.");
+    const manifestHash = hashContent("manifest-proof-retract");
 
+    const postPayload = Buffer.concat([
+      enclavePda.toBuffer(),
+      Buffer.from([0]), // kind = Post
+      PublicKey.default.toBuffer(),
+      u32LE(1),
+      Buffer.from(contentHash),
+      Buffer.from(manifestHash),
+    ]);
     await program.methods
-      .submitTip(tipContentHash, tipAmount, 0, new BN(tipNonce))
+      .anchorPost(contentHash, manifestHash)
       .accounts({
-        tipper: authority.publicKey,
-        rateLimit: rateLimitPda,
-        tip: tipPda,
-        escrow: escrowPda,
-        targetEnclave: SystemProgram.programId, // global tip
+        config: configPda,
+        postAnchor: post1Pda,
+        agentIdentity: agent1Pda,
+        enclave: enclavePda,
+        enclaveBan: deriveBanPDA(enclavePda, agent1Pda)[0],
+        payer: authority.publicKey,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
         systemProgram: SystemProgram.programId,
       })
+      .preInstructions([
+        createEd25519Ix(agentSigner1, buildAgentMessage(ACTION_ANCHOR_POST, agent1Pda, postPayload)),
+      ])
       .rpc();
 
-    const tip = await program.account.tipAnchor.fetch(tipPda);
-    expect(tip.amount.toNumber()).to.equal(15_000_000);
-    expect(tip.tipper.toBase58()).to.equal(authority.publicKey.toBase58());
-    expect(tip.targetEnclave.toBase58()).to.equal(
-      SystemProgram.programId.toBase58()
-    );
-    expect(JSON.stringify(tip.status)).to.include("pending");
+    // Owner path: request is timelocked (default 5 minutes), then canceled.
+    const [retractionPda] = deriveRetractionPDA(post1Pda);
+    await program.methods
+      .requestRetractEntry()
+      .accounts({
+        economics: economicsPda,
+        agentIdentity: agent1Pda,
+        postAnchor: post1Pda,
+        owner: authority.publicKey,
+        retraction: retractionPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
 
-    const escrow = await program.account.tipEscrow.fetch(escrowPda);
+    try {
+      await program.methods
+        .executeRetractEntry()
+        .accounts({
+          agentIdentity: agent1Pda,
+          postAnchor: post1Pda,
+          owner: authority.publicKey,
+          retraction: retractionPda,
+        })
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal("RetractionNotReady");
+    }
+
+    await program.methods
+      .cancelRetractEntry()
+      .accounts({
+        agentIdentity: agent1Pda,
+        owner: authority.publicKey,
+        retraction: retractionPda,
+      })
+      .rpc();
+    expect(await provider.connection.getAccountInfo(retractionPda)).to.equal(null);
+
+    // Agent path: immediate tombstone.
+    await program.methods
+      .retractEntry()
+      .accounts({
+        postAnchor: post1Pda,
+        agentIdentity: agent1Pda,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+      })
+      .preInstructions([
+        createEd25519Ix(
+          agentSigner1,
+          buildAgentMessage(ACTION_RETRACT_ENTRY, agent1Pda, post1Pda.toBuffer())
+        ),
+      ])
+      .rpc();
+
+    const post = await program.account.postAnchor.fetch(post1Pda);
+    expect(post.status).to.deep.equal({ retracted: {} });
+    expect(post.retractedAt.toNumber()).to.be.greaterThan(0);
+    expect(Buffer.from(post.contentHash).equals(Buffer.from(contentHash))).to.equal(true);
+
+    // Votes on a tombstoned entry are rejected.
+    const [votePda] = deriveVotePDA(post1Pda, agent2Pda);
+    const votePayload = Buffer.concat([
+      post1Pda.toBuffer(),
+      Buffer.from([1]),
+      await voteNonceLE(agent2Pda),
+    ]);
+    try {
+      await program.methods
+        .castVote(1)
+        .accounts({
+          config: configPda,
+          reputationVote: votePda,
+          postAnchor: post1Pda,
+          postAgent: agent1Pda,
+          votingConfig: votingConfigPda,
+          voterAgent: agent2Pda,
+          voterVault: vault2Pda,
+          payer: authority.publicKey,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        })
+        .preInstructions([
+          createEd25519Ix(agentSigner2, buildAgentMessage(ACTION_CAST_VOTE, agent2Pda, votePayload)),
+        ])
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal("EntryRetracted");
+    }
+  });
+
+  it("archives an entry (rent refunded, final state committed to agent archive)", async () => {
+    const [post1Pda] = derivePostPDA(agent1Pda, 1);
+    const [archivePda] = deriveArchivePDA(agent1Pda);
+    const finalState = await program.account.postAnchor.fetch(post1Pda);
+    const rentBefore = await provider.connection.getBalance(authority.publicKey);
+
+    const archiveSig = await program.methods
+      .archiveEntry()
+      .accounts({
+        postAnchor: post1Pda,
+        agentArchive: archivePda,
+        agentIdentity: agent1Pda,
+        rentRecipient: authority.publicKey,
+        payer: authority.publicKey,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      })
+      .preInstructions([
+        createEd25519Ix(
+          agentSigner1,
+          buildAgentMessage(ACTION_ARCHIVE_ENTRY, agent1Pda, post1Pda.toBuffer())
+        ),
+      ])
+      .rpc({ commitment: "confirmed" });
+
+    expect(await provider.connection.getAccountInfo(post1Pda)).to.equal(null);
+
+    // Archive PDA rent is paid by the same wallet; the post refund outweighs it and fees.
+    const rentAfter = await provider.connection.getBalance(authority.publicKey);
+    expect(rentAfter).to.be.greaterThan(rentBefore - 1_000_000);
+
+    // Root = sha256(zero_root || leaf) for the first archived entry.
+    const expectedRoot = createHash("sha256")
+      .update(Buffer.concat([Buffer.alloc(32), archiveLeafHash(post1Pda, finalState)]))
+      .digest();
+    const archive = await (program.account as any).agentArchive.fetch(archivePda);
+    expect(archive.archivedCount).to.equal(1);
+    expect(Buffer.from(archive.root).equals(expectedRoot)).to.equal(true);
+
+    // The logged preimage hashes to the folded leaf.
+    const tx = await provider.connection.getTransaction(archiveSig, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    const prefix = "Program log: Archive leaf preimage: ";
+    const preimageLog = tx!.meta!.logMessages!.find((l) => l.startsWith(prefix))!;
+    const loggedLeaf = createHash("sha256")
+      .update(Buffer.from(preimageLog.slice(prefix.length), "hex"))
+      .digest();
+    expect(loggedLeaf.equals(archiveLeafHash(post1Pda, finalState))).to.equal(true);
+  });
+
+  it("anchors compressed entries and votes by leaf proof (change + retract)", async () => {
+    const [postTreePda] = derivePostTreePDA(enclavePda);
+    await program.methods
+      .initializePostTree()
+      .accounts({
+        enclave: enclavePda,
+        postTree: postTreePda,
+        payer: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    // Compressed post by agent1.
+    const agent1 = await program.account.agentIdentity.fetch(agent1Pda);
+    const postIndex = agent1.totalEntries;
+    const postContent = hashContent("compressed post");
+    const postManifest = hashContent("compressed manifest");
+    const postPayload = Buffer.concat([
+      enclavePda.toBuffer(),
+      Buffer.from([0]),
+      Buffer.alloc(32),
+      u32LE(postIndex),
+      Buffer.from(postContent),
+      Buffer.from(postManifest),
+    ]);
+    const postSig = await program.methods
+      .anchorCompressedPost(postContent, postManifest)
+      .accounts({
+        config: configPda,
+        postTree: postTreePda,
+        agentIdentity: agent1Pda,
+        enclave: enclavePda,
+        enclaveBan: deriveBanPDA(enclavePda, agent1Pda)[0],
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+      })
+      .preInstructions([
+        createEd25519Ix(
+          agentSigner1,
+          buildAgentMessage(ACTION_ANCHOR_COMPRESSED_POST, agent1Pda, postPayload)
+        ),
+      ])
+      .rpc({ commitment: "confirmed" });
+
+    const postTimes = await compressedEntryTimes(postSig);
+    const postBody = compressedBodyHash({
+      kind: 0,
+      replyTo: Buffer.alloc(32),
+      entryIndex: postIndex,
+      contentHash: postContent,
+      manifestHash: postManifest,
+      ...postTimes,
+    });
+    const postLeaf = compressedLeaf(enclavePda, agent1Pda, postBody);
+    const leaves = [postLeaf];
+
+    // Compressed comment by agent2, proving the parent leaf.
+    const agent2 = await program.account.agentIdentity.fetch(agent2Pda);
+    const commentContent = hashContent("compressed reply");
+    const commentManifest = hashContent("compressed reply manifest");
+    const commentPayload = Buffer.concat([
+      enclavePda.toBuffer(),
+      postLeaf,
+      Buffer.from([1]),
+      u32LE(agent2.totalEntries),
+      Buffer.from(commentContent),
+      Buffer.from(commentManifest),
+    ]);
+    const commentSig = await program.methods
+      .anchorCompressedComment(
+        commentContent,
+        commentManifest,
+        Array.from(postLeaf),
+        0,
+        chunkProof(leaves, 0)
+      )
+      .accounts({
+        config: configPda,
+        postTree: postTreePda,
+        agentIdentity: agent2Pda,
+        enclave: enclavePda,
+        enclaveBan: deriveBanPDA(enclavePda, agent2Pda)[0],
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+      })
+      .preInstructions([
+        createEd25519Ix(
+          agentSigner2,
+          buildAgentMessage(ACTION_ANCHOR_COMPRESSED_COMMENT, agent2Pda, commentPayload)
+        ),
+      ])
+      .rpc({ commitment: "confirmed" });
+
+    const commentBody = compressedBodyHash({
+      kind: 1,
+      replyTo: postLeaf,
+      entryIndex: agent2.totalEntries,
+      contentHash: commentContent,
+      manifestHash: commentManifest,
+      ...(await compressedEntryTimes(commentSig)),
+    });
+    leaves.push(compressedLeaf(enclavePda, agent2Pda, commentBody));
+
+    const tree = await (program.account as any).postTree.fetch(postTreePda);
+    expect(tree.nextIndex).to.equal(2);
+
+    // Compressed vote by agent2 on agent1's post (proof against the updated chunk).
+    const [votePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vote"), postLeaf, agent2Pda.toBuffer()],
+      program.programId
+    );
+    const repBefore = (await program.account.agentIdentity.fetch(agent1Pda)).reputationScore;
+    const votePayload = Buffer.concat([postLeaf, Buffer.from([1]), await voteNonceLE(agent2Pda)]);
+    await program.methods
+      .castCompressedVote(1, Array.from(postLeaf), Array.from(postBody), 0, chunkProof(leaves, 0))
+      .accounts({
+        config: configPda,
+        reputationVote: votePda,
+        postTree: postTreePda,
+        postAgent: agent1Pda,
+        votingConfig: votingConfigPda,
+        voterAgent: agent2Pda,
+        voterVault: vault2Pda,
+        payer: authority.publicKey,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      })
+      .preInstructions([
+        createEd25519Ix(
+          agentSigner2,
+          buildAgentMessage(ACTION_CAST_COMPRESSED_VOTE, agent2Pda, votePayload)
+        ),
+      ])
+      .rpc();
+
+    const repAfter = (await program.account.agentIdentity.fetch(agent1Pda)).reputationScore;
+    expect(repAfter.toNumber() - repBefore.toNumber()).to.equal(1);
+
+    // Flip +1 → -1 (no proof; the body hash re-binds the author).
+    const changeMessage = buildAgentMessage(
+      ACTION_CHANGE_COMPRESSED_VOTE,
+      agent2Pda,
+      Buffer.concat([postLeaf, Buffer.from([0xff]), await voteNonceLE(agent2Pda)])
+    );
+    await program.methods
+      .changeCompressedVote(-1, Array.from(postLeaf), Array.from(postBody))
+      .accounts({
+        config: configPda,
+        reputationVote: votePda,
+        postTree: postTreePda,
+        postAgent: agent1Pda,
+        votingConfig: votingConfigPda,
+        voterAgent: agent2Pda,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+      })
+      .preInstructions([createEd25519Ix(agentSigner2, changeMessage)])
+      .rpc();
+
+    const repChanged = (await program.account.agentIdentity.fetch(agent1Pda)).reputationScore;
+    expect(repChanged.toNumber() - repBefore.toNumber()).to.equal(-1);
+
+    // Retract (closes the vote PDA).
+    const retractMessage = buildAgentMessage(
+      ACTION_RETRACT_COMPRESSED_VOTE,
+      agent2Pda,
+      Buffer.concat([postLeaf, await voteNonceLE(agent2Pda)])
+    );
+    await program.methods
+      .retractCompressedVote(Array.from(postLeaf), Array.from(postBody))
+      .accounts({
+        reputationVote: votePda,
+        postTree: postTreePda,
+        postAgent: agent1Pda,
+        votingConfig: votingConfigPda,
+        voterAgent: agent2Pda,
+        payer: authority.publicKey,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+      })
+      .preInstructions([createEd25519Ix(agentSigner2, retractMessage)])
+      .rpc();
+
+    const repRetracted = (await program.account.agentIdentity.fetch(agent1Pda)).reputationScore;
+    expect(repRetracted.toNumber()).to.equal(repBefore.toNumber());
+    expect(await provider.connection.getAccountInfo(votePda)).to.equal(null);
+
+    // Replaying the original cast signature cannot resurrect the vote.
+    try {
+      await program.methods
+        .castCompressedVote(1, Array.from(postLeaf), Array.from(postBody), 0, chunkProof(leaves, 0))
+        .accounts({
+          config: configPda,
+          reputationVote: votePda,
+          postTree: postTreePda,
+          postAgent: agent1Pda,
+          votingConfig: votingConfigPda,
+          voterAgent: agent2Pda,
+          voterVault: vault2Pda,
+          payer: authority.publicKey,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        })
+        .preInstructions([
+          createEd25519Ix(
+            agentSigner2,
+            buildAgentMessage(ACTION_CAST_COMPRESSED_VOTE, agent2Pda, votePayload)
+          ),
+        ])
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal("SignatureMessageMismatch");
+    }
+  });
+
+  it("quote-posts an entry across enclaves (repost_count tracked)", async () => {
+    // Second enclave (created by agent2).
+    const nameHash = hashContent("cross-posting-enclave");
+    const metadataHash = hashContent("cross-posting-enclave-metadata");
+    const [otherEnclavePda] = deriveEnclavePDA(nameHash);
+    const [otherTreasuryPda] = deriveEnclaveTreasuryPDA(otherEnclavePda);
+    await program.methods
+      .createEnclave(nameHash, metadataHash)
+      .accounts({
+        config: configPda,
+        creatorAgent: agent2Pda,
+        enclave: otherEnclavePda,
+        enclaveTreasury: otherTreasuryPda,
+        payer: authority.publicKey,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      })
+      .preInstructions([
+        createEd25519Ix(
+          agentSigner2,
+          buildAgentMessage(
+            ACTION_CREATE_ENCLAVE,
+            agent2Pda,
+            Buffer.concat([Buffer.from(nameHash), Buffer.from(metadataHash)])
+          )
+        ),
+      ])
+      .rpc();
+
+    const agent2 = await program.account.agentIdentity.fetch(agent2Pda);
+    const [quotePda] = derivePostPDA(agent2Pda, agent2.totalEntries);
+    const contentHash = hashContent("Worth reading, from the other enclave.");
+    const manifestHash = hashContent("quote-manifest");
+    const payload = Buffer.concat([
+      otherEnclavePda.toBuffer(),
+      Buffer.from([3]), // kind = Quote
+      post0Pda.toBuffer(),
+      u32LE(agent2.totalEntries),
+      Buffer.from(contentHash),
+      Buffer.from(manifestHash),
+    ]);
+
+    await program.methods
+      .anchorRepost({ quote: {} }, contentHash, manifestHash)
+      .accounts({
+        config: configPda,
+        repostAnchor: quotePda,
+        agentIdentity: agent2Pda,
+        enclave: otherEnclavePda,
+        enclaveBan: deriveBanPDA(otherEnclavePda, agent2Pda)[0],
+        quotedEntry: post0Pda,
+        payer: authority.publicKey,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      })
+      .preInstructions([
+        createEd25519Ix(agentSigner2, buildAgentMessage(ACTION_ANCHOR_REPOST, agent2Pda, payload)),
+      ])
+      .rpc();
+
+    const quote = await program.account.postAnchor.fetch(quotePda);
+    expect(quote.kind).to.deep.equal({ quote: {} });
+    expect(quote.replyTo.toBase58()).to.equal(post0Pda.toBase58());
+    expect(quote.enclave.toBase58()).to.equal(otherEnclavePda.toBase58());
+
+    const original = await program.account.postAnchor.fetch(post0Pda);
+    expect(original.repostCount).to.equal(1);
+  });
+
+  it("tracks thread root/depth for comments and enforces the enclave depth limit", async () => {
+    const anchorComment = async (
+      agentPda: PublicKey,
+      signer: Keypair,
+      parentPda: PublicKey,
+      threadRoot: PublicKey | null,
+      text: string
+    ) => {
+      const agent = await program.account.agentIdentity.fetch(agentPda);
+      const [commentPda] = derivePostPDA(agentPda, agent.totalEntries);
+      const contentHash = hashContent(text);
+      const manifestHash = hashContent(`${text}-manifest`);
+      const payload = Buffer.concat([
+        enclavePda.toBuffer(),
+        parentPda.toBuffer(),
+        Buffer.from([1]), // kind = Comment
+        u32LE(agent.totalEntries),
+        Buffer.from(contentHash),
+        Buffer.from(manifestHash),
+      ]);
+      await program.methods
+        .anchorComment(contentHash, manifestHash)
+        .accounts({
+          config: configPda,
+          commentAnchor: commentPda,
+          agentIdentity: agentPda,
+          enclave: enclavePda,
+          enclaveBan: deriveBanPDA(enclavePda, agentPda)[0],
+          parentPost: parentPda,
+          payer: authority.publicKey,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          systemProgram: SystemProgram.programId,
+          threadRoot,
+        })
+        .preInstructions([
+          createEd25519Ix(signer, buildAgentMessage(ACTION_ANCHOR_COMMENT, agentPda, payload)),
+        ])
+        .rpc();
+      return commentPda;
+    };
+
+    const setMaxDepth = (depth: number) =>
+      program.methods
+        .setEnclaveMaxCommentDepth(depth)
+        .accounts({ enclave: enclavePda, authority: authority.publicKey })
+        .rpc();
+
+    const rootBefore = await program.account.postAnchor.fetch(post0Pda);
+
+    const reply = await anchorComment(agent2Pda, agentSigner2, post0Pda, null, "depth one");
+    const nested = await anchorComment(agent1Pda, agentSigner1, reply, post0Pda, "depth two");
+
+    const nestedEntry = await program.account.postAnchor.fetch(nested);
+    expect(nestedEntry.depth).to.equal(2);
+    expect(nestedEntry.threadRoot.toBase58()).to.equal(post0Pda.toBase58());
+    expect(nestedEntry.replyTo.toBase58()).to.equal(reply.toBase58());
+
+    const root = await program.account.postAnchor.fetch(post0Pda);
+    expect(root.threadReplyCount - rootBefore.threadReplyCount).to.equal(2);
+    expect(root.commentCount - rootBefore.commentCount).to.equal(1);
+
+    // An entry with replies cannot be archived (its replies still reference it).
+    try {
+      await program.methods
+        .archiveEntry()
+        .accounts({
+          postAnchor: reply,
+          agentArchive: deriveArchivePDA(agent2Pda)[0],
+          agentIdentity: agent2Pda,
+          rentRecipient: authority.publicKey,
+          payer: authority.publicKey,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        })
+        .preInstructions([
+          createEd25519Ix(agentSigner2, buildAgentMessage(ACTION_ARCHIVE_ENTRY, agent2Pda, reply.toBuffer())),
+        ])
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal("EntryHasReplies");
+    }
+
+    // With max depth 2, a depth-3 reply is rejected.
+    await setMaxDepth(2);
+    try {
+      await anchorComment(agent2Pda, agentSigner2, nested, post0Pda, "depth three");
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal("CommentDepthExceeded");
+    } finally {
+      await setMaxDepth(0);
+    }
+ 
+    // A vote on the nested reply does not block archiving; its tally is committed.
+    const [nestedVotePda] = deriveVotePDA(nested, agent2Pda);
+    const nestedVotePayload = Buffer.concat([
+      nested.toBuffer(),
+      Buffer.from([1]),
+      await voteNonceLE(agent2Pda),
+    ]);
+    await program.methods
+      .castVote(1)
+      .accounts({
+        config: configPda,
+        reputationVote: nestedVotePda,
+        postAnchor: nested,
+        postAgent: agent1Pda,
+        votingConfig: votingConfigPda,
+        voterAgent: agent2Pda,
+        voterVault: vault2Pda,
+        payer: authority.publicKey,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      })
+      .preInstructions([
+        createEd25519Ix(agentSigner2, buildAgentMessage(ACTION_CAST_VOTE, agent2Pda, nestedVotePayload)),
+      ])
+      .rpc();
+
+    // Threads are archived leaf-first; each archive detaches the entry from its parent and root.
+    const archiveComment = (
+      agentPda: PublicKey,
+      signer: Keypair,
+      entry: PublicKey,
+      parentEntry: PublicKey,
+      threadRoot: PublicKey | null
+    ) =>
+      program.methods
+        .archiveEntry()
+        .accounts({
+          postAnchor: entry,
+          agentArchive: deriveArchivePDA(agentPda)[0],
+          agentIdentity: agentPda,
+          rentRecipient: authority.publicKey,
+          payer: authority.publicKey,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          systemProgram: SystemProgram.programId,
+          parentEntry,
+          threadRoot,
+        })
+        .preInstructions([
+          createEd25519Ix(signer, buildAgentMessage(ACTION_ARCHIVE_ENTRY, agentPda, entry.toBuffer())),
+        ])
+        .rpc();
+
+    const nestedFinal = await program.account.postAnchor.fetch(nested);
+    expect(nestedFinal.upvotes).to.equal(1);
+    const archiveBefore = await (program.account as any).agentArchive.fetch(deriveArchivePDA(agent1Pda)[0]);
+    await archiveComment(agent1Pda, agentSigner1, nested, reply, post0Pda);
+    const archiveAfter = await (program.account as any).agentArchive.fetch(deriveArchivePDA(agent1Pda)[0]);
+    const expectedArchiveRoot = createHash("sha256")
+      .update(Buffer.concat([Buffer.from(archiveBefore.root), archiveLeafHash(nested, nestedFinal)]))
+      .digest();
+    expect(Buffer.from(archiveAfter.root).equals(expectedArchiveRoot)).to.equal(true);
+    expect((await program.account.postAnchor.fetch(reply)).commentCount).to.equal(0);
+
+    await archiveComment(agent2Pda, agentSigner2, reply, post0Pda, null);
+    const rootAfter = await program.account.postAnchor.fetch(post0Pda);
+    expect(rootAfter.threadReplyCount).to.equal(rootBefore.threadReplyCount);
+    expect(rootAfter.commentCount).to.equal(rootBefore.commentCount);
+
+    // The vote on the archived reply is final; its rent goes back to the original payer.
+    await program.methods
+      .closeArchivedVote(agent1Pda, nestedFinal.postIndex)
+      .accounts({
+        reputationVote: nestedVotePda,
+        postAnchor: nested,
+        voterAgent: agent2Pda,
+        payer: authority.publicKey,
+      })
+      .rpc();
+    expect(await provider.connection.getAccountInfo(nestedVotePda)).to.equal(null);
+  });
+
+  it("moderates an enclave (moderators, bans, hidden entries, moderation log)", async () => {
+    const reasonHash = hashContent("spam");
+    const nextLog = async () => {
+      const enclave = await program.account.enclave.fetch(enclavePda);
+      return deriveModerationLogPDA(enclavePda, enclave.moderationCount.toNumber())[0];
+    };
+    // Moderation payloads end with the enclave's current moderation_count (single-use).
+    const moderationIndexLE = async () => {
+      const enclave = await program.account.enclave.fetch(enclavePda);
+      return u64LE(enclave.moderationCount.toNumber());
+    };
+
+    // Owner appoints agent2 as moderator.
+    const [moderatorPda] = deriveModeratorPDA(enclavePda, agent2Pda);
+    const appointLog = await nextLog();
+    await program.methods
+      .addEnclaveModerator(reasonHash)
+      .accounts({
+        enclave: enclavePda,
+        moderatorAgent: agent2Pda,
+        moderator: moderatorPda,
+        moderationLog: appointLog,
+        authority: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    // Moderator bans agent1.
+    const [banPda] = deriveBanPDA(enclavePda, agent1Pda);
+    const banLog = await nextLog();
+    const banPayload = Buffer.concat([
+      enclavePda.toBuffer(),
+      agent1Pda.toBuffer(),
+      Buffer.from(reasonHash),
+      await moderationIndexLE(),
+    ]);
+    await program.methods
+      .banAgentFromEnclave(reasonHash)
+      .accounts({
+        enclave: enclavePda,
+        moderatorAgent: agent2Pda,
+        moderator: moderatorPda,
+        targetAgent: agent1Pda,
+        enclaveBan: banPda,
+        moderationLog: banLog,
+        payer: authority.publicKey,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      })
+      .preInstructions([
+        createEd25519Ix(
+          agentSigner2,
+          buildAgentMessage(ACTION_BAN_AGENT, agent2Pda, banPayload)
+        ),
+      ])
+      .rpc();
+
+    const log = await (program.account as any).moderationLog.fetch(banLog);
+    expect(log.action).to.deep.equal({ ban: {} });
+    expect(log.target.toBase58()).to.equal(agent1Pda.toBase58());
+    expect(Buffer.from(log.reasonHash).equals(Buffer.from(reasonHash))).to.equal(true);
+
+    // Banned agent cannot post in the enclave.
+    const agent1 = await program.account.agentIdentity.fetch(agent1Pda);
+    const [bannedPostPda] = derivePostPDA(agent1Pda, agent1.totalEntries);
+    const contentHash = hashContent("banned post");
+    const manifestHash = hashContent("banned manifest");
+    const postPayload = Buffer.concat([
+      enclavePda.toBuffer(),
+      Buffer.from([0]),
+      PublicKey.default.toBuffer(),
+      u32LE(agent1.totalEntries),
+      Buffer.from(contentHash),
+      Buffer.from(manifestHash),
+    ]);
+    try {
+      await program.methods
+        .anchorPost(contentHash, manifestHash)
+        .accounts({
+          config: configPda,
+          postAnchor: bannedPostPda,
+          agentIdentity: agent1Pda,
+          enclave: enclavePda,
+          enclaveBan: banPda,
+          payer: authority.publicKey,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        })
+        .preInstructions([
+          createEd25519Ix(agentSigner1, buildAgentMessage(ACTION_ANCHOR_POST, agent1Pda, postPayload)),
+        ])
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal("AgentBanned");
+    }
+
+    // Moderator hides post0.
+    const hideLog = await nextLog();
+    await program.methods
+      .setEntryHidden(true, reasonHash)
+      .accounts({
+        enclave: enclavePda,
+        moderatorAgent: agent2Pda,
+        moderator: moderatorPda,
+        postAnchor: post0Pda,
+        moderationLog: hideLog,
+        payer: authority.publicKey,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      })
+      .preInstructions([
+        createEd25519Ix(
+          agentSigner2,
+          buildAgentMessage(
+            ACTION_SET_ENTRY_HIDDEN,
+            agent2Pda,
+            Buffer.concat([
+              enclavePda.toBuffer(),
+              post0Pda.toBuffer(),
+              Buffer.from([1]),
+              Buffer.from(reasonHash),
+              await moderationIndexLE(),
+            ])
+          )
+        ),
+      ])
+      .rpc();
+    expect((await program.account.postAnchor.fetch(post0Pda)).hidden).to.equal(true);
+
+    // Hidden entries cannot be reposted (rejected before the signature is checked).
+    const agent2 = await program.account.agentIdentity.fetch(agent2Pda);
+    try {
+      await program.methods
+        .anchorRepost({ repost: {} }, Array(32).fill(0), Array(32).fill(0))
+        .accounts({
+          repostAnchor: derivePostPDA(agent2Pda, agent2.totalEntries)[0],
+          agentIdentity: agent2Pda,
+          enclave: enclavePda,
+          enclaveBan: deriveBanPDA(enclavePda, agent2Pda)[0],
+          quotedEntry: post0Pda,
+          payer: authority.publicKey,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal("EntryHidden");
+    }
+
+    // Moderator lifts the ban (rent back to the payer).
+    await program.methods
+      .unbanAgentFromEnclave(reasonHash)
+      .accounts({
+        enclave: enclavePda,
+        moderatorAgent: agent2Pda,
+        moderator: moderatorPda,
+        enclaveBan: banPda,
+        rentRecipient: authority.publicKey,
+        moderationLog: await nextLog(),
+        payer: authority.publicKey,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      })
+      .preInstructions([
+        createEd25519Ix(
+          agentSigner2,
+          buildAgentMessage(
+            ACTION_UNBAN_AGENT,
+            agent2Pda,
+            Buffer.concat([
+              enclavePda.toBuffer(),
+              agent1Pda.toBuffer(),
+              Buffer.from(reasonHash),
+              await moderationIndexLE(),
+            ])
+          )
+        ),
+      ])
+      .rpc();
+    expect(await provider.connection.getAccountInfo(banPda)).to.equal(null);
+
+    // The old ban signature cannot be replayed.
+    try {
+      await program.methods
+        .banAgentFromEnclave(reasonHash)
+        .accounts({
+          enclave: enclavePda,
+          moderatorAgent: agent2Pda,
+          moderator: moderatorPda,
+          targetAgent: agent1Pda,
+          enclaveBan: banPda,
+          moderationLog: await nextLog(),
+          payer: authority.publicKey,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        })
+        .preInstructions([
+          createEd25519Ix(agentSigner2, buildAgentMessage(ACTION_BAN_AGENT, agent2Pda, banPayload)),
+        ])
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal("SignatureMessageMismatch");
+    }
+
+    const enclave = await program.account.enclave.fetch(enclavePda);
+    expect(enclave.moderationCount.toNumber()).to.equal(4);
+  });
+
+  it("gates an enclave behind approved memberships (join fee to enclave treasury)", async () => {
+    const joinFee = 1_000_000;
+    const [membershipPda] = deriveMembershipPDA(enclavePda, agent2Pda);
+
+    await program.methods
+      .setEnclaveAccessPolicy({ approvalRequired: {} }, 0, new BN(0), new BN(joinFee))
+      .accounts({ enclave: enclavePda, authority: authority.publicKey })
+      .rpc();
+
+    const postAsAgent2 = async (label: string, membership: PublicKey | null) => {
+      const agent2 = await program.account.agentIdentity.fetch(agent2Pda);
+      const [postPda] = derivePostPDA(agent2Pda, agent2.totalEntries);
+      const contentHash = hashContent(label);
+      const manifestHash = hashContent(`${label} manifest`);
+      const payload = Buffer.concat([
+        enclavePda.toBuffer(),
+        Buffer.from([0]),
+        PublicKey.default.toBuffer(),
+        u32LE(agent2.totalEntries),
+        Buffer.from(contentHash),
+        Buffer.from(manifestHash),
+      ]);
+      await program.methods
+        .anchorPost(contentHash, manifestHash)
+        .accounts({
+          config: configPda,
+          postAnchor: postPda,
+          agentIdentity: agent2Pda,
+          enclave: enclavePda,
+          enclaveBan: deriveBanPDA(enclavePda, agent2Pda)[0],
+          payer: authority.publicKey,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          systemProgram: SystemProgram.programId,
+          membership,
+        })
+        .preInstructions([
+          createEd25519Ix(agentSigner2, buildAgentMessage(ACTION_ANCHOR_POST, agent2Pda, payload)),
+        ])
+        .rpc();
+    };
+
+    try {
+      await postAsAgent2("gated post (no membership)", null);
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal("MembershipRequired");
+    }
+
+    const treasuryBefore = await provider.connection.getBalance(enclaveTreasuryPda);
+    await program.methods
+      .joinEnclave()
+      .accounts({
+        enclave: enclavePda,
+        agentIdentity: agent2Pda,
+        enclaveBan: deriveBanPDA(enclavePda, agent2Pda)[0],
+        membership: membershipPda,
+        enclaveTreasury: enclaveTreasuryPda,
+        votingConfig: votingConfigPda,
+        payer: authority.publicKey,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      })
+      .preInstructions([
+        createEd25519Ix(agentSigner2, buildAgentMessage(ACTION_JOIN_ENCLAVE, agent2Pda, enclavePda.toBuffer())),
+      ])
+      .rpc();
+    // The fee is held by the pending membership, not the treasury.
+    expect(await provider.connection.getBalance(enclaveTreasuryPda)).to.equal(treasuryBefore);
+
+    let membership = await (program.account as any).enclaveMembership.fetch(membershipPda);
+    expect(membership.status).to.deep.equal({ pending: {} });
+    expect(membership.feePaid.toNumber()).to.equal(joinFee);
+
+    try {
+      await postAsAgent2("gated post (pending)", membershipPda);
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal("MembershipNotActive");
+    }
+
+    await program.methods
+      .approveEnclaveMembership()
+      .accounts({
+        enclave: enclavePda,
+        membership: membershipPda,
+        enclaveTreasury: enclaveTreasuryPda,
+        authority: authority.publicKey,
+      })
+      .rpc();
+    membership = await (program.account as any).enclaveMembership.fetch(membershipPda);
+    expect(membership.status).to.deep.equal({ active: {} });
+    const treasuryAfter = await provider.connection.getBalance(enclaveTreasuryPda);
+    expect(treasuryAfter - treasuryBefore).to.equal(joinFee);
+
+    // A rejected request closes the membership and refunds rent + fee to the wallet that paid
+    // them. The enclave has no reputation floor, so the voting config can be omitted.
+    const [rejectedMembershipPda] = deriveMembershipPDA(enclavePda, agent1Pda);
+    const joinAsAgent1 = (payer: Keypair) =>
+      program.methods
+        .joinEnclave()
+        .accounts({
+          enclave: enclavePda,
+          agentIdentity: agent1Pda,
+          enclaveBan: deriveBanPDA(enclavePda, agent1Pda)[0],
+          membership: rejectedMembershipPda,
+          enclaveTreasury: enclaveTreasuryPda,
+          votingConfig: null,
+          payer: payer.publicKey,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        })
+        .preInstructions([
+          createEd25519Ix(agentSigner1, buildAgentMessage(ACTION_JOIN_ENCLAVE, agent1Pda, enclavePda.toBuffer())),
+        ])
+        .signers([payer])
+        .rpc();
+    const relayer = Keypair.generate();
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        SystemProgram.transfer({
+          fromPubkey: authority.publicKey,
+          toPubkey: relayer.publicKey,
+          lamports: LAMPORTS_PER_SOL / 10,
+        })
+      )
+    );
+    const relayerBefore = await provider.connection.getBalance(relayer.publicKey);
+    await joinAsAgent1(relayer);
+    let pending = await (program.account as any).enclaveMembership.fetch(rejectedMembershipPda);
+    expect(pending.payer.toBase58()).to.equal(relayer.publicKey.toBase58());
+    await program.methods
+      .rejectEnclaveMembership()
+      .accounts({
+        enclave: enclavePda,
+        membership: rejectedMembershipPda,
+        agentIdentity: agent1Pda,
+        payer: relayer.publicKey,
+        authority: authority.publicKey,
+      })
+      .rpc();
+    expect(await provider.connection.getAccountInfo(rejectedMembershipPda)).to.equal(null);
+    // Transaction fees are paid by the provider wallet, so the relayer is made whole.
+    expect(await provider.connection.getBalance(relayer.publicKey)).to.equal(relayerBefore);
+    expect(await provider.connection.getBalance(enclaveTreasuryPda)).to.equal(treasuryAfter);
+
+    // The agent can cancel its own pending request; rent + fee go back to the payer.
+    await joinAsAgent1(relayer);
+    pending = await (program.account as any).enclaveMembership.fetch(rejectedMembershipPda);
+    await program.methods
+      .cancelEnclaveMembership()
+      .accounts({
+        membership: rejectedMembershipPda,
+        agentIdentity: agent1Pda,
+        payer: relayer.publicKey,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+      })
+      .preInstructions([
+        createEd25519Ix(
+          agentSigner1,
+          buildAgentMessage(
+            ACTION_CANCEL_ENCLAVE_MEMBERSHIP,
+            agent1Pda,
+            Buffer.concat([enclavePda.toBuffer(), i64LE(pending.joinedAt.toNumber())])
+          )
+        ),
+      ])
+      .rpc();
+    expect(await provider.connection.getAccountInfo(rejectedMembershipPda)).to.equal(null);
+    expect(await provider.connection.getBalance(relayer.publicKey)).to.equal(relayerBefore);
+    expect(await provider.connection.getBalance(enclaveTreasuryPda)).to.equal(treasuryAfter);
+
+    await postAsAgent2("gated post (member)", membershipPda);
+
+    const enclave = await program.account.enclave.fetch(enclavePda);
+    expect(enclave.memberCount).to.equal(1);
+
+    // Re-open the enclave for the remaining tests.
+    await program.methods
+      .setEnclaveAccessPolicy({ open: {} }, 0, new BN(0), new BN(0))
+      .accounts({ enclave: enclavePda, authority: authority.publicKey })
+      .rpc();
+  });
+
+  it("manages enclave lifecycle (metadata, ownership transfer, suspension)", async () => {
+    const newMetadataHash = hashContent("proof-theory-enclave-metadata-v2");
+    await program.methods
+      .updateEnclaveMetadata(newMetadataHash)
+      .accounts({ enclave: enclavePda, authority: authority.publicKey })
+      .rpc();
+    let enclave = await program.account.enclave.fetch(enclavePda);
+    expect(Buffer.from(enclave.metadataHash).equals(Buffer.from(newMetadataHash))).to.equal(true);
+
+    // Two-step ownership transfer (and back).
+    const newOwner = Keypair.generate();
+    await program.methods
+      .transferEnclaveOwnership(newOwner.publicKey)
+      .accounts({ enclave: enclavePda, authority: authority.publicKey })
+      .rpc();
+    await program.methods
+      .acceptEnclaveOwnership()
+      .accounts({ enclave: enclavePda, newOwner: newOwner.publicKey })
+      .signers([newOwner])
+      .rpc();
+    enclave = await program.account.enclave.fetch(enclavePda);
+    expect(enclave.creatorOwner.toBase58()).to.equal(newOwner.publicKey.toBase58());
+    expect(enclave.pendingOwner.toBase58()).to.equal(PublicKey.default.toBase58());
+
+    try {
+      await program.methods
+        .setEnclaveActive(false)
+        .accounts({ enclave: enclavePda, authority: authority.publicKey })
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal("UnauthorizedEnclaveOwner");
+    }
+
+    // The creator agent's membership exemption ends with the transfer.
+    await program.methods
+      .setEnclaveAccessPolicy({ gated: {} }, 0, new BN(0), new BN(0))
+      .accounts({ enclave: enclavePda, authority: newOwner.publicKey })
+      .signers([newOwner])
+      .rpc();
+    const agent1 = await program.account.agentIdentity.fetch(agent1Pda);
+    const contentHash = hashContent("creator post after transfer");
+    const manifestHash = hashContent("creator post after transfer manifest");
+    const payload = Buffer.concat([
+      enclavePda.toBuffer(),
+      Buffer.from([0]),
+      PublicKey.default.toBuffer(),
+      u32LE(agent1.totalEntries),
+      Buffer.from(contentHash),
+      Buffer.from(manifestHash),
+    ]);
+    try {
+      await program.methods
+        .anchorPost(contentHash, manifestHash)
+        .accounts({
+          config: configPda,
+          postAnchor: derivePostPDA(agent1Pda, agent1.totalEntries)[0],
+          agentIdentity: agent1Pda,
+          enclave: enclavePda,
+          enclaveBan: deriveBanPDA(enclavePda, agent1Pda)[0],
+          payer: authority.publicKey,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          systemProgram: SystemProgram.programId,
+          membership: null,
+        })
+        .preInstructions([
+          createEd25519Ix(agentSigner1, buildAgentMessage(ACTION_ANCHOR_POST, agent1Pda, payload)),
+        ])
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal("MembershipRequired");
+    }
+    await program.methods
+      .setEnclaveAccessPolicy({ open: {} }, 0, new BN(0), new BN(0))
+      .accounts({ enclave: enclavePda, authority: newOwner.publicKey })
+      .signers([newOwner])
+      .rpc();
+
+    // The new owner can remove a moderator appointed earlier, but the moderator rent goes
+    // back to the wallet that paid it (newOwner only pays for the moderation log).
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        SystemProgram.transfer({
+          fromPubkey: authority.publicKey,
+          toPubkey: newOwner.publicKey,
+          lamports: 10_000_000,
+        })
+      )
+    );
+    const [moderatorPda] = deriveModeratorPDA(enclavePda, agent2Pda);
+    const removeLog = deriveModerationLogPDA(
+      enclavePda,
+      (await program.account.enclave.fetch(enclavePda)).moderationCount.toNumber()
+    )[0];
+    const removeModerator = (payer: PublicKey) =>
+      program.methods
+        .removeEnclaveModerator(hashContent("rotation"))
+        .accounts({
+          enclave: enclavePda,
+          moderator: moderatorPda,
+          payer,
+          moderationLog: removeLog,
+          authority: newOwner.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([newOwner])
+        .rpc();
+    try {
+      await removeModerator(newOwner.publicKey);
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal("InvalidRentRecipient");
+    }
+    const newOwnerBefore = await provider.connection.getBalance(newOwner.publicKey);
+    await removeModerator(authority.publicKey);
+    expect(await provider.connection.getAccountInfo(moderatorPda)).to.equal(null);
+    const logRent = await provider.connection.getBalance(removeLog);
+    expect(newOwnerBefore - (await provider.connection.getBalance(newOwner.publicKey))).to.equal(
+      logRent
+    );
+
+    await program.methods
+      .transferEnclaveOwnership(authority.publicKey)
+      .accounts({ enclave: enclavePda, authority: newOwner.publicKey })
+      .signers([newOwner])
+      .rpc();
+    await program.methods
+      .acceptEnclaveOwnership()
+      .accounts({ enclave: enclavePda, newOwner: authority.publicKey })
+      .rpc();
+
+    // Admin suspension blocks owner reactivation until lifted.
+    await program.methods
+      .suspendEnclave(true)
+      .accounts({ config: configPda, authority: authority.publicKey, enclave: enclavePda })
+      .rpc();
+    enclave = await program.account.enclave.fetch(enclavePda);
+    expect(enclave.isActive).to.equal(false);
+
+    try {
+      await program.methods
+        .setEnclaveActive(true)
+        .accounts({ enclave: enclavePda, authority: authority.publicKey })
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal("EnclaveSuspended");
+    }
+
+    await program.methods
+      .suspendEnclave(false)
+      .accounts({ config: configPda, authority: authority.publicKey, enclave: enclavePda })
+      .rpc();
+    await program.methods
+      .setEnclaveActive(true)
+      .accounts({ enclave: enclavePda, authority: authority.publicKey })
+      .rpc();
+    enclave = await program.account.enclave.fetch(enclavePda);
+    expect(enclave.isActive).to.equal(true);
+  });
+
+  it("charges per-enclave posting fees into the enclave treasury (capped globally)", async () => {
+    const postFee = 2_000_000;
+
+    try {
+      await program.methods
+        .setEnclaveFees(new BN(10_000_001), new BN(0))
+        .accounts({ enclave: enclavePda, economics: economicsPda, authority: authority.publicKey })
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal("EnclaveFeeTooHigh");
+    }
+
+    await program.methods
+      .setEnclaveFees(new BN(postFee), new BN(0))
+      .accounts({ enclave: enclavePda, economics: economicsPda, authority: authority.publicKey })
+      .rpc();
+
+    const agent1 = await program.account.agentIdentity.fetch(agent1Pda);
+    const [postPda] = derivePostPDA(agent1Pda, agent1.totalEntries);
+    const contentHash = hashContent("paid post");
+    const manifestHash = hashContent("paid post manifest");
+    const payload = Buffer.concat([
+      enclavePda.toBuffer(),
+      Buffer.from([0]),
+      PublicKey.default.toBuffer(),
+      u32LE(agent1.totalEntries),
+      Buffer.from(contentHash),
+      Buffer.from(manifestHash),
+    ]);
+
+    const treasuryBefore = await provider.connection.getBalance(enclaveTreasuryPda);
+    await program.methods
+      .anchorPost(contentHash, manifestHash)
+      .accounts({
+        config: configPda,
+        postAnchor: postPda,
+        agentIdentity: agent1Pda,
+        enclave: enclavePda,
+        enclaveBan: deriveBanPDA(enclavePda, agent1Pda)[0],
+        payer: authority.publicKey,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        systemProgram: SystemProgram.programId,
+        membership: null,
+        enclaveTreasury: enclaveTreasuryPda,
+        economics: economicsPda,
+      })
+      .preInstructions([
+        createEd25519Ix(agentSigner1, buildAgentMessage(ACTION_ANCHOR_POST, agent1Pda, payload)),
+      ])
+      .rpc();
+    const treasuryAfter = await provider.connection.getBalance(enclaveTreasuryPda);
+    expect(treasuryAfter - treasuryBefore).to.equal(postFee);
+
+    // Compressed posts pay the same fee (no rent, but not fee-free).
+    const agent1AfterPost = await program.account.agentIdentity.fetch(agent1Pda);
+    const compressedContent = hashContent("paid compressed post");
+    const compressedManifest = hashContent("paid compressed manifest");
+    const compressedPayload = Buffer.concat([
+      enclavePda.toBuffer(),
+      Buffer.from([0]),
+      Buffer.alloc(32),
+      u32LE(agent1AfterPost.totalEntries),
+      Buffer.from(compressedContent),
+      Buffer.from(compressedManifest),
+    ]);
+    await program.methods
+      .anchorCompressedPost(compressedContent, compressedManifest)
+      .accounts({
+        config: configPda,
+        postTree: derivePostTreePDA(enclavePda)[0],
+        agentIdentity: agent1Pda,
+        enclave: enclavePda,
+        enclaveBan: deriveBanPDA(enclavePda, agent1Pda)[0],
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        membership: null,
+        enclaveTreasury: enclaveTreasuryPda,
+        economics: economicsPda,
+        feePayer: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .preInstructions([
+        createEd25519Ix(
+          agentSigner1,
+          buildAgentMessage(ACTION_ANCHOR_COMPRESSED_POST, agent1Pda, compressedPayload)
+        ),
+      ])
+      .rpc();
+    const treasuryAfterCompressed = await provider.connection.getBalance(enclaveTreasuryPda);
+    expect(treasuryAfterCompressed - treasuryAfter).to.equal(postFee);
+
+    await program.methods
+      .setEnclaveFees(new BN(0), new BN(0))
+      .accounts({ enclave: enclavePda, economics: economicsPda, authority: authority.publicKey })
+      .rpc();
+  });
+
+  it("deposits to vault", async () => {
+    const depositAmount = new BN(LAMPORTS_PER_SOL);
+
+    await program.methods
+      .depositToVault(depositAmount)
+      .accounts({
+        config: configPda,
+        agentIdentity: agent1Pda,
+        vault: vault1Pda,
+        depositor: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const vaultBalance = await provider.connection.getBalance(vault1Pda);
+    expect(vaultBalance).to.be.greaterThan(anchor.web3.LAMPORTS_PER_SOL);
+  });
+
+  it("withdraws from vault", async () => {
+    const withdrawAmount = new BN(500_000_000);
+    const balanceBefore = await provider.connection.getBalance(
+      authority.publicKey
+    );
+
+    await program.methods
+      .withdrawFromVault(withdrawAmount)
+      .accounts({
+        agentIdentity: agent1Pda,
+        votingConfig: votingConfigPda,
+        vault: vault1Pda,
+        owner: authority.publicKey,
+      })
+      .rpc();
+
+    const balanceAfter = await provider.connection.getBalance(
+      authority.publicKey
+    );
+    expect(balanceAfter).to.be.greaterThan(balanceBefore);
+  });
+
+  it("donates to agent (receipt + vault)", async () => {
+    const donor = Keypair.generate();
+    const airdropSig = await provider.connection.requestAirdrop(
+      donor.publicKey,
+      2 * LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdropSig, "confirmed");
+
+    const donationNonce = 1;
+    const [receiptPda] = deriveDonationReceiptPDA(
+      donor.publicKey,
+      agent1Pda,
+      donationNonce
+    );
+
+    const donateAmount = new BN(50_000_000); // 0.05 SOL
+    const contextHash = hashContent(`post:${post0Pda.toBase58()}`);
+
+    const vaultBefore = await provider.connection.getBalance(vault1Pda);
+
+    await program.methods
+      .donateToAgent(donateAmount, contextHash, new BN(donationNonce))
+      .accounts({
+        config: configPda,
+        donor: donor.publicKey,
+        agentIdentity: agent1Pda,
+        vault: vault1Pda,
+        receipt: receiptPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([donor])
+      .rpc();
+
+    const vaultAfter = await provider.connection.getBalance(vault1Pda);
+    expect(vaultAfter - vaultBefore).to.equal(donateAmount.toNumber());
+
+    const receipt = await program.account.donationReceipt.fetch(receiptPda);
+    expect(receipt.donor.toBase58()).to.equal(donor.publicKey.toBase58());
+    expect(receipt.agent.toBase58()).to.equal(agent1Pda.toBase58());
+    expect(receipt.vault.toBase58()).to.equal(vault1Pda.toBase58());
+    expect(receipt.amount.toNumber()).to.equal(donateAmount.toNumber());
+    expect(Buffer.from(receipt.contextHash)).to.deep.equal(Buffer.from(contextHash));
+    expect(receipt.donatedAt.toNumber()).to.be.greaterThan(0);
+  });
+
+  it("submits a tip (global)", async () => {
+    const tipNonce = 0;
+    const [tipPda] = deriveTipPDA(authority.publicKey, tipNonce);
+    const [escrowPda] = deriveEscrowPDA(tipPda);
+    const [rateLimitPda] = deriveRateLimitPDA(authority.publicKey);
+
+    const tipContentHash = hashContent(
+      "Check out this amazing article about AI agents!"
+    );
+    const tipAmount = new BN(15_000_000); // 0.015 SOL minimum
+
+    await program.methods
+      .submitTip(tipContentHash, tipAmount, 0, new BN(tipNonce))
+      .accounts({
+        config: configPda,
+        tipper: authority.publicKey,
+        rateLimit: rateLimitPda,
+        tip: tipPda,
+        escrow: escrowPda,
+        targetEnclave: SystemProgram.programId, // global tip
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const tip = await program.account.tipAnchor.fetch(tipPda);
+    expect(tip.amount.toNumber()).to.equal(15_000_000);
+    expect(tip.tipper.toBase58()).to.equal(authority.publicKey.toBase58());
+    expect(tip.targetEnclave.toBase58()).to.equal(
+      SystemProgram.programId.toBase58()
+    );
+    expect(JSON.stringify(tip.status)).to.include("pending");
+
+    const escrow = await program.account.tipEscrow.fetch(escrowPda);
     expect(escrow.amount.toNumber()).to.equal(15_000_000);
   });
 
@@ -717,6 +2402,7 @@ describe("wunderland-sol", () => {
     await program.methods
       .submitTip(tipContentHash, tipAmount, 0, new BN(tipNonce))
       .accounts({
+        config: configPda,
         tipper: authority.publicKey,
         rateLimit: rateLimitPda,
         tip: tipPda,
@@ -744,6 +2430,127 @@ describe("wunderland-sol", () => {
     expect(enclaveTreasuryAfter - enclaveTreasuryBefore).to.equal(4_500_000);
   });
 
+  it("creates an approved sub-enclave that passes tip revenue up to its parent", async () => {
+    // Parent requires approval and takes 50% of sub-enclave tip shares.
+    await program.methods
+      .setSubEnclavePolicy(true, 5_000)
+      .accounts({ enclave: enclavePda, authority: authority.publicKey })
+      .rpc();
+
+    const nameHash = hashContent("proof-theory/lambda-calculus");
+    const metadataHash = hashContent("lambda-calculus-metadata");
+    const [subEnclavePda] = deriveEnclavePDA(nameHash);
+    const [subTreasuryPda] = deriveEnclaveTreasuryPDA(subEnclavePda);
+    await program.methods
+      .createEnclave(nameHash, metadataHash)
+      .accounts({
+        config: configPda,
+        creatorAgent: agent2Pda,
+        enclave: subEnclavePda,
+        enclaveTreasury: subTreasuryPda,
+        payer: authority.publicKey,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        systemProgram: SystemProgram.programId,
+        parentEnclave: enclavePda,
+      })
+      .preInstructions([
+        createEd25519Ix(
+          agentSigner2,
+          buildAgentMessage(
+            ACTION_CREATE_ENCLAVE,
+            agent2Pda,
+            Buffer.concat([Buffer.from(nameHash), Buffer.from(metadataHash), enclavePda.toBuffer()])
+          )
+        ),
+      ])
+      .rpc();
+
+    let sub = await program.account.enclave.fetch(subEnclavePda);
+    expect(sub.parent.toBase58()).to.equal(enclavePda.toBase58());
+    expect(sub.parentApproved).to.equal(false);
+    expect(sub.isActive).to.equal(false);
+    expect(sub.parentShareBps).to.equal(5_000);
+
+    try {
+      await program.methods
+        .setEnclaveActive(true)
+        .accounts({ enclave: subEnclavePda, authority: authority.publicKey })
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal("SubEnclaveNotApproved");
+    }
+
+    const approveSub = () =>
+      program.methods
+        .approveSubEnclave()
+        .accounts({ parentEnclave: enclavePda, subEnclave: subEnclavePda, authority: authority.publicKey })
+        .rpc();
+    await approveSub();
+    sub = await program.account.enclave.fetch(subEnclavePda);
+    expect(sub.parentApproved).to.equal(true);
+    expect(sub.isActive).to.equal(false);
+
+    try {
+      await approveSub();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal("SubEnclaveAlreadyApproved");
+    }
+
+    // Activation stays with the child owner.
+    await program.methods
+      .setEnclaveActive(true)
+      .accounts({ enclave: subEnclavePda, authority: authority.publicKey })
+      .rpc();
+    sub = await program.account.enclave.fetch(subEnclavePda);
+    expect(sub.isActive).to.equal(true);
+
+    // Tip into the sub-enclave: 30% enclave share is split 50/50 with the parent.
+    const tipNonce = 2;
+    const [tipPda] = deriveTipPDA(authority.publicKey, tipNonce);
+    const [escrowPda] = deriveEscrowPDA(tipPda);
+    const [rateLimitPda] = deriveRateLimitPDA(authority.publicKey);
+    await program.methods
+      .submitTip(hashContent("Sub-enclave tip"), new BN(15_000_000), 0, new BN(tipNonce))
+      .accounts({
+        config: configPda,
+        tipper: authority.publicKey,
+        rateLimit: rateLimitPda,
+        tip: tipPda,
+        escrow: escrowPda,
+        targetEnclave: subEnclavePda,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const parentBefore = await provider.connection.getBalance(enclaveTreasuryPda);
+    const subBefore = await provider.connection.getBalance(subTreasuryPda);
+    await program.methods
+      .settleTip()
+      .accounts({
+        config: configPda,
+        authority: authority.publicKey,
+        tip: tipPda,
+        escrow: escrowPda,
+        treasury: treasuryPda,
+        targetEnclave: subEnclavePda,
+        enclaveTreasury: subTreasuryPda,
+        systemProgram: SystemProgram.programId,
+        parentEnclaveTreasury: enclaveTreasuryPda,
+      })
+      .rpc();
+    const parentAfter = await provider.connection.getBalance(enclaveTreasuryPda);
+    const subAfter = await provider.connection.getBalance(subTreasuryPda);
+    expect(subAfter - subBefore).to.equal(2_250_000);
+    expect(parentAfter - parentBefore).to.equal(2_250_000);
+
+    await program.methods
+      .setSubEnclavePolicy(false, 0)
+      .accounts({ enclave: enclavePda, authority: authority.publicKey })
+      .rpc();
+  });
+
   it("publishes a rewards epoch and supports Merkle claims + sweep", async () => {
     const epoch = new BN(0);
     const [rewardsEpochPda] = deriveRewardsEpochPDA(enclavePda, epoch);
@@ -770,8 +2577,10 @@ describe("wunderland-sol", () => {
     await program.methods
       .publishRewardsEpoch(epoch, Array.from(root), total, new BN(2)) // 2s claim window
       .accounts({
+        config: configPda,
         enclave: enclavePda,
         enclaveTreasury: enclaveTreasuryPda,
+        enclaveCouncil: deriveCouncilPDA(enclavePda)[0],
         rewardsEpoch: rewardsEpochPda,
         authority: authority.publicKey,
         systemProgram: SystemProgram.programId,
@@ -793,12 +2602,14 @@ describe("wunderland-sol", () => {
     await program.methods
       .claimRewards(claim.index, new BN(claim.amount), [Array.from(leaves[0])])
       .accounts({
+        config: configPda,
         rewardsEpoch: rewardsEpochPda,
         agentIdentity: agent2Pda,
         vault: vault2Pda,
         claimReceipt: claimReceiptPda,
         payer: authority.publicKey,
         systemProgram: SystemProgram.programId,
+        enclave: enclavePda,
       })
       .rpc();
 
@@ -810,12 +2621,14 @@ describe("wunderland-sol", () => {
       await program.methods
         .claimRewards(claim.index, new BN(claim.amount), [Array.from(leaves[0])])
         .accounts({
+          config: configPda,
           rewardsEpoch: rewardsEpochPda,
           agentIdentity: agent2Pda,
           vault: vault2Pda,
           claimReceipt: claimReceiptPda,
           payer: authority.publicKey,
           systemProgram: SystemProgram.programId,
+          enclave: enclavePda,
         })
         .rpc();
       expect.fail("should have thrown");
@@ -839,6 +2652,218 @@ describe("wunderland-sol", () => {
     expect(rewardsEpochAfterSweep.sweptAt.toNumber()).to.be.greaterThan(0);
   });
 
+  it("governs enclave rewards epochs through a council (propose → approve → execute → dissolve)", async () => {
+    const nameHash = hashContent("council-governed-enclave");
+    const metadataHash = hashContent("council-governed-enclave-metadata");
+    const [councilEnclavePda] = deriveEnclavePDA(nameHash);
+    const [councilTreasuryPda] = deriveEnclaveTreasuryPDA(councilEnclavePda);
+    await program.methods
+      .createEnclave(nameHash, metadataHash)
+      .accounts({
+        config: configPda,
+        creatorAgent: agent2Pda,
+        enclave: councilEnclavePda,
+        enclaveTreasury: councilTreasuryPda,
+        payer: authority.publicKey,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      })
+      .preInstructions([
+        createEd25519Ix(
+          agentSigner2,
+          buildAgentMessage(
+            ACTION_CREATE_ENCLAVE,
+            agent2Pda,
+            Buffer.concat([Buffer.from(nameHash), Buffer.from(metadataHash)])
+          )
+        ),
+      ])
+      .rpc();
+
+    // Fund the enclave treasury directly.
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        SystemProgram.transfer({
+          fromPubkey: authority.publicKey,
+          toPubkey: councilTreasuryPda,
+          lamports: 5_000_000,
+        })
+      )
+    );
+
+    const member2 = Keypair.generate();
+    const member3 = Keypair.generate();
+    const [councilPda] = deriveCouncilPDA(councilEnclavePda);
+    await program.methods
+      .initializeEnclaveCouncil(
+        [authority.publicKey, member2.publicKey, member3.publicKey],
+        2,
+        new BN(3600)
+      )
+      .accounts({
+        enclave: councilEnclavePda,
+        council: councilPda,
+        authority: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const epoch = new BN(0);
+    const [rewardsEpochPda] = deriveRewardsEpochPDA(councilEnclavePda, epoch);
+    const root = hashContent("council-rewards-root");
+    const amount = new BN(3_000_000);
+
+    // The owner can no longer publish alone.
+    try {
+      await program.methods
+        .publishRewardsEpoch(epoch, Array.from(root), amount, new BN(0))
+        .accounts({
+          config: configPda,
+          enclave: councilEnclavePda,
+          enclaveTreasury: councilTreasuryPda,
+          enclaveCouncil: councilPda,
+          rewardsEpoch: rewardsEpochPda,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal("CouncilGoverned");
+    }
+
+    const [proposalPda] = deriveRewardsProposalPDA(councilPda, 0);
+    await program.methods
+      .proposeRewardsEpoch(epoch, Array.from(root), amount, new BN(0))
+      .accounts({
+        council: councilPda,
+        proposal: proposalPda,
+        proposer: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const execute = () =>
+      program.methods
+        .executeRewardsProposal()
+        .accounts({
+          config: configPda,
+          enclave: councilEnclavePda,
+          council: councilPda,
+          proposal: proposalPda,
+          enclaveTreasury: councilTreasuryPda,
+          rewardsEpoch: rewardsEpochPda,
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+    try {
+      await execute();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal("ProposalThresholdNotMet");
+    }
+
+    await program.methods
+      .approveRewardsProposal()
+      .accounts({ council: councilPda, proposal: proposalPda, member: member2.publicKey })
+      .signers([member2])
+      .rpc();
+
+    const treasuryBefore = await provider.connection.getBalance(councilTreasuryPda);
+    await execute();
+    const treasuryAfter = await provider.connection.getBalance(councilTreasuryPda);
+    expect(treasuryBefore - treasuryAfter).to.equal(amount.toNumber());
+
+    const proposal = await (program.account as any).rewardsProposal.fetch(proposalPda);
+    expect(proposal.executed).to.equal(true);
+    expect(proposal.approvalCount).to.equal(2);
+    const rewardsEpoch = await (program.account as any).rewardsEpoch.fetch(rewardsEpochPda);
+    expect(rewardsEpoch.totalAmount.toNumber()).to.equal(amount.toNumber());
+
+    // An owner update supersedes open proposals (approvals are tracked by member position).
+    const [proposal1Pda] = deriveRewardsProposalPDA(councilPda, 1);
+    await program.methods
+      .proposeRewardsEpoch(new BN(1), Array.from(hashContent("council-rewards-root-1")), amount, new BN(0))
+      .accounts({
+        council: councilPda,
+        proposal: proposal1Pda,
+        proposer: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    // Council changes need the owner plus a threshold (2) of current members.
+    const updateCouncil = () =>
+      program.methods
+        .updateEnclaveCouncil([authority.publicKey, member3.publicKey], 1, new BN(3600))
+        .accounts({ enclave: councilEnclavePda, council: councilPda, authority: authority.publicKey });
+    try {
+      await updateCouncil().rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal("CouncilApprovalMissing");
+    }
+    await updateCouncil()
+      .remainingAccounts([{ pubkey: member2.publicKey, isSigner: true, isWritable: false }])
+      .signers([member2])
+      .rpc();
+    const council = await (program.account as any).enclaveCouncil.fetch(councilPda);
+    expect(council.memberCount).to.equal(2);
+    expect(council.threshold).to.equal(1);
+    expect(council.minProposalId.toNumber()).to.equal(2);
+    expect(council.liveProposals).to.equal(2);
+
+    try {
+      await program.methods
+        .approveRewardsProposal()
+        .accounts({ council: councilPda, proposal: proposal1Pda, member: member3.publicKey })
+        .signers([member3])
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal("ProposalSuperseded");
+    }
+
+    const dissolve = () =>
+      program.methods
+        .dissolveEnclaveCouncil()
+        .accounts({ enclave: councilEnclavePda, council: councilPda, authority: authority.publicKey })
+        .rpc();
+    try {
+      await dissolve();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal("CouncilHasOpenProposals");
+    }
+
+    // Executed and superseded proposals can be closed by anyone; rent returns to the proposer.
+    for (const pda of [proposalPda, proposal1Pda]) {
+      await program.methods
+        .closeRewardsProposal()
+        .accounts({ council: councilPda, proposal: pda, proposer: authority.publicKey })
+        .rpc();
+      expect(await provider.connection.getAccountInfo(pda)).to.equal(null);
+    }
+
+    // Dissolving hands publishing back to the owner (threshold is now 1: the owner's own seat).
+    await dissolve();
+    expect(await provider.connection.getAccountInfo(councilPda)).to.equal(null);
+    const [ownerEpochPda] = deriveRewardsEpochPDA(councilEnclavePda, new BN(2));
+    await program.methods
+      .publishRewardsEpoch(new BN(2), Array.from(root), new BN(1_000_000), new BN(0))
+      .accounts({
+        config: configPda,
+        enclave: councilEnclavePda,
+        enclaveTreasury: councilTreasuryPda,
+        enclaveCouncil: councilPda,
+        rewardsEpoch: ownerEpochPda,
+        authority: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  });
+
   it("publishes a GLOBAL rewards epoch and supports Merkle claims + sweep", async () => {
     const epoch = new BN(1);
     const globalEnclave = SystemProgram.programId;
@@ -890,6 +2915,7 @@ describe("wunderland-sol", () => {
     await program.methods
       .claimRewards(claim.index, new BN(claim.amount), [Array.from(leaves[1])])
       .accounts({
+        config: configPda,
         rewardsEpoch: rewardsEpochPda,
         agentIdentity: agent1Pda,
         vault: vault1Pda,
@@ -936,8 +2962,9 @@ describe("wunderland-sol", () => {
     const buyItNow = new BN(12_000_000); // must be > budget (Option<u64>)
 
     await program.methods
-      .createJob(new BN(jobNonce), metadataHash, budget, buyItNow)
+      .createJob(new BN(jobNonce), metadataHash, budget, buyItNow, new BN(0), new BN(0), new BN(86_400))
       .accounts({
+        config: configPda,
         job: jobPda,
         escrow: jobEscrowPda,
         creator: authority.publicKey,
@@ -980,8 +3007,9 @@ describe("wunderland-sol", () => {
     const budget = new BN(budgetLamports);
 
     await program.methods
-      .createJob(new BN(jobNonce), metadataHash, budget, null)
+      .createJob(new BN(jobNonce), metadataHash, budget, null, new BN(0), new BN(0), new BN(86_400))
       .accounts({
+        config: configPda,
         job: jobPda,
         escrow: jobEscrowPda,
         creator: authority.publicKey,
@@ -1006,6 +3034,7 @@ describe("wunderland-sol", () => {
     await program.methods
       .placeJobBid(bidAmount, messageHash)
       .accounts({
+        config: configPda,
         job: jobPda,
         bid: bidPda,
         agentIdentity: agent1Pda,
@@ -1020,6 +3049,7 @@ describe("wunderland-sol", () => {
     await program.methods
       .acceptJobBid()
       .accounts({
+        config: configPda,
         job: jobPda,
         bid: bidPda,
         escrow: jobEscrowPda,
@@ -1045,6 +3075,7 @@ describe("wunderland-sol", () => {
     await program.methods
       .submitJob(submissionHash)
       .accounts({
+        config: configPda,
         job: jobPda,
         submission: submissionPda,
         agentIdentity: agent1Pda,
@@ -1064,6 +3095,7 @@ describe("wunderland-sol", () => {
     await program.methods
       .approveJobSubmission()
       .accounts({
+        config: configPda,
         job: jobPda,
         escrow: jobEscrowPda,
         submission: submissionPda,
@@ -1096,8 +3128,9 @@ describe("wunderland-sol", () => {
     const buyItNow = new BN(buyItNowLamports);
 
     await program.methods
-      .createJob(new BN(jobNonce), metadataHash, budget, buyItNow)
+      .createJob(new BN(jobNonce), metadataHash, budget, buyItNow, new BN(0), new BN(0), new BN(86_400))
       .accounts({
+        config: configPda,
         job: jobPda,
         escrow: jobEscrowPda,
         creator: authority.publicKey,
@@ -1120,6 +3153,7 @@ describe("wunderland-sol", () => {
     await program.methods
       .placeJobBid(new BN(buyItNowLamports), messageHash)
       .accounts({
+        config: configPda,
         job: jobPda,
         bid: bidPda,
         agentIdentity: agent1Pda,
@@ -1152,6 +3186,7 @@ describe("wunderland-sol", () => {
     await program.methods
       .submitJob(submissionHash)
       .accounts({
+        config: configPda,
         job: jobPda,
         submission: submissionPda,
         agentIdentity: agent1Pda,
@@ -1168,6 +3203,7 @@ describe("wunderland-sol", () => {
     await program.methods
       .approveJobSubmission()
       .accounts({
+        config: configPda,
         job: jobPda,
         escrow: jobEscrowPda,
         submission: submissionPda,
@@ -1194,8 +3230,9 @@ describe("wunderland-sol", () => {
     const buyItNow = new BN(buyItNowLamports);
 
     await program.methods
-      .createJob(new BN(jobNonce), metadataHash, budget, buyItNow)
+      .createJob(new BN(jobNonce), metadataHash, budget, buyItNow, new BN(0), new BN(0), new BN(86_400))
       .accounts({
+        config: configPda,
         job: jobPda,
         escrow: jobEscrowPda,
         creator: authority.publicKey,
@@ -1219,6 +3256,7 @@ describe("wunderland-sol", () => {
     await program.methods
       .placeJobBid(new BN(bidLamports), messageHash)
       .accounts({
+        config: configPda,
         job: jobPda,
         bid: bidPda,
         agentIdentity: agent1Pda,
@@ -1233,6 +3271,7 @@ describe("wunderland-sol", () => {
     await program.methods
       .acceptJobBid()
       .accounts({
+        config: configPda,
         job: jobPda,
         bid: bidPda,
         escrow: jobEscrowPda,
@@ -1255,42 +3294,248 @@ describe("wunderland-sol", () => {
     const submitEdIx = createEd25519Ix(agentSigner1, submitMessage);
 
     await program.methods
-      .submitJob(submissionHash)
-      .accounts({
-        job: jobPda,
-        submission: submissionPda,
-        agentIdentity: agent1Pda,
-        payer: authority.publicKey,
-        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
-        systemProgram: SystemProgram.programId,
-      })
-      .preInstructions([submitEdIx])
+      .submitJob(submissionHash)
+      .accounts({
+        config: configPda,
+        job: jobPda,
+        submission: submissionPda,
+        agentIdentity: agent1Pda,
+        payer: authority.publicKey,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      })
+      .preInstructions([submitEdIx])
+      .rpc();
+
+    // Approve and payout to agent vault (base budget only)
+    const vaultBefore = await provider.connection.getBalance(vault1Pda);
+
+    await program.methods
+      .approveJobSubmission()
+      .accounts({
+        config: configPda,
+        job: jobPda,
+        escrow: jobEscrowPda,
+        submission: submissionPda,
+        acceptedBid: bidPda,
+        vault: vault1Pda,
+        creator: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const vaultAfter = await provider.connection.getBalance(vault1Pda);
+    expect(vaultAfter - vaultBefore).to.equal(bidLamports);
+  });
+
+  // ================================================================
+  // Error tests (3)
+  // ================================================================
+
+  it("expires an undelivered job and lets the creator reclaim the escrow", async () => {
+    const jobNonce = 4;
+    const [jobPda] = deriveJobPDA(authority.publicKey, jobNonce);
+    const [jobEscrowPda] = deriveJobEscrowPDA(jobPda);
+    const [bidPda] = deriveJobBidPDA(jobPda, agent1Pda);
+
+    const metadataHash = hashContent("job-metadata-expiry");
+    const buyItNowLamports = 12_000_000;
+    const now = (await provider.connection.getBlockTime(await provider.connection.getSlot()))!;
+
+    await program.methods
+      .createJob(
+        new BN(jobNonce),
+        metadataHash,
+        new BN(10_000_000),
+        new BN(buyItNowLamports),
+        new BN(0),
+        new BN(now + 3),
+        new BN(86_400)
+      )
+      .accounts({
+        config: configPda,
+        job: jobPda,
+        escrow: jobEscrowPda,
+        creator: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    // Buy-it-now assigns the job immediately.
+    const messageHash = hashContent("bid-message-expiry");
+    const bidPayload = Buffer.concat([
+      jobPda.toBuffer(),
+      u64LE(buyItNowLamports),
+      Buffer.from(messageHash),
+    ]);
+    await program.methods
+      .placeJobBid(new BN(buyItNowLamports), messageHash)
+      .accounts({
+        config: configPda,
+        job: jobPda,
+        bid: bidPda,
+        agentIdentity: agent1Pda,
+        payer: authority.publicKey,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      })
+      .preInstructions([
+        createEd25519Ix(agentSigner1, buildAgentMessage(ACTION_PLACE_JOB_BID, agent1Pda, bidPayload)),
+      ])
+      .rpc();
+
+    const reclaim = () =>
+      program.methods
+        .reclaimExpiredJob()
+        .accounts({
+          job: jobPda,
+          bid: bidPda,
+          escrow: jobEscrowPda,
+          creator: authority.publicKey,
+        })
+        .rpc();
+
+    try {
+      await reclaim();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal("JobNotExpired");
+    }
+
+    await new Promise((r) => setTimeout(r, 5000));
+    const escrowBefore = await provider.connection.getBalance(jobEscrowPda);
+    await reclaim();
+    const escrowAfter = await provider.connection.getBalance(jobEscrowPda);
+    expect(escrowBefore - escrowAfter).to.equal(buyItNowLamports);
+
+    const job = await (program.account as any).jobPosting.fetch(jobPda);
+    expect(JSON.stringify(job.status)).to.include("expired");
+    const bid = await (program.account as any).jobBid.fetch(bidPda);
+    expect(JSON.stringify(bid.status)).to.include("expired");
+  });
+
+  it("lets anyone finalize a submission after the review window", async () => {
+    const jobNonce = 5;
+    const [jobPda] = deriveJobPDA(authority.publicKey, jobNonce);
+    const [jobEscrowPda] = deriveJobEscrowPDA(jobPda);
+    const [bidPda] = deriveJobBidPDA(jobPda, agent1Pda);
+    const [submissionPda] = deriveJobSubmissionPDA(jobPda);
+
+    const budgetLamports = 10_000_000;
+    const buyItNowLamports = 12_000_000;
+
+    await program.methods
+      .createJob(
+        new BN(jobNonce),
+        hashContent("job-metadata-review"),
+        new BN(budgetLamports),
+        new BN(buyItNowLamports),
+        new BN(0),
+        new BN(0),
+        new BN(2)
+      )
+      .accounts({
+        config: configPda,
+        job: jobPda,
+        escrow: jobEscrowPda,
+        creator: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    // Buy-it-now assigns the job immediately.
+    const messageHash = hashContent("bid-message-review");
+    const bidPayload = Buffer.concat([
+      jobPda.toBuffer(),
+      u64LE(buyItNowLamports),
+      Buffer.from(messageHash),
+    ]);
+    await program.methods
+      .placeJobBid(new BN(buyItNowLamports), messageHash)
+      .accounts({
+        config: configPda,
+        job: jobPda,
+        bid: bidPda,
+        agentIdentity: agent1Pda,
+        payer: authority.publicKey,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      })
+      .preInstructions([
+        createEd25519Ix(agentSigner1, buildAgentMessage(ACTION_PLACE_JOB_BID, agent1Pda, bidPayload)),
+      ])
+      .rpc();
+
+    const submissionHash = hashContent("job-submission-review");
+    const submitPayload = Buffer.concat([jobPda.toBuffer(), Buffer.from(submissionHash)]);
+    await program.methods
+      .submitJob(submissionHash)
+      .accounts({
+        config: configPda,
+        job: jobPda,
+        submission: submissionPda,
+        agentIdentity: agent1Pda,
+        payer: authority.publicKey,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      })
+      .preInstructions([
+        createEd25519Ix(agentSigner1, buildAgentMessage(ACTION_SUBMIT_JOB, agent1Pda, submitPayload)),
+      ])
+      .rpc();
+
+    // A third party finalizes; the creator never approves.
+    const caller = Keypair.generate();
+    const sig = await provider.connection.requestAirdrop(caller.publicKey, LAMPORTS_PER_SOL);
+    await provider.connection.confirmTransaction(sig);
+
+    const finalize = () =>
+      program.methods
+        .finalizeJobSubmission()
+        .accounts({
+          job: jobPda,
+          escrow: jobEscrowPda,
+          submission: submissionPda,
+          acceptedBid: bidPda,
+          vault: vault1Pda,
+          creator: authority.publicKey,
+          caller: caller.publicKey,
+        })
+        .signers([caller])
+        .rpc();
+
+    try {
+      await finalize();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal("JobReviewWindowOpen");
+    }
+
+    await new Promise((r) => setTimeout(r, 4000));
+
+    // Finalization pays the agent, so it stays open while jobs are paused.
+    const PAUSE_JOBS = 1 << 5;
+    await program.methods
+      .setPaused(PAUSE_JOBS)
+      .accounts({ config: configPda, authority: authority.publicKey })
       .rpc();
 
-    // Approve and payout to agent vault (base budget only)
     const vaultBefore = await provider.connection.getBalance(vault1Pda);
+    await finalize();
+    const vaultAfter = await provider.connection.getBalance(vault1Pda);
+    expect(vaultAfter - vaultBefore).to.equal(buyItNowLamports);
 
     await program.methods
-      .approveJobSubmission()
-      .accounts({
-        job: jobPda,
-        escrow: jobEscrowPda,
-        submission: submissionPda,
-        acceptedBid: bidPda,
-        vault: vault1Pda,
-        creator: authority.publicKey,
-        systemProgram: SystemProgram.programId,
-      })
+      .setPaused(0)
+      .accounts({ config: configPda, authority: authority.publicKey })
       .rpc();
 
-    const vaultAfter = await provider.connection.getBalance(vault1Pda);
-    expect(vaultAfter - vaultBefore).to.equal(bidLamports);
+    const job = await (program.account as any).jobPosting.fetch(jobPda);
+    expect(JSON.stringify(job.status)).to.include("completed");
+    const escrow = await (program.account as any).jobEscrow.fetch(jobEscrowPda);
+    expect(escrow.amount.toNumber()).to.equal(0);
   });
 
-  // ================================================================
-  // Error tests (3)
-  // ================================================================
-
   it("rejects self-vote", async () => {
     const [votePda] = deriveVotePDA(post0Pda, agent1Pda);
     const value = 1;
@@ -1298,6 +3543,7 @@ describe("wunderland-sol", () => {
     const payload = Buffer.concat([
       post0Pda.toBuffer(),
       Buffer.from([value]),
+      await voteNonceLE(agent1Pda),
     ]);
     const message = buildAgentMessage(ACTION_CAST_VOTE, agent1Pda, payload);
     const ed25519Ix = createEd25519Ix(agentSigner1, message);
@@ -1306,10 +3552,13 @@ describe("wunderland-sol", () => {
       await program.methods
         .castVote(value)
         .accounts({
+          config: configPda,
           reputationVote: votePda,
           postAnchor: post0Pda,
           postAgent: agent1Pda,
-          voterAgent: agent1Pda, // same as post author → self-vote
+          votingConfig: votingConfigPda,
+          voterAgent: agent1Pda,
+          voterVault: vault1Pda, // same as post author → self-vote
           payer: authority.publicKey,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
           systemProgram: SystemProgram.programId,
@@ -1361,6 +3610,7 @@ describe("wunderland-sol", () => {
     const payload = Buffer.concat([
       post0Pda.toBuffer(),
       Buffer.from([0]),
+      await voteNonceLE(agent1Pda),
     ]);
     const message = buildAgentMessage(ACTION_CAST_VOTE, agent1Pda, payload);
     const ed25519Ix = createEd25519Ix(agentSigner1, message);
@@ -1369,10 +3619,13 @@ describe("wunderland-sol", () => {
       await program.methods
         .castVote(0) // invalid: must be +1 or -1
         .accounts({
+          config: configPda,
           reputationVote: votePda,
           postAnchor: post0Pda,
           postAgent: agent1Pda,
+          votingConfig: votingConfigPda,
           voterAgent: agent1Pda,
+          voterVault: vault1Pda,
           payer: authority.publicKey,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
           systemProgram: SystemProgram.programId,
@@ -1385,6 +3638,404 @@ describe("wunderland-sol", () => {
     }
   });
 
+  it("enforces voting prerequisites (minimum agent age)", async () => {
+    const flatCurve = {
+      levelWeights: [1, 1, 1, 1, 1, 1],
+      reputationPerBonus: new BN(0),
+      maxReputationBonus: 0,
+      agePerBonusSeconds: new BN(0),
+      maxAgeBonus: 0,
+      maxVoteWeight: 1,
+    };
+    const noRequirements = {
+      minAgentAgeSeconds: new BN(0),
+      minTotalEntries: 0,
+      minVaultStakeLamports: new BN(0),
+    };
+
+    await program.methods
+      .updateVotingConfig(
+        flatCurve,
+        { ...noRequirements, minAgentAgeSeconds: new BN(365 * 24 * 60 * 60) },
+        new BN(0),
+        new BN(STAKE_LOCK_SECONDS)
+      )
+      .accounts({
+        config: configPda,
+        authority: authority.publicKey,
+        votingConfig: votingConfigPda,
+      })
+      .rpc();
+
+    const [votePda] = deriveVotePDA(post0Pda, agent2Pda);
+    const message = buildAgentMessage(
+      ACTION_CAST_VOTE,
+      agent2Pda,
+      Buffer.concat([post0Pda.toBuffer(), Buffer.from([1]), await voteNonceLE(agent2Pda)])
+    );
+
+    try {
+      await program.methods
+        .castVote(1)
+        .accounts({
+          config: configPda,
+          reputationVote: votePda,
+          postAnchor: post0Pda,
+          postAgent: agent1Pda,
+          votingConfig: votingConfigPda,
+          voterAgent: agent2Pda,
+          voterVault: vault2Pda,
+          payer: authority.publicKey,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        })
+        .preInstructions([createEd25519Ix(agentSigner2, message)])
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal("VoterTooNew");
+    } finally {
+      await program.methods
+        .updateVotingConfig(flatCurve, noRequirements, new BN(0), new BN(STAKE_LOCK_SECONDS))
+        .accounts({
+          config: configPda,
+          authority: authority.publicKey,
+          votingConfig: votingConfigPda,
+        })
+        .rpc();
+    }
+  });
+
+  it("keeps the voting stake locked after a recent voter is deactivated", async () => {
+    const flatCurve = {
+      levelWeights: [1, 1, 1, 1, 1, 1],
+      reputationPerBonus: new BN(0),
+      maxReputationBonus: 0,
+      agePerBonusSeconds: new BN(0),
+      maxAgeBonus: 0,
+      maxVoteWeight: 1,
+    };
+    const noRequirements = {
+      minAgentAgeSeconds: new BN(0),
+      minTotalEntries: 0,
+      minVaultStakeLamports: new BN(0),
+    };
+    const setVoting = (minVaultStakeLamports: number, stakeLockSeconds: number) =>
+      program.methods
+        .updateVotingConfig(
+          flatCurve,
+          { ...noRequirements, minVaultStakeLamports: new BN(minVaultStakeLamports) },
+          new BN(0),
+          new BN(stakeLockSeconds)
+        )
+        .accounts({
+          config: configPda,
+          authority: authority.publicKey,
+          votingConfig: votingConfigPda,
+        })
+        .rpc();
+    const withdraw = () =>
+      program.methods
+        .withdrawFromVault(new BN(1_000))
+        .accounts({
+          agentIdentity: agent2Pda,
+          votingConfig: votingConfigPda,
+          vault: vault2Pda,
+          owner: authority.publicKey,
+        })
+        .rpc();
+
+    // agent2 has voted; lock its whole stake above rent.
+    expect((await program.account.agentIdentity.fetch(agent2Pda)).lastVotedAt.toNumber()).to.be.greaterThan(0);
+    await program.methods
+      .depositToVault(new BN(1_000_000))
+      .accounts({
+        config: configPda,
+        agentIdentity: agent2Pda,
+        vault: vault2Pda,
+        depositor: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    const vaultInfo = (await provider.connection.getAccountInfo(vault2Pda))!;
+    const rent = await provider.connection.getMinimumBalanceForRentExemption(vaultInfo.data.length);
+    await setVoting(vaultInfo.lamports - rent, STAKE_LOCK_SECONDS);
+
+    await program.methods
+      .deactivateAgent()
+      .accounts({ agentIdentity: agent2Pda, owner: authority.publicKey })
+      .rpc();
+    try {
+      // Deactivating does not release the stake of a recent voter.
+      try {
+        await withdraw();
+        expect.fail("should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("VaultStakeLocked");
+      }
+
+      // The voting config cannot be swapped out to skip the lock.
+      try {
+        await program.methods
+          .withdrawFromVault(new BN(1_000))
+          .accounts({
+            agentIdentity: agent2Pda,
+            votingConfig: program.programId,
+            vault: vault2Pda,
+            owner: authority.publicKey,
+          })
+          .rpc();
+        expect.fail("should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("ConstraintSeeds");
+      }
+
+      // Once the lock window is over (here: disabled), the inactive agent's stake is free.
+      await setVoting(vaultInfo.lamports - rent, 0);
+      await withdraw();
+    } finally {
+      await program.methods
+        .reactivateAgent()
+        .accounts({ agentIdentity: agent2Pda, owner: authority.publicKey })
+        .rpc();
+      await setVoting(0, STAKE_LOCK_SECONDS);
+    }
+  });
+
+  it("proposes the admin authority in two steps (minimum timelock + cancel)", async () => {
+    const newAdmin = Keypair.generate();
+    const [transferPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("authority_transfer")],
+      program.programId
+    );
+    const propose = (to: PublicKey, timelock: number) =>
+      program.methods
+        .proposeAuthority(to, new BN(timelock))
+        .accounts({
+          config: configPda,
+          authorityTransfer: transferPda,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+    // The timelock cannot be skipped or shortened below the minimum (1 hour).
+    for (const timelock of [0, 3599]) {
+      try {
+        await propose(newAdmin.publicKey, timelock);
+        expect.fail("should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("AuthorityTimelockTooShort");
+      }
+    }
+
+    // Timelocked proposal cannot be accepted early, and can be canceled.
+    await propose(newAdmin.publicKey, 3600);
+    try {
+      await program.methods
+        .acceptAuthority()
+        .accounts({
+          config: configPda,
+          treasury: treasuryPda,
+          economics: economicsPda,
+          authorityTransfer: transferPda,
+          newAuthority: newAdmin.publicKey,
+          previousAuthority: authority.publicKey,
+        })
+        .signers([newAdmin])
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal("AuthorityTransferNotReady");
+    }
+    await program.methods
+      .cancelAuthorityTransfer()
+      .accounts({ config: configPda, authorityTransfer: transferPda, authority: authority.publicKey })
+      .rpc();
+    expect(await provider.connection.getAccountInfo(transferPda)).to.equal(null);
+  });
+
+  it("delegates admin permissions through roles (grant → use → revoke)", async () => {
+    const delegate = Keypair.generate();
+    const [adminRolesPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("admin_roles")],
+      program.programId
+    );
+    const SETTLER = 1;
+    const TREASURY_ADMIN = 8;
+    const withdraw = () =>
+      program.methods
+        .withdrawTreasury(new BN(1_000), Array(32).fill(0))
+        .accounts({
+          config: configPda,
+          treasury: treasuryPda,
+          authority: delegate.publicKey,
+          recipient: authority.publicKey,
+          adminRoles: adminRolesPda,
+        })
+        .signers([delegate])
+        .rpc();
+
+    await program.methods
+      .grantAdminRole(delegate.publicKey, SETTLER)
+      .accounts({
+        config: configPda,
+        adminRoles: adminRolesPda,
+        authority: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    // A settler cannot touch the treasury.
+    try {
+      await withdraw();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal("MissingRole");
+    }
+
+    await program.methods
+      .grantAdminRole(delegate.publicKey, TREASURY_ADMIN)
+      .accounts({
+        config: configPda,
+        adminRoles: adminRolesPda,
+        authority: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    const roles = await (program.account as any).adminRoles.fetch(adminRolesPda);
+    const index = roles.grantees.findIndex((g: PublicKey) => g.equals(delegate.publicKey));
+    expect(roles.roles[index]).to.equal(SETTLER | TREASURY_ADMIN);
+    await withdraw();
+
+    // Revoking every role removes the grantee.
+    await program.methods
+      .revokeAdminRole(delegate.publicKey, SETTLER | TREASURY_ADMIN)
+      .accounts({ config: configPda, adminRoles: adminRolesPda, authority: authority.publicKey })
+      .rpc();
+    const after = await (program.account as any).adminRoles.fetch(adminRolesPda);
+    expect(
+      after.grantees.slice(0, after.count).some((g: PublicKey) => g.equals(delegate.publicKey))
+    ).to.equal(false);
+    try {
+      await withdraw();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal("MissingRole");
+    }
+  });
+
+  it("withdraws from the treasury to a recipient within the spending cap", async () => {
+    const recipient = Keypair.generate().publicKey;
+    const memoHash = Array.from(createHash("sha256").update("invoice-42").digest());
+    const withdraw = (lamports: number) =>
+      program.methods
+        .withdrawTreasury(new BN(lamports), memoHash)
+        .accounts({
+          config: configPda,
+          treasury: treasuryPda,
+          authority: authority.publicKey,
+          recipient,
+        })
+        .rpc();
+    const setCap = (cap: BN, period: number) =>
+      program.methods
+        .setTreasuryWithdrawalCap(cap, new BN(period))
+        .accounts({ config: configPda, treasury: treasuryPda, authority: authority.publicKey })
+        .rpc();
+
+    const before = await program.account.globalTreasury.fetch(treasuryPda);
+    // Every earlier withdrawal in this run is still inside the 24h rolling window.
+    const windowWithdrawn = (before.recentWithdrawals as any[]).reduce(
+      (sum: BN, w: any) => sum.add(w.lamports),
+      new BN(0)
+    );
+    const amount = LAMPORTS_PER_SOL / 100; // rent-exempt for a fresh system account
+    await setCap(windowWithdrawn.addn(amount), 24 * 60 * 60);
+
+    await withdraw(amount);
+    expect(await provider.connection.getBalance(recipient)).to.equal(amount);
+    const after = await program.account.globalTreasury.fetch(treasuryPda);
+    expect(after.totalWithdrawn.sub(before.totalWithdrawn).toNumber()).to.equal(amount);
+    expect(after.lastMemoHash).to.deep.equal(memoHash);
+
+    try {
+      await withdraw(1_000);
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal("TreasuryWithdrawalCapExceeded");
+    }
+
+    // Restore the default cap for the remaining tests.
+    await setCap(new BN(10_000_000_000), 24 * 60 * 60);
+  });
+
+  it("pauses a subsystem without trapping funds (vault deposits paused, withdrawals open)", async () => {
+    const PAUSE_VAULTS = 1 << 6;
+    const setPaused = (mask: number) =>
+      program.methods
+        .setPaused(mask)
+        .accounts({ config: configPda, authority: authority.publicKey })
+        .rpc();
+
+    await setPaused(PAUSE_VAULTS);
+    expect((await program.account.programConfig.fetch(configPda)).pausedSubsystems).to.equal(
+      PAUSE_VAULTS
+    );
+
+    try {
+      await program.methods
+        .depositToVault(new BN(1_000))
+        .accounts({
+          config: configPda,
+          agentIdentity: agent1Pda,
+          vault: vault1Pda,
+          depositor: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal("SubsystemPaused");
+    }
+
+    // Withdrawals stay available during a pause.
+    await program.methods
+      .withdrawFromVault(new BN(1_000))
+      .accounts({
+        agentIdentity: agent1Pda,
+        votingConfig: votingConfigPda,
+        vault: vault1Pda,
+        owner: authority.publicKey,
+      })
+      .rpc();
+
+    await setPaused(0);
+  });
+
+  it("migrates accounts permissionlessly (current layout is a no-op)", async () => {
+    const payer = authority.publicKey;
+    const before = await provider.connection.getAccountInfo(configPda);
+    await program.methods
+      .migrateProgramConfig()
+      .accounts({ account: configPda, payer, systemProgram: SystemProgram.programId })
+      .rpc();
+    const after = await provider.connection.getAccountInfo(configPda);
+    expect(after!.data.length).to.equal(before!.data.length);
+    expect((await program.account.programConfig.fetch(configPda)).version).to.equal(1);
+
+    // The instruction's type must match the account's discriminator.
+    try {
+      await program.methods
+        .migrateGlobalTreasury()
+        .accounts({ account: configPda, payer, systemProgram: SystemProgram.programId })
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal("AccountDiscriminatorMismatch");
+    }
+  });
+
   it("enforces max agents per wallet (lifetime cap)", async () => {
     const spamOwner = Keypair.generate();
     const airdropSig = await provider.connection.requestAirdrop(
@@ -1463,16 +4114,66 @@ describe("wunderland-sol", () => {
   });
 
   it("recovers agent signer (owner-based, timelocked)", async () => {
-    // Set timelock=0 for test speed.
+    const [econChangePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("econ_change")],
+      program.programId
+    );
+    const queueEconomics = (recoveryTimelock: number, changeDelay: number) =>
+      program.methods
+        .updateEconomics(
+          new BN(50_000_000),
+          5,
+          new BN(recoveryTimelock),
+          new BN(10_000_000),
+          new BN(changeDelay)
+        )
+        .accounts({
+          config: configPda,
+          authority: authority.publicKey,
+          economics: economicsPda,
+          pendingChange: econChangePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+    // The change delay cannot be dropped below the minimum (1 hour).
+    try {
+      await queueEconomics(5 * 60, 0);
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal("EconomicsChangeDelayTooShort");
+    }
+
+    // A queued change waits for the current delay, and can be canceled before it applies.
+    await queueEconomics(0, 60 * 60);
+    const pending = await (program.account as any).pendingEconomicsChange.fetch(econChangePda);
+    expect(pending.effectiveAt.toNumber() - pending.proposedAt.toNumber()).to.equal(24 * 60 * 60);
+    try {
+      // Permissionless: no authority account involved.
+      await program.methods
+        .executeEconomicsUpdate()
+        .accounts({
+          economics: economicsPda,
+          pendingChange: econChangePda,
+          proposer: authority.publicKey,
+        })
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal("EconomicsChangeNotReady");
+    }
     await program.methods
-      .updateEconomics(new BN(50_000_000), 5, new BN(0))
+      .cancelEconomicsUpdate()
       .accounts({
         config: configPda,
+        pendingChange: econChangePda,
+        proposer: authority.publicKey,
         authority: authority.publicKey,
-        economics: economicsPda,
       })
       .rpc();
+    expect(await provider.connection.getAccountInfo(econChangePda)).to.equal(null);
 
+    // Recovery requests honor the current timelock (5 minutes) before executing.
     const newSigner = Keypair.generate().publicKey;
     const [recoveryPda] = deriveRecoveryPDA(agent1Pda);
 
@@ -1484,20 +4185,34 @@ describe("wunderland-sol", () => {
         owner: authority.publicKey,
         recovery: recoveryPda,
         systemProgram: SystemProgram.programId,
+        config: configPda,
       })
       .rpc();
 
+    try {
+      await program.methods
+        .executeRecoverAgentSigner()
+        .accounts({
+          agentIdentity: agent1Pda,
+          owner: authority.publicKey,
+          recovery: recoveryPda,
+          config: configPda,
+        })
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.error.errorCode.code).to.equal("RecoveryNotReady");
+    }
+
     await program.methods
-      .executeRecoverAgentSigner()
+      .cancelRecoverAgentSigner()
       .accounts({
         agentIdentity: agent1Pda,
         owner: authority.publicKey,
         recovery: recoveryPda,
       })
       .rpc();
-
-    const agent = await program.account.agentIdentity.fetch(agent1Pda);
-    expect(agent.agentSigner.toBase58()).to.equal(newSigner.toBase58());
+    expect(await provider.connection.getAccountInfo(recoveryPda)).to.equal(null);
   });
 
   it("deactivates an agent (owner-only safety valve)", async () => {
@@ -90,6 +90,10 @@ export function deriveEconomicsPda(programId: PublicKey = WUNDERLAND_PROGRAM_ID)
   return PublicKey.findProgramAddressSync([Buffer.from('econ')], programId);
 }
 
+export function deriveVotingConfigPda(programId: PublicKey = WUNDERLAND_PROGRAM_ID): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from('voting')], programId);
+}
+
 export function deriveOwnerCounterPda(owner: PublicKey, programId: PublicKey = WUNDERLAND_PROGRAM_ID): [PublicKey, number] {
   return PublicKey.findProgramAddressSync([Buffer.from('owner_counter'), owner.toBuffer()], programId);
 }
@@ -361,6 +365,7 @@ export function buildRequestRecoverAgentSignerIx(opts: {
   programId?: PublicKey;
 }): { recovery: PublicKey; instruction: TransactionInstruction } {
   const programId = opts.programId ?? WUNDERLAND_PROGRAM_ID;
+  const [config] = deriveConfigPda(programId);
   const [economics] = deriveEconomicsPda(programId);
   const [recovery] = deriveRecoveryPda(opts.agentIdentity, programId);
 
@@ -373,6 +378,7 @@ export function buildRequestRecoverAgentSignerIx(opts: {
       { pubkey: opts.owner, isSigner: true, isWritable: true },
       { pubkey: recovery, isSigner: false, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: config, isSigner: false, isWritable: false },
     ],
     data: Buffer.from(data),
   });
@@ -386,6 +392,7 @@ export function buildExecuteRecoverAgentSignerIx(opts: {
   programId?: PublicKey;
 }): { recovery: PublicKey; instruction: TransactionInstruction } {
   const programId = opts.programId ?? WUNDERLAND_PROGRAM_ID;
+  const [config] = deriveConfigPda(programId);
   const [recovery] = deriveRecoveryPda(opts.agentIdentity, programId);
 
   const instruction = new TransactionInstruction({
@@ -394,6 +401,7 @@ export function buildExecuteRecoverAgentSignerIx(opts: {
       { pubkey: opts.agentIdentity, isSigner: false, isWritable: true },
       { pubkey: opts.owner, isSigner: true, isWritable: true },
       { pubkey: recovery, isSigner: false, isWritable: true },
+      { pubkey: config, isSigner: false, isWritable: false },
     ],
     data: Buffer.from(IX_EXECUTE_RECOVER_SIGNER),
   });
@@ -434,6 +442,7 @@ export function buildSubmitTipIx(opts: {
   programId?: PublicKey;
 }): { tip: PublicKey; escrow: PublicKey; instruction: TransactionInstruction } {
   const programId = opts.programId ?? WUNDERLAND_PROGRAM_ID;
+  const [config] = deriveConfigPda(programId);
   if (opts.contentHash.length !== 32) throw new Error('contentHash must be 32 bytes.');
   if (opts.amountLamports <= 0n) throw new Error('amountLamports must be > 0.');
 
@@ -458,6 +467,7 @@ export function buildSubmitTipIx(opts: {
       { pubkey: escrow, isSigner: false, isWritable: true },
       { pubkey: opts.targetEnclave, isSigner: false, isWritable: false },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: config, isSigner: false, isWritable: false },
     ],
     data: Buffer.from(data),
   });
@@ -494,6 +504,7 @@ export function buildDonateToAgentIx(opts: {
   programId?: PublicKey;
 }): { vault: PublicKey; receipt: PublicKey; instruction: TransactionInstruction } {
   const programId = opts.programId ?? WUNDERLAND_PROGRAM_ID;
+  const [config] = deriveConfigPda(programId);
   if (opts.amountLamports <= 0n) throw new Error('amountLamports must be > 0.');
 
   const contextHash = opts.contextHash ?? new Uint8Array(32);
@@ -522,6 +533,7 @@ export function buildDonateToAgentIx(opts: {
       { pubkey: vault, isSigner: false, isWritable: true },
       { pubkey: receipt, isSigner: false, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: config, isSigner: false, isWritable: false },
     ],
     data: Buffer.from(data),
   });
@@ -662,6 +674,7 @@ export function buildCreateJobIx(opts: {
   programId?: PublicKey;
 }): { jobPda: PublicKey; escrowPda: PublicKey; instruction: TransactionInstruction } {
   const programId = opts.programId ?? WUNDERLAND_PROGRAM_ID;
+  const [config] = deriveConfigPda(programId);
   if (opts.metadataHash.length !== 32) throw new Error('metadataHash must be 32 bytes.');
   if (opts.budgetLamports <= 0n) throw new Error('budgetLamports must be > 0.');
 
@@ -694,6 +707,7 @@ export function buildCreateJobIx(opts: {
       { pubkey: escrowPda, isSigner: false, isWritable: true },
       { pubkey: opts.creator, isSigner: true, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: config, isSigner: false, isWritable: false },
     ],
     data: Buffer.from(data),
   });
@@ -728,6 +742,7 @@ export function buildAcceptJobBidIx(opts: {
   programId?: PublicKey;
 }): TransactionInstruction {
   const programId = opts.programId ?? WUNDERLAND_PROGRAM_ID;
+  const [config] = deriveConfigPda(programId);
   const [escrowPda] = deriveJobEscrowPda(opts.jobPda, programId);
 
   return new TransactionInstruction({
@@ -737,6 +752,7 @@ export function buildAcceptJobBidIx(opts: {
       { pubkey: opts.bidPda, isSigner: false, isWritable: true },
       { pubkey: escrowPda, isSigner: false, isWritable: true },
       { pubkey: opts.creator, isSigner: true, isWritable: true },
+      { pubkey: config, isSigner: false, isWritable: false },
     ],
     data: Buffer.from(IX_ACCEPT_JOB_BID),
   });
@@ -751,6 +767,7 @@ export function buildApproveJobSubmissionIx(opts: {
   programId?: PublicKey;
 }): TransactionInstruction {
   const programId = opts.programId ?? WUNDERLAND_PROGRAM_ID;
+  const [config] = deriveConfigPda(programId);
   const [escrowPda] = deriveJobEscrowPda(opts.jobPda, programId);
 
   return new TransactionInstruction({
@@ -763,6 +780,7 @@ export function buildApproveJobSubmissionIx(opts: {
       { pubkey: opts.vaultPda, isSigner: false, isWritable: true },
       { pubkey: opts.creator, isSigner: true, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: config, isSigner: false, isWritable: false },
     ],
     data: Buffer.from(IX_APPROVE_JOB_SUBMISSION),
   });
@@ -812,6 +830,7 @@ export function buildClaimRewardsIx(opts: {
   programId?: PublicKey;
 }): { instruction: TransactionInstruction; claimReceiptPda: PublicKey; vaultPda: PublicKey } {
   const programId = opts.programId ?? WUNDERLAND_PROGRAM_ID;
+  const [config] = deriveConfigPda(programId);
 
   const [claimReceiptPda] = deriveRewardsClaimPda(opts.rewardsEpochPda, opts.index, programId);
   const [vaultPda] = deriveVaultPda(opts.agentIdentityPda, programId);
@@ -840,6 +859,7 @@ export function buildClaimRewardsIx(opts: {
     { pubkey: vaultPda, isSigner: false, isWritable: true },
     { pubkey: opts.payer, isSigner: true, isWritable: true },
     { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    { pubkey: config, isSigner: false, isWritable: false },
   ];
 
   const instruction = new TransactionInstruction({ keys, programId, data });
@@ -920,6 +940,27 @@ export function deriveEnclaveTreasuryPda(
   );
 }
 
+export function deriveEnclaveBanPda(
+  enclavePda: PublicKey,
+  agentIdentity: PublicKey,
+  programId: PublicKey = WUNDERLAND_PROGRAM_ID,
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('ban'), enclavePda.toBuffer(), agentIdentity.toBuffer()],
+    programId,
+  );
+}
+
+export function deriveEnclaveCouncilPda(
+  enclavePda: PublicKey,
+  programId: PublicKey = WUNDERLAND_PROGRAM_ID,
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('council'), enclavePda.toBuffer()],
+    programId,
+  );
+}
+
 export function derivePostAnchorPda(
   agentIdentity: PublicKey,
   totalEntries: number,
@@ -968,10 +1009,12 @@ export function buildAnchorPostIx(opts: {
   programId?: PublicKey;
 }): { postAnchor: PublicKey; instruction: TransactionInstruction } {
   const programId = opts.programId ?? WUNDERLAND_PROGRAM_ID;
+  const [config] = deriveConfigPda(programId);
   if (opts.contentHash.length !== 32) throw new Error('contentHash must be 32 bytes.');
   if (opts.manifestHash.length !== 32) throw new Error('manifestHash must be 32 bytes.');
 
   const [postAnchor] = derivePostAnchorPda(opts.agentIdentity, opts.totalEntries, programId);
+  const [enclaveBan] = deriveEnclaveBanPda(opts.enclave, opts.agentIdentity, programId);
 
   const data = concatBytes([IX_ANCHOR_POST, opts.contentHash, opts.manifestHash]);
 
@@ -981,9 +1024,11 @@ export function buildAnchorPostIx(opts: {
       { pubkey: postAnchor, isSigner: false, isWritable: true },
       { pubkey: opts.agentIdentity, isSigner: false, isWritable: true },
       { pubkey: opts.enclave, isSigner: false, isWritable: false },
+      { pubkey: enclaveBan, isSigner: false, isWritable: false },
       { pubkey: opts.payer, isSigner: true, isWritable: true },
       { pubkey: SYSVAR_INSTRUCTIONS_PUBKEY, isSigner: false, isWritable: false },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: config, isSigner: false, isWritable: false },
     ],
     data: Buffer.from(data),
   });
@@ -1002,10 +1047,12 @@ export function buildAnchorCommentIx(opts: {
   programId?: PublicKey;
 }): { commentAnchor: PublicKey; instruction: TransactionInstruction } {
   const programId = opts.programId ?? WUNDERLAND_PROGRAM_ID;
+  const [config] = deriveConfigPda(programId);
   if (opts.contentHash.length !== 32) throw new Error('contentHash must be 32 bytes.');
   if (opts.manifestHash.length !== 32) throw new Error('manifestHash must be 32 bytes.');
 
   const [commentAnchor] = derivePostAnchorPda(opts.agentIdentity, opts.totalEntries, programId);
+  const [enclaveBan] = deriveEnclaveBanPda(opts.enclave, opts.agentIdentity, programId);
 
   const data = concatBytes([IX_ANCHOR_COMMENT, opts.contentHash, opts.manifestHash]);
 
@@ -1015,10 +1062,12 @@ export function buildAnchorCommentIx(opts: {
       { pubkey: commentAnchor, isSigner: false, isWritable: true },
       { pubkey: opts.agentIdentity, isSigner: false, isWritable: true },
       { pubkey: opts.enclave, isSigner: false, isWritable: false },
+      { pubkey: enclaveBan, isSigner: false, isWritable: false },
       { pubkey: opts.parentPost, isSigner: false, isWritable: true },
       { pubkey: opts.payer, isSigner: true, isWritable: true },
       { pubkey: SYSVAR_INSTRUCTIONS_PUBKEY, isSigner: false, isWritable: false },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: config, isSigner: false, isWritable: false },
     ],
     data: Buffer.from(data),
   });
@@ -1026,6 +1075,10 @@ export function buildAnchorCommentIx(opts: {
   return { commentAnchor, instruction };
 }
 
+/**
+ * Must be preceded by an ed25519 verify ix over the agent message for
+ * `post(32) || value_i8(1) || vote_nonce_u64_le(8)` (voter's current `vote_nonce`).
+ */
 export function buildCastVoteIx(opts: {
   postAnchor: PublicKey;
   postAgent: PublicKey;
@@ -1036,6 +1089,9 @@ export function buildCastVoteIx(opts: {
 }): { reputationVote: PublicKey; instruction: TransactionInstruction } {
   const programId = opts.programId ?? WUNDERLAND_PROGRAM_ID;
 
+  const [config] = deriveConfigPda(programId);
+  const [votingConfig] = deriveVotingConfigPda(programId);
+  const [voterVault] = deriveVaultPda(opts.voterAgent, programId);
   const [reputationVote] = deriveReputationVotePda(opts.postAnchor, opts.voterAgent, programId);
 
   const data = concatBytes([IX_CAST_VOTE, i8LE(opts.value)]);
@@ -1046,10 +1102,13 @@ export function buildCastVoteIx(opts: {
       { pubkey: reputationVote, isSigner: false, isWritable: true },
       { pubkey: opts.postAnchor, isSigner: false, isWritable: true },
       { pubkey: opts.postAgent, isSigner: false, isWritable: true },
-      { pubkey: opts.voterAgent, isSigner: false, isWritable: false },
+      { pubkey: votingConfig, isSigner: false, isWritable: false },
+      { pubkey: opts.voterAgent, isSigner: false, isWritable: true },
+      { pubkey: voterVault, isSigner: false, isWritable: false },
       { pubkey: opts.payer, isSigner: true, isWritable: true },
       { pubkey: SYSVAR_INSTRUCTIONS_PUBKEY, isSigner: false, isWritable: false },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: config, isSigner: false, isWritable: false },
     ],
     data: Buffer.from(data),
   });
@@ -1104,6 +1163,7 @@ export function buildPlaceJobBidIx(opts: {
   programId?: PublicKey;
 }): { bidPda: PublicKey; instruction: TransactionInstruction } {
   const programId = opts.programId ?? WUNDERLAND_PROGRAM_ID;
+  const [config] = deriveConfigPda(programId);
   if (opts.messageHash.length !== 32) throw new Error('messageHash must be 32 bytes.');
   if (opts.bidLamports <= 0n) throw new Error('bidLamports must be > 0.');
 
@@ -1120,6 +1180,7 @@ export function buildPlaceJobBidIx(opts: {
       { pubkey: opts.payer, isSigner: true, isWritable: true },
       { pubkey: SYSVAR_INSTRUCTIONS_PUBKEY, isSigner: false, isWritable: false },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: config, isSigner: false, isWritable: false },
     ],
     data: Buffer.from(data),
   });
@@ -1158,6 +1219,7 @@ export function buildSubmitJobIx(opts: {
   programId?: PublicKey;
 }): { submissionPda: PublicKey; instruction: TransactionInstruction } {
   const programId = opts.programId ?? WUNDERLAND_PROGRAM_ID;
+  const [config] = deriveConfigPda(programId);
   if (opts.submissionHash.length !== 32) throw new Error('submissionHash must be 32 bytes.');
 
   const [submissionPda] = deriveJobSubmissionPda(opts.jobPda, programId);
@@ -1173,6 +1235,7 @@ export function buildSubmitJobIx(opts: {
       { pubkey: opts.payer, isSigner: true, isWritable: true },
       { pubkey: SYSVAR_INSTRUCTIONS_PUBKEY, isSigner: false, isWritable: false },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: config, isSigner: false, isWritable: false },
     ],
     data: Buffer.from(data),
   });
@@ -1194,9 +1257,11 @@ export function buildPublishRewardsEpochIx(opts: {
   programId?: PublicKey;
 }): { rewardsEpoch: PublicKey; instruction: TransactionInstruction } {
   const programId = opts.programId ?? WUNDERLAND_PROGRAM_ID;
+  const [config] = deriveConfigPda(programId);
   if (opts.merkleRoot.length !== 32) throw new Error('merkleRoot must be 32 bytes.');
 
   const [enclaveTreasury] = deriveEnclaveTreasuryPda(opts.enclave, programId);
+  const [enclaveCouncil] = deriveEnclaveCouncilPda(opts.enclave, programId);
   const [rewardsEpoch] = deriveRewardsEpochPda(opts.enclave, opts.epoch, programId);
 
   const data = concatBytes([
@@ -1212,9 +1277,11 @@ export function buildPublishRewardsEpochIx(opts: {
     keys: [
       { pubkey: opts.enclave, isSigner: false, isWritable: false },
       { pubkey: enclaveTreasury, isSigner: false, isWritable: true },
+      { pubkey: enclaveCouncil, isSigner: false, isWritable: false },
       { pubkey: rewardsEpoch, isSigner: false, isWritable: true },
       { pubkey: opts.authority, isSigner: true, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: config, isSigner: false, isWritable: false },
     ],
     data: Buffer.from(data),
   });
@@ -1320,6 +1387,7 @@ export function buildRotateAgentSignerIx(opts: {
   programId?: PublicKey;
 }): TransactionInstruction {
   const programId = opts.programId ?? WUNDERLAND_PROGRAM_ID;
+  const [config] = deriveConfigPda(programId);
 
   const data = concatBytes([IX_ROTATE_AGENT_SIGNER, publicKeyBytes(opts.newAgentSigner)]);
 
@@ -1328,6 +1396,7 @@ export function buildRotateAgentSignerIx(opts: {
     keys: [
       { pubkey: opts.agentIdentity, isSigner: false, isWritable: true },
       { pubkey: SYSVAR_INSTRUCTIONS_PUBKEY, isSigner: false, isWritable: false },
+      { pubkey: config, isSigner: false, isWritable: false },
     ],
     data: Buffer.from(data),
   });
@@ -1344,6 +1413,7 @@ export function buildDepositToVaultIx(opts: {
   programId?: PublicKey;
 }): { vault: PublicKey; instruction: TransactionInstruction } {
   const programId = opts.programId ?? WUNDERLAND_PROGRAM_ID;
+  const [config] = deriveConfigPda(programId);
   if (opts.lamports <= 0n) throw new Error('lamports must be > 0.');
 
   const [vault] = deriveVaultPda(opts.agentIdentity, programId);
@@ -1357,6 +1427,7 @@ export function buildDepositToVaultIx(opts: {
       { pubkey: vault, isSigner: false, isWritable: true },
       { pubkey: opts.depositor, isSigner: true, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: config, isSigner: false, isWritable: false },
     ],
     data: Buffer.from(data),
   });
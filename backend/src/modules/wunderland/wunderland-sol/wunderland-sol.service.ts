@@ -368,7 +368,8 @@ export class WunderlandSolService {
   }
 
   /**
-   * Schedules an on-chain reputation vote (`cast_vote`) for an anchored post/comment.
+   * Schedules an on-chain reputation vote (`cast_vote`, or `change_vote` when the actor already
+   * voted the other way) for an anchored post/comment.
    *
    * This is typically invoked from autonomous browsing engagement (like/downvote).
    * Safe to call when disabled or not configured.
@@ -524,14 +525,31 @@ export class WunderlandSolService {
     // Avoid guaranteed failure + wasted fees.
     if (postAgentPda.equals(voterAgentPda)) return;
 
-    // Pre-check vote PDA to avoid sending a failing tx (and paying fees).
+    // Pre-check vote PDA: an existing vote is changed (or left alone if it already matches)
+    // instead of sending a cast that is guaranteed to fail.
+    let existingValue: number | null = null;
     try {
       const [votePda] = client.getVotePDA(postAnchorPda, voterAgentPda);
       const voteInfo = await client.connection.getAccountInfo(votePda);
-      if (voteInfo?.data) return;
+      if (voteInfo?.data) {
+        existingValue = sdk.decodeReputationVoteAccount(Buffer.from(voteInfo.data as Buffer)).value;
+      }
     } catch {
       // If pre-check fails (RPC), continue and let the tx be authoritative.
     }
+    if (existingValue === value) return;
+
+    if (existingValue !== null) {
+      await client.changeVote({
+        voterAgentPda,
+        agentSigner,
+        payer,
+        postAnchorPda,
+        postAgentPda,
+        newValue: value,
+      });
+      return;
+    }
 
     await client.castVote({
       voterAgentPda,
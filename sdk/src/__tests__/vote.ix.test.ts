@@ -0,0 +1,76 @@
+import { describe, expect, it } from 'vitest';
+import { Keypair } from '@solana/web3.js';
+import {
+  buildCastVoteIx,
+  buildRetractVoteIx,
+  buildVotePayload,
+  deriveConfigPDA,
+  deriveVaultPDA,
+  deriveVotingConfigPDA,
+} from '../client';
+
+describe('buildVotePayload', () => {
+  it('encodes post || value || vote_nonce', () => {
+    const postAnchorPda = Keypair.generate().publicKey;
+    const payload = buildVotePayload({ postAnchorPda, value: -1, voteNonce: 7n });
+
+    expect(payload.length).toBe(32 + 1 + 8);
+    expect(payload.subarray(0, 32).equals(postAnchorPda.toBuffer())).toBe(true);
+    expect(payload.readInt8(32)).toBe(-1);
+    expect(payload.readBigUInt64LE(33)).toBe(7n);
+  });
+
+  it('omits the value byte for retractions', () => {
+    const postAnchorPda = Keypair.generate().publicKey;
+    const payload = buildVotePayload({ postAnchorPda, value: null, voteNonce: 3n });
+
+    expect(payload.length).toBe(32 + 8);
+    expect(payload.readBigUInt64LE(32)).toBe(3n);
+  });
+});
+
+describe('vote instructions', () => {
+  const programId = Keypair.generate().publicKey;
+  const reputationVotePda = Keypair.generate().publicKey;
+  const postAnchorPda = Keypair.generate().publicKey;
+  const postAgentPda = Keypair.generate().publicKey;
+  const voterAgentPda = Keypair.generate().publicKey;
+  const payer = Keypair.generate().publicKey;
+
+  it('passes voting config and voter vault to cast_vote and appends config', () => {
+    const ix = buildCastVoteIx({
+      programId,
+      reputationVotePda,
+      postAnchorPda,
+      postAgentPda,
+      voterAgentPda,
+      payer,
+      value: 1,
+    });
+
+    const keys = ix.keys.map((k) => k.pubkey.toBase58());
+    expect(keys[0]).toBe(reputationVotePda.toBase58());
+    expect(keys[3]).toBe(deriveVotingConfigPDA(programId)[0].toBase58());
+    expect(keys[4]).toBe(voterAgentPda.toBase58());
+    expect(ix.keys[4].isWritable).toBe(true); // vote_nonce is bumped
+    expect(keys[5]).toBe(deriveVaultPDA(voterAgentPda, programId)[0].toBase58());
+    expect(keys[9]).toBe(deriveConfigPDA(programId)[0].toBase58());
+    expect(ix.keys.length).toBe(10);
+  });
+
+  it('refunds retracted vote rent to the given recipient', () => {
+    const rentRecipient = Keypair.generate().publicKey;
+    const ix = buildRetractVoteIx({
+      programId,
+      reputationVotePda,
+      postAnchorPda,
+      postAgentPda,
+      voterAgentPda,
+      rentRecipient,
+    });
+
+    expect(ix.data.length).toBe(8);
+    expect(ix.keys[5].pubkey.toBase58()).toBe(rentRecipient.toBase58());
+    expect(ix.keys[5].isWritable).toBe(true);
+  });
+});
@@ -89,6 +89,8 @@ export const ACTION_ROTATE_AGENT_SIGNER = 5;
 export const ACTION_PLACE_JOB_BID = 6;
 export const ACTION_WITHDRAW_JOB_BID = 7;
 export const ACTION_SUBMIT_JOB = 8;
+export const ACTION_CHANGE_VOTE = 9;
+export const ACTION_RETRACT_VOTE = 10;
 
 export function buildAgentMessage(opts: {
   action: number;
@@ -230,6 +232,14 @@ export function deriveConfigPDA(programId: PublicKey): [PublicKey, number] {
   return PublicKey.findProgramAddressSync([Buffer.from('config')], programId);
 }
 
+/**
+ * VotingConfig PDA.
+ * Seeds: ["voting"]
+ */
+export function deriveVotingConfigPDA(programId: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from('voting')], programId);
+}
+
 /**
  * EconomicsConfig PDA.
  * Seeds: ["econ"]
@@ -296,6 +306,29 @@ export function deriveEnclaveTreasuryPDA(enclavePda: PublicKey, programId: Publi
   return PublicKey.findProgramAddressSync([Buffer.from('enclave_treasury'), enclavePda.toBuffer()], programId);
 }
 
+/**
+ * Derive EnclaveBan PDA.
+ * Seeds: ["ban", enclave_pda, agent_identity_pda]
+ */
+export function deriveEnclaveBanPDA(
+  enclavePda: PublicKey,
+  agentIdentityPda: PublicKey,
+  programId: PublicKey,
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('ban'), enclavePda.toBuffer(), agentIdentityPda.toBuffer()],
+    programId,
+  );
+}
+
+/**
+ * Derive EnclaveCouncil PDA.
+ * Seeds: ["council", enclave_pda]
+ */
+export function deriveEnclaveCouncilPDA(enclavePda: PublicKey, programId: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from('council'), enclavePda.toBuffer()], programId);
+}
+
 /**
  * Derive RewardsEpoch PDA.
  * Seeds: ["rewards_epoch", enclave_pda, epoch_u64_le]
@@ -616,6 +649,8 @@ export function buildRequestRecoverAgentSignerIx(opts: {
   newAgentSigner: PublicKey;
 }): TransactionInstruction {
   const data = Buffer.concat([anchorDiscriminator('request_recover_agent_signer'), opts.newAgentSigner.toBuffer()]);
+  const [configPda] = deriveConfigPDA(opts.programId);
+
   return new TransactionInstruction({
     programId: opts.programId,
     keys: [
@@ -624,6 +659,7 @@ export function buildRequestRecoverAgentSignerIx(opts: {
       { pubkey: opts.owner, isSigner: true, isWritable: true },
       { pubkey: opts.recoveryPda, isSigner: false, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: configPda, isSigner: false, isWritable: false },
     ],
     data,
   });
@@ -636,12 +672,15 @@ export function buildExecuteRecoverAgentSignerIx(opts: {
   recoveryPda: PublicKey;
 }): TransactionInstruction {
   const data = anchorDiscriminator('execute_recover_agent_signer');
+  const [configPda] = deriveConfigPDA(opts.programId);
+
   return new TransactionInstruction({
     programId: opts.programId,
     keys: [
       { pubkey: opts.agentIdentityPda, isSigner: false, isWritable: true },
       { pubkey: opts.owner, isSigner: true, isWritable: true },
       { pubkey: opts.recoveryPda, isSigner: false, isWritable: true },
+      { pubkey: configPda, isSigner: false, isWritable: false },
     ],
     data,
   });
@@ -742,14 +781,19 @@ export function buildPublishRewardsEpochIx(opts: {
     windowBuf,
   ]);
 
+  const [enclaveCouncilPda] = deriveEnclaveCouncilPDA(opts.enclavePda, opts.programId);
+  const [configPda] = deriveConfigPDA(opts.programId);
+
   const instruction = new TransactionInstruction({
     programId: opts.programId,
     keys: [
       { pubkey: opts.enclavePda, isSigner: false, isWritable: false },
       { pubkey: enclaveTreasuryPda, isSigner: false, isWritable: true },
+      { pubkey: enclaveCouncilPda, isSigner: false, isWritable: false },
       { pubkey: rewardsEpochPda, isSigner: false, isWritable: true },
       { pubkey: opts.authority, isSigner: true, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: configPda, isSigner: false, isWritable: false },
     ],
     data,
   });
@@ -829,6 +873,8 @@ export function buildClaimRewardsIx(opts: {
     ...proofBufs,
   ]);
 
+  const [configPda] = deriveConfigPDA(opts.programId);
+
   const instruction = new TransactionInstruction({
     programId: opts.programId,
     keys: [
@@ -838,6 +884,7 @@ export function buildClaimRewardsIx(opts: {
       { pubkey: claimReceiptPda, isSigner: false, isWritable: true },
       { pubkey: opts.payer, isSigner: true, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: configPda, isSigner: false, isWritable: false },
     ],
     data,
   });
@@ -912,15 +959,20 @@ export function buildAnchorPostIx(opts: {
     Buffer.from(manifestHashBytes),
   ]);
 
+  const [enclaveBanPda] = deriveEnclaveBanPDA(opts.enclavePda, opts.agentIdentityPda, opts.programId);
+  const [configPda] = deriveConfigPDA(opts.programId);
+
   return new TransactionInstruction({
     programId: opts.programId,
     keys: [
       { pubkey: opts.postAnchorPda, isSigner: false, isWritable: true },
       { pubkey: opts.agentIdentityPda, isSigner: false, isWritable: true },
       { pubkey: opts.enclavePda, isSigner: false, isWritable: false },
+      { pubkey: enclaveBanPda, isSigner: false, isWritable: false },
       { pubkey: opts.payer, isSigner: true, isWritable: true },
       { pubkey: SYSVAR_INSTRUCTIONS_PUBKEY, isSigner: false, isWritable: false },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: configPda, isSigner: false, isWritable: false },
     ],
     data,
   });
@@ -944,21 +996,43 @@ export function buildAnchorCommentIx(opts: {
     Buffer.from(manifestHashBytes),
   ]);
 
+  const [enclaveBanPda] = deriveEnclaveBanPDA(opts.enclavePda, opts.agentIdentityPda, opts.programId);
+  const [configPda] = deriveConfigPDA(opts.programId);
+
   return new TransactionInstruction({
     programId: opts.programId,
     keys: [
       { pubkey: opts.commentAnchorPda, isSigner: false, isWritable: true },
       { pubkey: opts.agentIdentityPda, isSigner: false, isWritable: true },
       { pubkey: opts.enclavePda, isSigner: false, isWritable: false },
+      { pubkey: enclaveBanPda, isSigner: false, isWritable: false },
       { pubkey: opts.parentPostPda, isSigner: false, isWritable: true },
       { pubkey: opts.payer, isSigner: true, isWritable: true },
       { pubkey: SYSVAR_INSTRUCTIONS_PUBKEY, isSigner: false, isWritable: false },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: configPda, isSigner: false, isWritable: false },
     ],
     data,
   });
 }
 
+/**
+ * Signed vote payload for cast/change/retract: `post(32) || value_i8(1) || vote_nonce_u64_le(8)`.
+ * Retract omits the value byte. `voteNonce` is the voter's current `AgentIdentity.vote_nonce`.
+ */
+export function buildVotePayload(opts: {
+  postAnchorPda: PublicKey;
+  value: 1 | -1 | null;
+  voteNonce: bigint;
+}): Buffer {
+  const nonceBuf = Buffer.alloc(8);
+  nonceBuf.writeBigUInt64LE(opts.voteNonce, 0);
+  if (opts.value === null) return Buffer.concat([opts.postAnchorPda.toBuffer(), nonceBuf]);
+  const valueBuf = Buffer.alloc(1);
+  valueBuf.writeInt8(opts.value, 0);
+  return Buffer.concat([opts.postAnchorPda.toBuffer(), valueBuf, nonceBuf]);
+}
+
 export function buildCastVoteIx(opts: {
   programId: PublicKey;
   reputationVotePda: PublicKey;
@@ -972,16 +1046,80 @@ export function buildCastVoteIx(opts: {
   anchorDiscriminator('cast_vote').copy(data, 0);
   data.writeInt8(opts.value, 8);
 
+  const [configPda] = deriveConfigPDA(opts.programId);
+  const [votingConfigPda] = deriveVotingConfigPDA(opts.programId);
+  const [voterVaultPda] = deriveVaultPDA(opts.voterAgentPda, opts.programId);
+
   return new TransactionInstruction({
     programId: opts.programId,
     keys: [
       { pubkey: opts.reputationVotePda, isSigner: false, isWritable: true },
       { pubkey: opts.postAnchorPda, isSigner: false, isWritable: true },
       { pubkey: opts.postAgentPda, isSigner: false, isWritable: true },
-      { pubkey: opts.voterAgentPda, isSigner: false, isWritable: false },
+      { pubkey: votingConfigPda, isSigner: false, isWritable: false },
+      { pubkey: opts.voterAgentPda, isSigner: false, isWritable: true },
+      { pubkey: voterVaultPda, isSigner: false, isWritable: false },
       { pubkey: opts.payer, isSigner: true, isWritable: true },
       { pubkey: SYSVAR_INSTRUCTIONS_PUBKEY, isSigner: false, isWritable: false },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: configPda, isSigner: false, isWritable: false },
+    ],
+    data,
+  });
+}
+
+export function buildChangeVoteIx(opts: {
+  programId: PublicKey;
+  reputationVotePda: PublicKey;
+  postAnchorPda: PublicKey;
+  postAgentPda: PublicKey;
+  voterAgentPda: PublicKey;
+  newValue: 1 | -1;
+}): TransactionInstruction {
+  const data = Buffer.alloc(9);
+  anchorDiscriminator('change_vote').copy(data, 0);
+  data.writeInt8(opts.newValue, 8);
+
+  const [configPda] = deriveConfigPDA(opts.programId);
+  const [votingConfigPda] = deriveVotingConfigPDA(opts.programId);
+
+  return new TransactionInstruction({
+    programId: opts.programId,
+    keys: [
+      { pubkey: opts.reputationVotePda, isSigner: false, isWritable: true },
+      { pubkey: opts.postAnchorPda, isSigner: false, isWritable: true },
+      { pubkey: opts.postAgentPda, isSigner: false, isWritable: true },
+      { pubkey: votingConfigPda, isSigner: false, isWritable: false },
+      { pubkey: opts.voterAgentPda, isSigner: false, isWritable: true },
+      { pubkey: SYSVAR_INSTRUCTIONS_PUBKEY, isSigner: false, isWritable: false },
+      { pubkey: configPda, isSigner: false, isWritable: false },
+    ],
+    data,
+  });
+}
+
+export function buildRetractVoteIx(opts: {
+  programId: PublicKey;
+  reputationVotePda: PublicKey;
+  postAnchorPda: PublicKey;
+  postAgentPda: PublicKey;
+  voterAgentPda: PublicKey;
+  /** Original rent payer of the vote (receives the refund). */
+  rentRecipient: PublicKey;
+}): TransactionInstruction {
+  const data = anchorDiscriminator('retract_vote');
+  const [votingConfigPda] = deriveVotingConfigPDA(opts.programId);
+
+  return new TransactionInstruction({
+    programId: opts.programId,
+    keys: [
+      { pubkey: opts.reputationVotePda, isSigner: false, isWritable: true },
+      { pubkey: opts.postAnchorPda, isSigner: false, isWritable: true },
+      { pubkey: opts.postAgentPda, isSigner: false, isWritable: true },
+      { pubkey: votingConfigPda, isSigner: false, isWritable: false },
+      { pubkey: opts.voterAgentPda, isSigner: false, isWritable: true },
+      { pubkey: opts.rentRecipient, isSigner: false, isWritable: true },
+      { pubkey: SYSVAR_INSTRUCTIONS_PUBKEY, isSigner: false, isWritable: false },
     ],
     data,
   });
@@ -998,6 +1136,8 @@ export function buildDepositToVaultIx(opts: {
   anchorDiscriminator('deposit_to_vault').copy(data, 0);
   data.writeBigUInt64LE(opts.lamports, 8);
 
+  const [configPda] = deriveConfigPDA(opts.programId);
+
   return new TransactionInstruction({
     programId: opts.programId,
     keys: [
@@ -1005,6 +1145,7 @@ export function buildDepositToVaultIx(opts: {
       { pubkey: opts.vaultPda, isSigner: false, isWritable: true },
       { pubkey: opts.depositor, isSigner: true, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: configPda, isSigner: false, isWritable: false },
     ],
     data,
   });
@@ -1053,6 +1194,8 @@ export function buildDonateToAgentIx(opts: {
   Buffer.from(contextHashBytes).copy(data, 16);
   data.writeBigUInt64LE(opts.donationNonce, 48);
 
+  const [configPda] = deriveConfigPDA(opts.programId);
+
   return new TransactionInstruction({
     programId: opts.programId,
     keys: [
@@ -1061,6 +1204,7 @@ export function buildDonateToAgentIx(opts: {
       { pubkey: opts.vaultPda, isSigner: false, isWritable: true },
       { pubkey: opts.receiptPda, isSigner: false, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: configPda, isSigner: false, isWritable: false },
     ],
     data,
   });
@@ -1100,6 +1244,8 @@ export function buildCreateJobIx(opts: {
     data.writeBigUInt64LE(opts.buyItNowLamports as bigint, 57);
   }
 
+  const [configPda] = deriveConfigPDA(opts.programId);
+
   return new TransactionInstruction({
     programId: opts.programId,
     keys: [
@@ -1107,6 +1253,7 @@ export function buildCreateJobIx(opts: {
       { pubkey: opts.escrowPda, isSigner: false, isWritable: true },
       { pubkey: opts.creator, isSigner: true, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: configPda, isSigner: false, isWritable: false },
     ],
     data,
   });
@@ -1148,6 +1295,8 @@ export function buildPlaceJobBidIx(opts: {
   data.writeBigUInt64LE(opts.bidLamports, 8);
   Buffer.from(messageHashBytes).copy(data, 16);
 
+  const [configPda] = deriveConfigPDA(opts.programId);
+
   return new TransactionInstruction({
     programId: opts.programId,
     keys: [
@@ -1157,6 +1306,7 @@ export function buildPlaceJobBidIx(opts: {
       { pubkey: opts.payer, isSigner: true, isWritable: true },
       { pubkey: SYSVAR_INSTRUCTIONS_PUBKEY, isSigner: false, isWritable: false },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: configPda, isSigner: false, isWritable: false },
     ],
     data,
   });
@@ -1189,6 +1339,8 @@ export function buildAcceptJobBidIx(opts: {
   creator: PublicKey;
 }): TransactionInstruction {
   const data = Buffer.from(anchorDiscriminator('accept_job_bid'));
+  const [configPda] = deriveConfigPDA(opts.programId);
+
   return new TransactionInstruction({
     programId: opts.programId,
     keys: [
@@ -1196,6 +1348,7 @@ export function buildAcceptJobBidIx(opts: {
       { pubkey: opts.bidPda, isSigner: false, isWritable: true },
       { pubkey: opts.escrowPda, isSigner: false, isWritable: true },
       { pubkey: opts.creator, isSigner: true, isWritable: true },
+      { pubkey: configPda, isSigner: false, isWritable: false },
     ],
     data,
   });
@@ -1215,6 +1368,8 @@ export function buildSubmitJobIx(opts: {
   anchorDiscriminator('submit_job').copy(data, 0);
   Buffer.from(submissionHashBytes).copy(data, 8);
 
+  const [configPda] = deriveConfigPDA(opts.programId);
+
   return new TransactionInstruction({
     programId: opts.programId,
     keys: [
@@ -1224,6 +1379,7 @@ export function buildSubmitJobIx(opts: {
       { pubkey: opts.payer, isSigner: true, isWritable: true },
       { pubkey: SYSVAR_INSTRUCTIONS_PUBKEY, isSigner: false, isWritable: false },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: configPda, isSigner: false, isWritable: false },
     ],
     data,
   });
@@ -1239,6 +1395,8 @@ export function buildApproveJobSubmissionIx(opts: {
   creator: PublicKey;
 }): TransactionInstruction {
   const data = Buffer.from(anchorDiscriminator('approve_job_submission'));
+  const [configPda] = deriveConfigPDA(opts.programId);
+
   return new TransactionInstruction({
     programId: opts.programId,
     keys: [
@@ -1249,6 +1407,7 @@ export function buildApproveJobSubmissionIx(opts: {
       { pubkey: opts.vaultPda, isSigner: false, isWritable: true },
       { pubkey: opts.creator, isSigner: true, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: configPda, isSigner: false, isWritable: false },
     ],
     data,
   });
@@ -1260,11 +1419,14 @@ export function buildRotateAgentSignerIx(opts: {
   newAgentSigner: PublicKey;
 }): TransactionInstruction {
   const data = Buffer.concat([anchorDiscriminator('rotate_agent_signer'), opts.newAgentSigner.toBuffer()]);
+  const [configPda] = deriveConfigPDA(opts.programId);
+
   return new TransactionInstruction({
     programId: opts.programId,
     keys: [
       { pubkey: opts.agentIdentityPda, isSigner: false, isWritable: true },
       { pubkey: SYSVAR_INSTRUCTIONS_PUBKEY, isSigner: false, isWritable: false },
+      { pubkey: configPda, isSigner: false, isWritable: false },
     ],
     data,
   });
@@ -1332,6 +1494,8 @@ export function buildSubmitTipIx(opts: {
     nonceBuf,
   ]);
 
+  const [configPda] = deriveConfigPDA(opts.programId);
+
   return new TransactionInstruction({
     programId: opts.programId,
     keys: [
@@ -1341,6 +1505,7 @@ export function buildSubmitTipIx(opts: {
       { pubkey: opts.escrowPda, isSigner: false, isWritable: true },
       { pubkey: opts.targetEnclave, isSigner: false, isWritable: false },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: configPda, isSigner: false, isWritable: false },
     ],
     data,
   });
@@ -1518,6 +1683,10 @@ export class WunderlandSolClient {
     return deriveVotePDA(postAnchorPda, voterAgentIdentityPda, this.programId);
   }
 
+  getVotingConfigPDA(): [PublicKey, number] {
+    return deriveVotingConfigPDA(this.programId);
+  }
+
   // ---------- read methods ----------
 
   async getProgramConfig(): Promise<{ pda: PublicKey; account: { authority: PublicKey; agentCount: number; enclaveCount: number } } | null> {
@@ -2067,6 +2236,13 @@ export class WunderlandSolClient {
     return { signature, commentAnchorPda, entryIndex };
   }
 
+  /** Current `vote_nonce` of an agent (signed into every vote payload). */
+  private async fetchVoteNonce(voterAgentPda: PublicKey): Promise<bigint> {
+    const agentInfo = await this.connection.getAccountInfo(voterAgentPda);
+    if (!agentInfo) throw new Error(`AgentIdentity not found: ${voterAgentPda.toBase58()}`);
+    return decodeAgentIdentityAccount(agentInfo.data as Buffer).voteNonce;
+  }
+
   async castVote(opts: {
     voterAgentPda: PublicKey;
     agentSigner: Keypair;
@@ -2077,9 +2253,11 @@ export class WunderlandSolClient {
   }): Promise<{ signature: TransactionSignature; votePda: PublicKey }> {
     const [votePda] = this.getVotePDA(opts.postAnchorPda, opts.voterAgentPda);
 
-    const valueBuf = Buffer.alloc(1);
-    valueBuf.writeInt8(opts.value, 0);
-    const payload = Buffer.concat([opts.postAnchorPda.toBuffer(), valueBuf]);
+    const payload = buildVotePayload({
+      postAnchorPda: opts.postAnchorPda,
+      value: opts.value,
+      voteNonce: await this.fetchVoteNonce(opts.voterAgentPda),
+    });
     const message = buildAgentMessage({
       action: ACTION_CAST_VOTE,
       programId: this.programId,
@@ -2102,6 +2280,87 @@ export class WunderlandSolClient {
     return { signature, votePda };
   }
 
+  async changeVote(opts: {
+    voterAgentPda: PublicKey;
+    agentSigner: Keypair;
+    payer: Keypair;
+    postAnchorPda: PublicKey;
+    postAgentPda: PublicKey;
+    newValue: 1 | -1;
+  }): Promise<{ signature: TransactionSignature; votePda: PublicKey }> {
+    const [votePda] = this.getVotePDA(opts.postAnchorPda, opts.voterAgentPda);
+
+    const payload = buildVotePayload({
+      postAnchorPda: opts.postAnchorPda,
+      value: opts.newValue,
+      voteNonce: await this.fetchVoteNonce(opts.voterAgentPda),
+    });
+    const message = buildAgentMessage({
+      action: ACTION_CHANGE_VOTE,
+      programId: this.programId,
+      agentIdentityPda: opts.voterAgentPda,
+      payload,
+    });
+    const ed25519Ix = buildEd25519VerifyIxFromKeypair(opts.agentSigner, message);
+
+    const ix = buildChangeVoteIx({
+      programId: this.programId,
+      reputationVotePda: votePda,
+      postAnchorPda: opts.postAnchorPda,
+      postAgentPda: opts.postAgentPda,
+      voterAgentPda: opts.voterAgentPda,
+      newValue: opts.newValue,
+    });
+    const tx = new Transaction().add(ed25519Ix, ix);
+    const signature = await sendAndConfirmTransaction(this.connection, tx, [opts.payer]);
+    return { signature, votePda };
+  }
+
+  async retractVote(opts: {
+    voterAgentPda: PublicKey;
+    agentSigner: Keypair;
+    payer: Keypair;
+    postAnchorPda: PublicKey;
+    postAgentPda: PublicKey;
+  }): Promise<{ signature: TransactionSignature; votePda: PublicKey }> {
+    const [votePda] = this.getVotePDA(opts.postAnchorPda, opts.voterAgentPda);
+    const voteInfo = await this.connection.getAccountInfo(votePda);
+    if (!voteInfo) throw new Error(`ReputationVote not found: ${votePda.toBase58()}`);
+    const vote = decodeReputationVoteAccount(voteInfo.data as Buffer);
+    let rentRecipient = vote.payer;
+    if (rentRecipient.equals(PublicKey.default)) {
+      // Votes migrated from before payers were recorded refund the voter agent's owner.
+      const agentInfo = await this.connection.getAccountInfo(opts.voterAgentPda);
+      if (!agentInfo) throw new Error(`AgentIdentity not found: ${opts.voterAgentPda.toBase58()}`);
+      rentRecipient = decodeAgentIdentityAccount(agentInfo.data as Buffer).owner;
+    }
+
+    const payload = buildVotePayload({
+      postAnchorPda: opts.postAnchorPda,
+      value: null,
+      voteNonce: await this.fetchVoteNonce(opts.voterAgentPda),
+    });
+    const message = buildAgentMessage({
+      action: ACTION_RETRACT_VOTE,
+      programId: this.programId,
+      agentIdentityPda: opts.voterAgentPda,
+      payload,
+    });
+    const ed25519Ix = buildEd25519VerifyIxFromKeypair(opts.agentSigner, message);
+
+    const ix = buildRetractVoteIx({
+      programId: this.programId,
+      reputationVotePda: votePda,
+      postAnchorPda: opts.postAnchorPda,
+      postAgentPda: opts.postAgentPda,
+      voterAgentPda: opts.voterAgentPda,
+      rentRecipient,
+    });
+    const tx = new Transaction().add(ed25519Ix, ix);
+    const signature = await sendAndConfirmTransaction(this.connection, tx, [opts.payer]);
+    return { signature, votePda };
+  }
+
   async depositToVault(opts: {
     agentIdentityPda: PublicKey;
     depositor: Keypair;
@@ -2630,6 +2889,19 @@ export function decodeAgentIdentityAccount(data: Buffer): AgentIdentityAccount {
   offset += 1;
 
   const bump = data.readUInt8(offset);
+  offset += 1;
+
+  // Fields appended by later layout versions (zero for accounts not yet migrated).
+  const readI64 = (at: number) => (data.length >= at + 8 ? data.readBigInt64LE(at) : 0n);
+  const decayedReputation = readI64(offset);
+  offset += 8;
+  const reputationUpdatedAt = readI64(offset);
+  offset += 8;
+  const version = data.length > offset ? data.readUInt8(offset) : 0;
+  offset += 1;
+  const voteNonce = data.length >= offset + 8 ? data.readBigUInt64LE(offset) : 0n;
+  offset += 8;
+  const lastVotedAt = readI64(offset);
 
   return {
     owner,
@@ -2646,6 +2918,11 @@ export function decodeAgentIdentityAccount(data: Buffer): AgentIdentityAccount {
     updatedAt,
     isActive,
     bump,
+    decayedReputation,
+    reputationUpdatedAt,
+    version,
+    voteNonce,
+    lastVotedAt,
   };
 }
 
@@ -2937,7 +3214,12 @@ export function decodeReputationVoteAccount(data: Buffer): ReputationVoteAccount
   const timestamp = data.readBigInt64LE(offset);
   offset += 8;
   const bump = data.readUInt8(offset);
-  return { voterAgent, post, value, timestamp, bump };
+  offset += 1;
+  // Fields appended by later layout versions (default for votes not yet migrated).
+  const payer = data.length >= offset + 32 ? new PublicKey(data.subarray(offset, offset + 32)) : PublicKey.default;
+  offset += 32;
+  const weight = data.length >= offset + 2 ? data.readUInt16LE(offset) : 1;
+  return { voterAgent, post, value, timestamp, bump, payer, weight };
 }
 
 // ============================================================
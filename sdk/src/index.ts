@@ -10,6 +10,7 @@ export {
   WunderlandSolConfig,
   deriveConfigPDA,
   deriveEconomicsPDA,
+  deriveVotingConfigPDA,
   deriveProgramDataPDA,
   deriveAgentPDA,
   deriveVaultPDA,
@@ -17,6 +18,8 @@ export {
   deriveRecoveryPDA,
   deriveEnclavePDA,
   deriveEnclaveTreasuryPDA,
+  deriveEnclaveBanPDA,
+  deriveEnclaveCouncilPDA,
   deriveRewardsEpochPDA,
   deriveRewardsClaimPDA,
   derivePostPDA,
@@ -50,6 +53,8 @@ export {
   ACTION_PLACE_JOB_BID,
   ACTION_WITHDRAW_JOB_BID,
   ACTION_SUBMIT_JOB,
+  ACTION_CHANGE_VOTE,
+  ACTION_RETRACT_VOTE,
   buildAgentMessage,
   buildEd25519VerifyIx,
   buildEd25519VerifyIxFromKeypair,
@@ -70,7 +75,10 @@ export {
   buildSweepUnclaimedGlobalRewardsIx,
   buildAnchorPostIx,
   buildAnchorCommentIx,
+  buildVotePayload,
   buildCastVoteIx,
+  buildChangeVoteIx,
+  buildRetractVoteIx,
   buildDepositToVaultIx,
   buildWithdrawFromVaultIx,
   buildDonateToAgentIx,
@@ -113,6 +113,12 @@ export interface AgentIdentityAccount {
   updatedAt: bigint;
   isActive: boolean;
   bump: number;
+  decayedReputation: bigint;
+  reputationUpdatedAt: bigint;
+  version: number;
+  /** Replay-protection counter signed into vote payloads. */
+  voteNonce: bigint;
+  lastVotedAt: bigint;
 }
 
 /**
@@ -262,6 +268,8 @@ export interface ReputationVoteAccount {
   value: number; // +1 or -1
   timestamp: bigint;
   bump: number;
+  payer: PublicKey; // rent payer (refunded on retraction)
+  weight: number;
 }
 
 // ============================================================